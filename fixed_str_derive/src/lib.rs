@@ -0,0 +1,155 @@
+// fixed_str_derive/src/lib.rs
+
+//! Derive macro for fixed‑width records of `fixed_str` fields.
+//!
+//! Use it through the `derive` feature of `fixed_str`, which re‑exports [`FixedRecord`].
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// Derives `fixed_str::FixedRecord` for a struct whose fields implement `RecordField`.
+///
+/// The fields are laid out in declaration order without gaps. Besides the trait, the derive
+/// generates an `<FIELD>_OFFSET` constant per field and the inherent methods
+/// `from_bytes(&[u8; WIDTH])` and `to_bytes() -> [u8; WIDTH]`.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedRecord, FixedStr, SpacePadded};
+///
+/// #[derive(FixedRecord)]
+/// struct Account {
+///     name: SpacePadded<6>,
+///     id: u16,
+///     currency: FixedStr<3>,
+/// }
+///
+/// let account = Account::from_bytes(b"SMITH \x00\x2AEUR").unwrap();
+/// assert_eq!((account.name.as_str(), account.id), ("SMITH", 42));
+/// assert_eq!(Account::CURRENCY_OFFSET, 8);
+/// assert_eq!(&account.to_bytes(), b"SMITH \x00\x2AEUR");
+/// ```
+#[proc_macro_derive(FixedRecord)]
+pub fn derive_fixed_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "FixedRecord cannot be derived for generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "FixedRecord can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "FixedRecord can only be derived for structs",
+            ))
+        }
+    };
+
+    let krate = quote!(::fixed_str);
+    let mut offsets = Vec::new();
+    let mut specs = Vec::new();
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+    let mut end = quote!(0);
+    for (index, field) in fields.iter().enumerate() {
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let label = ident.to_string();
+        let label = label.trim_start_matches("r#");
+        let offset = format_ident!("{}_OFFSET", label.to_uppercase());
+        let doc = format!("The byte offset of the `{}` field.", label);
+        let width = quote!(<#ty as #krate::RecordField>::WIDTH);
+        offsets.push(quote! {
+            #[doc = #doc]
+            pub const #offset: usize = #end;
+        });
+        specs.push(quote! {
+            #krate::FieldSpec {
+                offset: Self::#offset,
+                width: #width,
+                pad: <#ty as #krate::RecordField>::PAD_BYTE,
+            }
+        });
+        reads.push(quote! {
+            #ident: <#ty as #krate::RecordField>::read_field(
+                &bytes[Self::#offset..Self::#offset + #width],
+            )
+            .map_err(|error| #krate::RecordError::Field {
+                index: #index,
+                offset: Self::#offset,
+                error,
+            })?
+        });
+        writes.push(quote! {
+            #krate::RecordField::write_field(&self.#ident, &mut out[Self::#offset..]);
+        });
+        end = quote!(Self::#offset + #width);
+    }
+
+    Ok(quote! {
+        impl #name {
+            #(#offsets)*
+
+            /// Parses the record from a buffer of exactly its width.
+            pub fn from_bytes(
+                bytes: &[u8; <#name as #krate::FixedRecord>::WIDTH],
+            ) -> ::core::result::Result<Self, #krate::RecordError> {
+                <Self as #krate::FixedRecord>::parse_slice(bytes)
+            }
+
+            /// Writes the record, including the padding of each field.
+            pub fn to_bytes(&self) -> [u8; <#name as #krate::FixedRecord>::WIDTH] {
+                let mut out = [0u8; <#name as #krate::FixedRecord>::WIDTH];
+                // The length matches, which is the only error `emit_into` reports.
+                let _ = #krate::FixedRecord::emit_into(self, &mut out);
+                out
+            }
+        }
+
+        impl #krate::FixedRecord for #name {
+            const WIDTH: usize = #end;
+            const FIELDS: &'static [#krate::FieldSpec] = &[#(#specs),*];
+
+            fn parse_slice(bytes: &[u8]) -> ::core::result::Result<Self, #krate::RecordError> {
+                if bytes.len() != Self::WIDTH {
+                    return ::core::result::Result::Err(#krate::RecordError::WrongLength {
+                        expected: Self::WIDTH,
+                        found: bytes.len(),
+                    });
+                }
+                ::core::result::Result::Ok(Self { #(#reads),* })
+            }
+
+            fn emit_into(&self, out: &mut [u8]) -> ::core::result::Result<(), #krate::RecordError> {
+                if out.len() != Self::WIDTH {
+                    return ::core::result::Result::Err(#krate::RecordError::WrongLength {
+                        expected: Self::WIDTH,
+                        found: out.len(),
+                    });
+                }
+                #(#writes)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
@@ -15,7 +15,7 @@ pub trait EffectiveBytes {
 
 impl<const N: usize> EffectiveBytes for FixedStr<N> {
     fn effective_bytes(&self) -> &[u8] {
-        &self[..self.len()]
+        head(&self.data, self.len())
     }
 }
 
@@ -28,14 +28,14 @@ impl<const N: usize> EffectiveBytes for &FixedStr<N> {
 impl EffectiveBytes for [u8] {
     fn effective_bytes(&self) -> &[u8] {
         let end = find_first_null(self);
-        &self[..end]
+        head(self, end)
     }
 }
 
 impl<const N: usize> EffectiveBytes for [u8; N] {
     fn effective_bytes(&self) -> &[u8] {
         let end = find_first_null(self);
-        &self[..end]
+        head(self, end)
     }
 }
 
@@ -45,7 +45,7 @@ impl EffectiveBytes for &str {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl EffectiveBytes for String {
     fn effective_bytes(&self) -> &[u8] {
         self.as_bytes().effective_bytes()
@@ -0,0 +1,56 @@
+// fixed_str/src/fmt_ext.rs
+
+//******************************************************************************
+//  ufmt Formatting
+//******************************************************************************
+
+#[cfg(feature = "ufmt")]
+mod ufmt_ext {
+    use crate::*;
+    use ufmt::{uDisplay, uWrite, Formatter};
+
+    /// Implements `ufmt` writing for `FixedStrBuf`.
+    ///
+    /// Each write is appended with `try_push_str`, so a write that does not fit
+    /// leaves the buffer unchanged and returns `FixedStrError::Overflow`.
+    impl<const N: usize> uWrite for FixedStrBuf<N> {
+        type Error = FixedStrError;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+            self.try_push_str(s)
+        }
+    }
+
+    /// Implements `ufmt` display for `FixedStr` by writing its effective string.
+    impl<const N: usize> uDisplay for FixedStr<N> {
+        fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: uWrite + ?Sized,
+        {
+            f.write_str(self.as_str())
+        }
+    }
+}
+
+// --- Tests for ufmt integration ---
+#[cfg(all(test, feature = "ufmt"))]
+mod ufmt_tests {
+    use crate::*;
+    use ufmt::uwrite;
+
+    #[test]
+    fn test_ufmt_write_into_buffer() {
+        let mut buf = FixedStrBuf::<16>::new();
+        let name = FixedStr::<8>::new("Rust");
+        uwrite!(buf, "Hi {} #{}", name, 42u8).unwrap();
+        assert_eq!(buf.finalize(), "Hi Rust #42");
+    }
+
+    #[test]
+    fn test_ufmt_write_overflow() {
+        let mut buf = FixedStrBuf::<4>::new();
+        let result = uwrite!(buf, "{}", "Hello");
+        assert!(result.is_err());
+        assert!(buf.is_empty());
+    }
+}
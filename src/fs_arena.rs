@@ -0,0 +1,276 @@
+// fixed_str/src/fs_arena.rs
+
+use super::*;
+use core::ops::Index;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Stores many `FixedStr<N>` values contiguously in a `Vec` (requires the `alloc` feature).
+///
+/// Values are appended with [`FixedStrArena::push`] and addressed by index. After
+/// [`FixedStrArena::sort`], [`FixedStrArena::binary_search_str`] finds a value by its
+/// effective string. [`FixedStrArrayArena`] offers the same interface without allocating.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, FixedStrArena};
+///
+/// let mut codes = FixedStrArena::<4>::new();
+/// codes.push(FixedStr::new("USD"));
+/// codes.push(FixedStr::new("EUR"));
+/// codes.push(FixedStr::new("JPY"));
+/// codes.sort();
+/// assert_eq!(codes.binary_search_str("JPY"), Ok(1));
+/// assert_eq!(codes.binary_search_str("GBP"), Err(1));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixedStrArena<const N: usize> {
+    values: Vec<FixedStr<N>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> FixedStrArena<N> {
+    /// Creates an empty arena.
+    pub const fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Creates an empty arena with room for `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a value and returns its index.
+    pub fn push(&mut self, value: FixedStr<N>) -> usize {
+        self.values.push(value);
+        self.values.len() - 1
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// Returns the stored values as a contiguous slice.
+    pub fn as_slice(&self) -> &[FixedStr<N>] {
+        &self.values
+    }
+
+    /// Returns the stored values as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [FixedStr<N>] {
+        &mut self.values
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> Extend<FixedStr<N>> for FixedStrArena<N> {
+    fn extend<I: IntoIterator<Item = FixedStr<N>>>(&mut self, iter: I) {
+        self.values.extend(iter);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> FromIterator<FixedStr<N>> for FixedStrArena<N> {
+    fn from_iter<I: IntoIterator<Item = FixedStr<N>>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Stores up to `M` `FixedStr<N>` values contiguously in an array, without allocating.
+///
+/// The `no_std` counterpart of [`FixedStrArena`]: [`FixedStrArrayArena::push`] hands the
+/// value back once the arena is full.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, FixedStrArrayArena};
+///
+/// let mut names = FixedStrArrayArena::<8, 2>::new();
+/// assert_eq!(names.push(FixedStr::new("eth1")), Ok(0));
+/// assert_eq!(names.push(FixedStr::new("eth0")), Ok(1));
+/// assert!(names.push(FixedStr::new("wlan0")).is_err());
+/// names.sort();
+/// assert_eq!(names.binary_search_str("eth1"), Ok(1));
+/// ```
+#[derive(Clone, Copy)]
+pub struct FixedStrArrayArena<const N: usize, const M: usize> {
+    values: [FixedStr<N>; M],
+    len: usize,
+}
+
+impl<const N: usize, const M: usize> FixedStrArrayArena<N, M> {
+    /// Creates an empty arena.
+    pub const fn new() -> Self {
+        Self {
+            values: [FixedStr { data: [0; N] }; M],
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of values the arena can hold.
+    pub const fn capacity(&self) -> usize {
+        M
+    }
+
+    /// Returns `true` if no more values can be pushed.
+    pub const fn is_full(&self) -> bool {
+        self.len == M
+    }
+
+    /// Appends a value and returns its index.
+    ///
+    /// # Errors
+    /// Returns the value back if the arena already holds `M` values.
+    pub fn push(&mut self, value: FixedStr<N>) -> Result<usize, FixedStr<N>> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.values[self.len] = value;
+        self.len += 1;
+        Ok(self.len - 1)
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns the stored values as a contiguous slice.
+    pub fn as_slice(&self) -> &[FixedStr<N>] {
+        &self.values[..self.len]
+    }
+
+    /// Returns the stored values as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [FixedStr<N>] {
+        &mut self.values[..self.len]
+    }
+}
+
+impl<const N: usize, const M: usize> Default for FixedStrArrayArena<N, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, const M: usize> fmt::Debug for FixedStrArrayArena<N, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq for FixedStrArrayArena<N, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize, const M: usize> Eq for FixedStrArrayArena<N, M> {}
+
+/// Implements the slice‑based accessors shared by both arenas.
+macro_rules! impl_arena_common {
+    ($arena:ty, $($generics:tt)*) => {
+        impl<$($generics)*> $arena {
+            /// Returns the number of stored values.
+            pub fn len(&self) -> usize {
+                self.as_slice().len()
+            }
+
+            /// Returns `true` if the arena holds no values.
+            pub fn is_empty(&self) -> bool {
+                self.as_slice().is_empty()
+            }
+
+            /// Returns the value at `index`, or `None` if it is out of bounds.
+            pub fn get(&self, index: usize) -> Option<&FixedStr<N>> {
+                self.as_slice().get(index)
+            }
+
+            /// Returns an iterator over the stored values in index order.
+            pub fn iter(&self) -> core::slice::Iter<'_, FixedStr<N>> {
+                self.as_slice().iter()
+            }
+
+            /// Sorts the values by their effective bytes, preparing for
+            /// [`binary_search_str`](Self::binary_search_str).
+            pub fn sort(&mut self) {
+                self.as_mut_slice().sort_unstable();
+            }
+
+            /// Binary searches the sorted values for `key`, comparing effective bytes.
+            ///
+            /// Returns `Ok` with the index of a matching value, or `Err` with the index where
+            /// `key` could be inserted. The result is unspecified if the arena is not sorted.
+            pub fn binary_search_str(&self, key: &str) -> Result<usize, usize> {
+                let key = key.effective_bytes();
+                self.as_slice()
+                    .binary_search_by(|value| value.effective_bytes().cmp(key))
+            }
+        }
+
+        impl<$($generics)*> Index<usize> for $arena {
+            type Output = FixedStr<N>;
+            fn index(&self, index: usize) -> &FixedStr<N> {
+                &self.as_slice()[index]
+            }
+        }
+
+        impl<'a, $($generics)*> IntoIterator for &'a $arena {
+            type Item = &'a FixedStr<N>;
+            type IntoIter = core::slice::Iter<'a, FixedStr<N>>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_arena_common!(FixedStrArena<N>, const N: usize);
+impl_arena_common!(FixedStrArrayArena<N, M>, const N: usize, const M: usize);
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod arena_tests {
+    use super::*;
+
+    #[test]
+    fn test_array_arena() {
+        let mut arena = FixedStrArrayArena::<4, 3>::default();
+        assert!(arena.is_empty());
+        for name in ["cc", "aa", "bb"] {
+            arena.push(FixedStr::new(name)).unwrap();
+        }
+        assert_eq!(arena.push(FixedStr::new("dd")), Err(FixedStr::new("dd")));
+        assert_eq!(arena[0].as_str(), "cc");
+        arena.sort();
+        let sorted: [&str; 3] = ["aa", "bb", "cc"];
+        assert!(arena.iter().map(|v| v.as_str()).eq(sorted));
+        assert_eq!(arena.binary_search_str("bb"), Ok(1));
+        assert_eq!(arena.binary_search_str("b"), Err(1));
+        // A key longer than the capacity never matches.
+        assert_eq!(arena.binary_search_str("ccccc"), Err(3));
+        arena.clear();
+        assert_eq!(arena.len(), 0);
+        assert_eq!(arena, FixedStrArrayArena::new());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_vec_arena() {
+        let mut arena: FixedStrArena<4> = ["b", "a"].iter().copied().map(FixedStr::new).collect();
+        assert_eq!(arena.push(FixedStr::from_bytes(*b"ab\0x")), 2);
+        arena.sort();
+        assert_eq!(arena.binary_search_str("ab"), Ok(1));
+        assert_eq!(arena.get(3), None);
+        assert_eq!((&arena).into_iter().count(), 3);
+    }
+}
@@ -0,0 +1,313 @@
+// fixed_str/src/fs_ascii.rs
+
+use super::*;
+use core::ops::{Bound, Deref, RangeBounds};
+
+/// A fixed‑capacity, null‑padded string that only holds ASCII.
+///
+/// Every constructor rejects non‑ASCII content, so every byte is one character: `len()` is
+/// the number of characters, any index is a character boundary, and case‑insensitive
+/// comparison is a byte‑wise operation. This suits protocol fields that are defined as
+/// ASCII. Read‑only access to the underlying [`FixedStr`] is available through `Deref`.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{AsciiFixedStr, FixedStrError};
+///
+/// let method = AsciiFixedStr::<8>::new("GET").unwrap();
+/// assert!(method.eq_ignore_ascii_case("get"));
+/// assert_eq!(method.slice(1..), "ET");
+/// assert_eq!(method.char_at(2), Some('T'));
+///
+/// let err = AsciiFixedStr::<8>::new("GÉT").unwrap_err();
+/// assert_eq!(err, FixedStrError::NonAscii { position: 1 });
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct AsciiFixedStr<const N: usize>(FixedStr<N>);
+
+impl<const N: usize> AsciiFixedStr<N> {
+    /// Creates a new `AsciiFixedStr`, truncating input longer than `N` bytes.
+    ///
+    /// As with [`FixedStr::new`], the content ends at the first null byte.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::NonAscii` if the stored content contains a non‑ASCII byte.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn new(input: &str) -> Result<Self, FixedStrError> {
+        require_capacity::<N>();
+        let bytes = input.effective_bytes();
+        let len = bytes.len().min(N);
+        #[cfg(feature = "debug_lossy")]
+        crate::lossy_hook::report_if_lossy(N, bytes.len(), len);
+        Self::from_content(head(bytes, len))
+    }
+
+    /// Creates a new `AsciiFixedStr` at compile time, truncating input longer than `N` bytes.
+    ///
+    /// # Panics
+    /// Panics if `N == 0` or if the stored content contains a non‑ASCII byte. In a const
+    /// item, both are compile errors.
+    pub const fn new_const(input: &str) -> Self {
+        require_capacity::<N>();
+        let bytes = input.as_bytes();
+        let mut data = [0u8; N];
+        let mut i = 0;
+        while i < N && i < bytes.len() && bytes[i] != 0 {
+            assert!(bytes[i].is_ascii(), "AsciiFixedStr content must be ASCII");
+            data[i] = bytes[i];
+            i += 1;
+        }
+        Self(FixedStr { data })
+    }
+
+    /// Creates an `AsciiFixedStr` from a full byte array, ending at the first null byte.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::NonAscii` if the content contains a non‑ASCII byte.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn from_bytes(bytes: [u8; N]) -> Result<Self, FixedStrError> {
+        require_capacity::<N>();
+        Self::from_content(bytes.effective_bytes())
+    }
+
+    /// Checks that `content` is ASCII and stores it; `content` must be at most `N` bytes.
+    fn from_content(content: &[u8]) -> Result<Self, FixedStrError> {
+        if let Some(position) = content.iter().position(|b| !b.is_ascii()) {
+            return Err(FixedStrError::NonAscii { position });
+        }
+        let mut data = [0u8; N];
+        write_at(&mut data, 0, content);
+        Ok(Self(FixedStr { data }))
+    }
+
+    /// Returns the content as a string slice.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns the underlying `FixedStr`.
+    pub const fn as_fixed_str(&self) -> &FixedStr<N> {
+        &self.0
+    }
+
+    /// Returns the underlying `FixedStr`.
+    pub const fn into_inner(self) -> FixedStr<N> {
+        self.0
+    }
+
+    /// Returns the character at `index`, or `None` if it is past the end.
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        self.as_str().as_bytes().get(index).map(|&b| b as char)
+    }
+
+    /// Returns the characters in `range`, clamped to the content; never panics.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> &str {
+        let s = self.as_str();
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => s.len(),
+        }
+        .min(s.len());
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        }
+        .min(end);
+        // Every index is a character boundary of ASCII content.
+        &s[start..end]
+    }
+
+    /// Compares the content with `other`, ignoring ASCII case.
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+
+    /// Converts the content to ASCII uppercase in place.
+    pub fn make_ascii_uppercase(&mut self) {
+        self.0.data.make_ascii_uppercase();
+    }
+
+    /// Converts the content to ASCII lowercase in place.
+    pub fn make_ascii_lowercase(&mut self) {
+        self.0.data.make_ascii_lowercase();
+    }
+
+    /// Returns a copy with the content in ASCII uppercase.
+    pub fn to_ascii_uppercase(&self) -> Self {
+        let mut upper = *self;
+        upper.make_ascii_uppercase();
+        upper
+    }
+
+    /// Returns a copy with the content in ASCII lowercase.
+    pub fn to_ascii_lowercase(&self) -> Self {
+        let mut lower = *self;
+        lower.make_ascii_lowercase();
+        lower
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> Deref for AsciiFixedStr<N> {
+    type Target = FixedStr<N>;
+    fn deref(&self) -> &FixedStr<N> {
+        &self.0
+    }
+}
+
+impl<const N: usize> fmt::Debug for AsciiFixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for AsciiFixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> NoAllocDisplay for AsciiFixedStr<N> {}
+
+impl<const N: usize> AsRef<str> for AsciiFixedStr<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for AsciiFixedStr<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl<const N: usize> Borrow<str> for AsciiFixedStr<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Converts a string without truncation.
+///
+/// Returns `FixedStrError::Overflow` if the input exceeds `N` bytes, or
+/// `FixedStrError::NonAscii` if it contains a non‑ASCII byte.
+impl<const N: usize> core::convert::TryFrom<&str> for AsciiFixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        require_capacity::<N>();
+        let bytes = s.effective_bytes();
+        if bytes.len() > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: bytes.len(),
+            });
+        }
+        Self::from_content(bytes)
+    }
+}
+
+/// Returns `FixedStrError::NonAscii` if the content contains a non‑ASCII byte.
+impl<const N: usize> core::convert::TryFrom<FixedStr<N>> for AsciiFixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(fixed: FixedStr<N>) -> Result<Self, Self::Error> {
+        Self::from_content(fixed.effective_bytes())
+    }
+}
+
+impl<const N: usize> From<AsciiFixedStr<N>> for FixedStr<N> {
+    fn from(ascii: AsciiFixedStr<N>) -> Self {
+        ascii.0
+    }
+}
+
+impl<const N: usize> FixedStrMeta for AsciiFixedStr<N> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::AsciiFixedStr;
+}
+
+#[cfg(feature = "cmp_foreign")]
+mod cmp_ext {
+    use crate::*;
+
+    impl<const N: usize> PartialEq<str> for AsciiFixedStr<N> {
+        fn eq(&self, other: &str) -> bool {
+            self.as_str() == other
+        }
+    }
+
+    impl<const N: usize> PartialEq<&str> for AsciiFixedStr<N> {
+        fn eq(&self, other: &&str) -> bool {
+            self.as_str() == *other
+        }
+    }
+
+    impl<const N: usize> PartialEq<AsciiFixedStr<N>> for &str {
+        fn eq(&self, other: &AsciiFixedStr<N>) -> bool {
+            *self == other.as_str()
+        }
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod ascii_tests {
+    use super::*;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(AsciiFixedStr::<3>::new("abcd").unwrap().as_str(), "abc");
+        assert_eq!(AsciiFixedStr::<8>::new("ab\0é").unwrap().as_str(), "ab");
+        // A non-ASCII byte past the capacity is truncated, not rejected.
+        assert!(AsciiFixedStr::<2>::new("abé").is_ok());
+        assert_eq!(
+            AsciiFixedStr::<4>::from_bytes(*b"a\xFFb\0"),
+            Err(FixedStrError::NonAscii { position: 1 })
+        );
+        assert_eq!(
+            AsciiFixedStr::<2>::try_from("abc"),
+            Err(FixedStrError::Overflow {
+                available: 2,
+                found: 3
+            })
+        );
+        const HOST: AsciiFixedStr<8> = AsciiFixedStr::new_const("Host");
+        assert_eq!(AsciiFixedStr::<8>::try_from("Host"), Ok(HOST));
+        let fixed = FixedStr::<4>::new("é");
+        assert!(AsciiFixedStr::try_from(fixed).is_err());
+    }
+
+    #[test]
+    fn test_ascii_operations() {
+        let mut name = AsciiFixedStr::<8>::new("Content").unwrap();
+        assert_eq!(name.len(), 7);
+        assert_eq!(name.slice(..3), "Con");
+        assert_eq!(name.slice(4..=6), "ent");
+        assert_eq!(name.slice(5..100), "nt");
+        assert_eq!(name.slice(9..), "");
+        assert_eq!(name.char_at(7), None);
+        assert!(name.eq_ignore_ascii_case("CONTENT"));
+        assert_eq!(name.to_ascii_lowercase().as_str(), "content");
+        name.make_ascii_uppercase();
+        assert_eq!(name.as_str(), "CONTENT");
+        assert_eq!(
+            <AsciiFixedStr<8> as FixedStrMeta>::KIND,
+            FixedStrKind::AsciiFixedStr
+        );
+    }
+}
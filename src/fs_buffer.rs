@@ -4,11 +4,48 @@ use super::*;
 
 /// A builder for incrementally constructing a `FixedStr` with a fixed capacity.
 /// It maintains an internal byte buffer and tracks the number of bytes currently written (the effective length).
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy)]
 pub struct FixedStrBuf<const N: usize> {
     pub(super) buffer: [u8; N],
     /// The number of bytes currently stored (i.e. the effective length).
     pub(super) len: usize,
+    /// Whether the content is known to be valid UTF‑8.
+    pub(super) utf8: bool,
+}
+
+/// The result of a lossy push, reporting whether the input was truncated.
+#[must_use = "the input may have been truncated"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PushOutcome {
+    /// The entire input was appended.
+    Complete,
+    /// Only the first `pushed` bytes of the input were appended.
+    Truncated {
+        /// The number of bytes appended.
+        pushed: usize,
+        /// The number of input bytes that did not fit.
+        dropped: usize,
+    },
+}
+
+impl PushOutcome {
+    /// Returns `true` if the entire input was appended.
+    pub const fn is_complete(&self) -> bool {
+        matches!(self, Self::Complete)
+    }
+
+    /// Returns `true` if the input was truncated.
+    pub const fn is_truncated(&self) -> bool {
+        !self.is_complete()
+    }
+
+    /// Returns the number of input bytes that were dropped (`0` for a complete push).
+    pub const fn dropped(&self) -> usize {
+        match self {
+            Self::Complete => 0,
+            Self::Truncated { dropped, .. } => *dropped,
+        }
+    }
 }
 
 impl<const N: usize> FixedStrBuf<N> {
@@ -34,18 +71,58 @@ impl<const N: usize> FixedStrBuf<N> {
     /// # Panics
     /// Panics if `N == 0`. Zero‑length strings are not supported.
     pub const fn new() -> Self {
-        panic_on_zero(N);
+        require_capacity::<N>();
         Self {
             buffer: [0u8; N],
             len: 0,
+            utf8: true,
         }
     }
 
+    /// Creates a builder from a buffer whose first `len` bytes are the content, checking
+    /// whether the content is valid UTF‑8.
+    pub(crate) fn from_raw_parts(buffer: [u8; N], len: usize) -> Self {
+        let utf8 = core::str::from_utf8(&buffer[..len]).is_ok();
+        Self { buffer, len, utf8 }
+    }
+
+    /// Returns `true` if the content is known to be valid UTF‑8.
+    ///
+    /// This holds unless bytes were appended with [`FixedStrBuf::push_bytes_unchecked`] or
+    /// the builder was created from invalid bytes. A later strict push of bytes that make
+    /// the content valid again, or a [`clear`](FixedStrBuf::clear), restores the guarantee.
+    pub fn is_utf8_guaranteed(&self) -> bool {
+        self.utf8
+    }
+
     /// Attempts to interpret the current effective bytes (up to the first null) as a valid UTF‑8 string.
     ///
     /// Returns an error if the effective content is not valid UTF‑8.
     pub fn try_as_str(&self) -> Result<&str, FixedStrError> {
-        core::str::from_utf8(self.effective_bytes()).map_err(|_| FixedStrError::InvalidUtf8)
+        core::str::from_utf8(self.effective_bytes()).map_err(FixedStrError::from)
+    }
+
+    /// Returns an iterator over the characters written so far.
+    ///
+    /// As with [`FixedStrBuf::try_as_str`], the content ends at the first null byte. If it
+    /// is not valid UTF‑8, which is only possible when the builder was created from raw
+    /// bytes, iteration stops before the first invalid sequence.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut buf = FixedStrBuf::<8>::new();
+    /// buf.try_push_str("né").unwrap();
+    /// assert!(buf.chars().eq(['n', 'é']));
+    /// ```
+    pub fn chars(&self) -> core::str::Chars<'_> {
+        let bytes = self.effective_bytes();
+        let valid = match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(e) => core::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+        };
+        valid.chars()
     }
 
     /// Attempts to append the entire input string to the buffer.
@@ -60,8 +137,80 @@ impl<const N: usize> FixedStrBuf<N> {
                 found: bytes.len(),
             });
         }
-        self.buffer[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        write_at(&mut self.buffer, self.len, bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+
+    /// Attempts to append raw bytes, which must keep the content valid UTF‑8.
+    ///
+    /// The bytes are validated together with the content already written, so a multi‑byte
+    /// character split across calls (the rest of one appended with
+    /// [`FixedStrBuf::push_bytes_unchecked`]) is accepted once it is complete. As with
+    /// [`FixedStrBuf::try_push_str`], the input ends at its first null byte, and nothing is
+    /// appended on error.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::Overflow` if the bytes exceed the remaining capacity.
+    /// - Returns `FixedStrError::InvalidUtf8` if the content would not be valid UTF‑8, with
+    ///   `valid_up_to` counted from the start of the content.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStrBuf, FixedStrError};
+    ///
+    /// let mut buf = FixedStrBuf::<8>::new();
+    /// buf.try_push_bytes(b"caf").unwrap();
+    /// assert_eq!(
+    ///     buf.try_push_bytes(b"\xC3"),
+    ///     Err(FixedStrError::InvalidUtf8 { valid_up_to: 3 })
+    /// );
+    /// buf.try_push_bytes("é".as_bytes()).unwrap();
+    /// assert_eq!(buf.try_as_str(), Ok("café"));
+    /// ```
+    pub fn try_push_bytes(&mut self, bytes: &[u8]) -> Result<(), FixedStrError> {
+        let bytes = bytes.effective_bytes();
+        if bytes.len() > self.remaining() {
+            return Err(FixedStrError::Overflow {
+                available: self.remaining(),
+                found: bytes.len(),
+            });
+        }
+        // Valid content stays valid if the new bytes are valid on their own.
+        let start = if self.utf8 { self.len } else { 0 };
+        let end = self.len + bytes.len();
+        write_at(&mut self.buffer, self.len, bytes);
+        if let Err(e) = core::str::from_utf8(&self.buffer[start..end]) {
+            self.buffer[self.len..end].fill(0);
+            return Err(FixedStrError::InvalidUtf8 {
+                valid_up_to: start + e.valid_up_to(),
+            });
+        }
+        self.len = end;
+        self.utf8 = true;
+        Ok(())
+    }
+
+    /// Appends raw bytes without checking that the content stays valid UTF‑8.
+    ///
+    /// Meant for parsers that have already validated their input, or that append a
+    /// multi‑byte character in pieces. Afterwards, [`FixedStrBuf::is_utf8_guaranteed`]
+    /// returns `false` until the guarantee is restored. The input ends at its first null
+    /// byte, and nothing is appended on error.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the bytes exceed the remaining capacity.
+    pub fn push_bytes_unchecked(&mut self, bytes: &[u8]) -> Result<(), FixedStrError> {
+        let bytes = bytes.effective_bytes();
+        if bytes.len() > self.remaining() {
+            return Err(FixedStrError::Overflow {
+                available: self.remaining(),
+                found: bytes.len(),
+            });
+        }
+        write_at(&mut self.buffer, self.len, bytes);
         self.len += bytes.len();
+        self.utf8 &= bytes.is_empty();
         Ok(())
     }
 
@@ -77,11 +226,82 @@ impl<const N: usize> FixedStrBuf<N> {
         self.try_push_str(s)
     }
 
+    /// Attempts to append an unsigned integer in the given radix, using lowercase digits.
+    ///
+    /// With `prefix`, the digits are preceded by `0b`, `0o` or `0x` for radix 2, 8 or 16;
+    /// other radixes have no prefix. As with [`FixedStrBuf::try_push_str`], nothing is
+    /// appended if the result does not fit.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut buf = FixedStrBuf::<16>::new();
+    /// buf.push_uint_radix(0xBEEF, 16, true).unwrap();
+    /// buf.try_push_char(' ').unwrap();
+    /// buf.push_uint_radix(35, 36, false).unwrap();
+    /// assert_eq!(buf.finalize().as_str(), "0xbeef z");
+    /// ```
+    pub fn push_uint_radix(
+        &mut self,
+        value: u128,
+        radix: u32,
+        prefix: bool,
+    ) -> Result<(), FixedStrError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36"
+        );
+        // Room for a prefix and the 128 binary digits of `u128::MAX`.
+        let mut scratch = [0u8; 130];
+        let mut start = scratch.len();
+        let mut rest = value;
+        loop {
+            start -= 1;
+            let digit = (rest % radix as u128) as u32;
+            scratch[start] = char::from_digit(digit, radix).unwrap() as u8;
+            rest /= radix as u128;
+            if rest == 0 {
+                break;
+            }
+        }
+        let marker = match radix {
+            2 => Some(b'b'),
+            8 => Some(b'o'),
+            16 => Some(b'x'),
+            _ => None,
+        };
+        if let (true, Some(marker)) = (prefix, marker) {
+            start -= 2;
+            scratch[start] = b'0';
+            scratch[start + 1] = marker;
+        }
+        // Digits and prefixes are ASCII.
+        self.try_push_str(str::from_utf8(&scratch[start..]).unwrap())
+    }
+
     /// Appends as many complete UTF‑8 characters from the input string as possible.
     ///
-    /// If the entire string fits into the remaining capacity, it returns `true`.
-    /// Otherwise, it appends only the valid initial segment (up to the last complete character) and returns `false`.
-    pub fn push_str_lossy(&mut self, s: &str) -> bool {
+    /// If the entire string fits into the remaining capacity, it returns [`PushOutcome::Complete`].
+    /// Otherwise, it appends only the valid initial segment (up to the last complete character) and
+    /// returns [`PushOutcome::Truncated`] with the number of bytes appended and dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStrBuf, PushOutcome};
+    ///
+    /// let mut buf = FixedStrBuf::<4>::new();
+    /// assert_eq!(buf.push_str_lossy("ab"), PushOutcome::Complete);
+    /// assert_eq!(
+    ///     buf.push_str_lossy("cde"),
+    ///     PushOutcome::Truncated { pushed: 2, dropped: 1 }
+    /// );
+    /// ```
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn push_str_lossy(&mut self, s: &str) -> PushOutcome {
         let remaining = self.remaining();
         let valid = if s.len() > remaining {
             truncate_utf8_lossy(s.as_bytes(), remaining)
@@ -91,11 +311,82 @@ impl<const N: usize> FixedStrBuf<N> {
 
         let bytes = valid.as_bytes();
         if !bytes.is_empty() {
-            self.buffer[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+            write_at(&mut self.buffer, self.len, bytes);
             self.len += bytes.len();
         }
 
-        bytes.len() == s.len()
+        #[cfg(feature = "debug_lossy")]
+        crate::lossy_hook::report_if_lossy(N, s.len(), bytes.len());
+
+        if bytes.len() == s.len() {
+            PushOutcome::Complete
+        } else {
+            PushOutcome::Truncated {
+                pushed: bytes.len(),
+                dropped: s.len() - bytes.len(),
+            }
+        }
+    }
+
+    /// Appends as much of the input as fits and returns a new builder holding the overflow.
+    ///
+    /// The input is split at the last valid UTF‑8 boundary that fits into the remaining
+    /// space. Returns `None` if the whole input was appended. Otherwise returns the overflow
+    /// builder together with the part of the input that did not fit into it either, which is
+    /// empty unless the overflow exceeds `N` bytes. Nothing is dropped, so long input can be
+    /// split across as many builders as needed by feeding the rest back in.
+    ///
+    /// **Note:** If the input contains a null byte (`\0`), it ends there.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut first = FixedStrBuf::<4>::new();
+    /// let (mut second, rest) = first.append_overflowing("abcdefghij").unwrap();
+    /// assert_eq!(first.finalize().as_str(), "abcd");
+    /// assert_eq!(second.finalize().as_str(), "efgh");
+    /// assert_eq!(rest, "ij");
+    /// ```
+    pub fn append_overflowing<'a>(&mut self, s: &'a str) -> Option<(FixedStrBuf<N>, &'a str)> {
+        let s = &s[..find_first_null(s.as_bytes())];
+        let split = find_valid_boundary(s.as_bytes(), self.remaining());
+        let (fits, tail) = s.split_at(split);
+        write_at(&mut self.buffer, self.len, fits.as_bytes());
+        self.len += fits.len();
+        if tail.is_empty() {
+            return None;
+        }
+        let mut overflow = Self::new();
+        let kept = find_valid_boundary(tail.as_bytes(), N);
+        let (kept, rest) = tail.split_at(kept);
+        write_at(&mut overflow.buffer, 0, kept.as_bytes());
+        overflow.len = kept.len();
+        Some((overflow, rest))
+    }
+
+    /// Like [`FixedStrBuf::try_push_str`], but returns the remaining capacity after the push.
+    ///
+    /// This saves a separate call to `remaining()` in tight encoding loops.
+    pub fn try_push_str_with_remaining(&mut self, s: &str) -> Result<usize, FixedStrError> {
+        self.try_push_str(s)?;
+        Ok(self.remaining())
+    }
+
+    /// Like [`FixedStrBuf::try_push_char`], but returns the remaining capacity after the push.
+    pub fn try_push_char_with_remaining(&mut self, c: char) -> Result<usize, FixedStrError> {
+        self.try_push_char(c)?;
+        Ok(self.remaining())
+    }
+
+    /// Like [`FixedStrBuf::push_str_lossy`], but also returns the remaining capacity after the push.
+    ///
+    /// The first element reports whether the entire string was appended.
+    #[must_use = "the input may have been truncated"]
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn push_str_lossy_with_remaining(&mut self, s: &str) -> (PushOutcome, usize) {
+        let outcome = self.push_str_lossy(s);
+        (outcome, self.remaining())
     }
 
     /// Finalizes the builder into a `FixedStr`.
@@ -103,11 +394,35 @@ impl<const N: usize> FixedStrBuf<N> {
     /// This method zero‑pads the unused portion of the buffer and creates a `FixedStr`
     /// from the internal byte array. If the written content contains a null byte (`\0`),
     /// the resulting string will terminate at that null, ignoring any bytes that follow.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
     pub fn finalize(mut self) -> FixedStr<N> {
-        self.buffer[self.len..N].fill(0);
+        zero_from(&mut self.buffer, self.len);
         FixedStr::from_bytes(self.buffer)
     }
 
+    /// Finalizes the builder into a `FixedStr`, failing instead of truncating invalid UTF‑8.
+    ///
+    /// As with [`FixedStrBuf::finalize`], the content ends at the first null byte and the
+    /// rest of the buffer is zeroed.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::InvalidUtf8` if the content is not valid UTF‑8, which is only
+    /// possible when the builder was created from raw bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStrBuf, FixedStrError};
+    ///
+    /// let buf = FixedStrBuf::<4>::try_from(&b"ab\xFF"[..]).unwrap();
+    /// assert_eq!(buf.finalize().as_str(), "ab");
+    /// assert_eq!(buf.finalize_strict(), Err(FixedStrError::InvalidUtf8 { valid_up_to: 2 }));
+    /// ```
+    pub fn finalize_strict(self) -> Result<FixedStr<N>, FixedStrError> {
+        let content = self.try_as_str()?;
+        let data = copy_into_buffer(content.as_bytes(), BufferCopyMode::Exact)?;
+        Ok(FixedStr { data })
+    }
+
     /// Finalizes the builder into a `FixedStr` without UTF-8 boundary checks.
     ///
     /// # Warning
@@ -125,15 +440,19 @@ impl<const N: usize> FixedStrBuf<N> {
     pub fn clear(&mut self) {
         self.buffer.fill(0);
         self.len = 0;
+        self.utf8 = true;
     }
 
     /// Truncates the effective content of the buffer to `new_len` bytes.
     ///
     /// If `new_len` is less than the current effective length, the method zeroes out the removed portion
     /// and updates the effective length accordingly. If `new_len` is greater than or equal to the current length,
-    /// no changes are made.
+    /// no changes are made. Cutting inside a multi‑byte character clears
+    /// [`FixedStrBuf::is_utf8_guaranteed`].
     pub fn truncate(&mut self, new_len: usize) {
         if new_len < self.len {
+            // A UTF‑8 continuation byte at the cut means a character was split.
+            self.utf8 &= (self.buffer[new_len] as i8) >= -0x40;
             for i in new_len..self.len {
                 self.buffer[i] = 0;
             }
@@ -143,7 +462,7 @@ impl<const N: usize> FixedStrBuf<N> {
 
     /// Converts the effective bytes of the buffer to a `String` in a lossy manner,
     /// replacing any invalid UTF‑8 sequences with the Unicode replacement character.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn to_string_lossy(&self) -> String {
         String::from_utf8_lossy(self.effective_bytes()).into_owned()
     }
@@ -156,24 +475,45 @@ impl<const N: usize> FixedStrBuf<N> {
 impl<const N: usize> fmt::Display for FixedStrBuf<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = core::str::from_utf8(&self.buffer[..self.len]).unwrap_or("<invalid UTF-8>");
-        write!(f, "{}", s)
+        f.pad(s)
     }
 }
 
+/// The alternate form (`{:#?}`) prints the capacity, length, content and a hex preview of
+/// the unused capacity.
 impl<const N: usize> fmt::Debug for FixedStrBuf<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return crate::string_helpers::debug_layout(f, "FixedStrBuf", &self.buffer, self.len);
+        }
         match str::from_utf8(&self.buffer[..self.len]) {
             Ok(s) => write!(f, "FixedStrBuf<{}>({:?})", N, s),
             Err(_) => write!(
                 f,
                 "FixedStrBuf<{}>(<invalid UTF-8>) {:?}",
                 N,
-                fast_format_hex::<384>(&self.buffer, 16, Some(8))
+                fast_format_hex_styled::<384>(&self.buffer, &default_hex_style())
             ),
         }
     }
 }
 
+/// Formats all `N` bytes of the buffer, including unused capacity, as lowercase hex digits.
+/// The alternate form (`{:#x}`) adds a `0x` prefix.
+impl<const N: usize> fmt::LowerHex for FixedStrBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::string_helpers::fmt_hex_bytes(&self.buffer, f, false)
+    }
+}
+
+/// Formats all `N` bytes of the buffer, including unused capacity, as uppercase hex digits.
+/// The alternate form (`{:#X}`) adds a `0x` prefix.
+impl<const N: usize> fmt::UpperHex for FixedStrBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::string_helpers::fmt_hex_bytes(&self.buffer, f, true)
+    }
+}
+
 impl<const N: usize> EffectiveBytes for FixedStrBuf<N> {
     /// Returns the effective bytes (up to the first null byte) from the internal buffer.
     fn effective_bytes(&self) -> &[u8] {
@@ -192,10 +532,12 @@ impl<const N: usize> Default for FixedStrBuf<N> {
         Self {
             buffer: [0; N],
             len: 0,
+            utf8: true,
         }
     }
 }
 
+#[cfg(feature = "deref_bytes")]
 impl<const N: usize> core::ops::Deref for FixedStrBuf<N> {
     type Target = [u8];
     fn deref(&self) -> &Self::Target {
@@ -212,10 +554,7 @@ impl<const N: usize> core::ops::Deref for FixedStrBuf<N> {
 /// Panics if `N == 0`. Zero‑length strings are not supported.
 impl<const N: usize> From<FixedStr<N>> for FixedStrBuf<N> {
     fn from(fixed: FixedStr<N>) -> Self {
-        Self {
-            buffer: fixed.data,
-            len: fixed.len(),
-        }
+        Self::from_raw_parts(fixed.data, fixed.len())
     }
 }
 
@@ -231,13 +570,20 @@ impl<const N: usize> core::convert::TryFrom<&[u8]> for FixedStrBuf<N> {
     fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
         let buf = copy_into_buffer(slice, BufferCopyMode::Exact)?;
         let effective_len = find_first_null(&buf);
-        Ok(Self {
-            buffer: buf,
-            len: effective_len,
-        })
+        Ok(Self::from_raw_parts(buf, effective_len))
+    }
+}
+
+/// Compares the whole buffer and the length; whether the content is known to be valid
+/// UTF‑8 is not part of the value.
+impl<const N: usize> PartialEq for FixedStrBuf<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.buffer == other.buffer && self.len == other.len
     }
 }
 
+impl<const N: usize> Eq for FixedStrBuf<N> {}
+
 impl<const N: usize> Hash for FixedStrBuf<N> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         // Only hash the effective bytes (up to the first null) to represent the visible string.
@@ -267,36 +613,42 @@ impl<const N: usize> PartialOrd for FixedStrBuf<N> {
     }
 }
 
+#[cfg(feature = "cmp_foreign")]
 impl<const N: usize> PartialEq<[u8]> for FixedStrBuf<N> {
     fn eq(&self, other: &[u8]) -> bool {
         self.effective_bytes() == other.effective_bytes()
     }
 }
 
+#[cfg(feature = "cmp_foreign")]
 impl<const N: usize> PartialEq<FixedStrBuf<N>> for [u8] {
     fn eq(&self, other: &FixedStrBuf<N>) -> bool {
         self.effective_bytes() == other.effective_bytes()
     }
 }
 
+#[cfg(feature = "cmp_foreign")]
 impl<const N: usize> PartialEq<&[u8]> for FixedStrBuf<N> {
     fn eq(&self, other: &&[u8]) -> bool {
         self.effective_bytes() == other.effective_bytes()
     }
 }
 
+#[cfg(feature = "cmp_foreign")]
 impl<const N: usize> PartialEq<FixedStrBuf<N>> for &[u8] {
     fn eq(&self, other: &FixedStrBuf<N>) -> bool {
         self.effective_bytes() == other.effective_bytes()
     }
 }
 
+#[cfg(feature = "cmp_foreign")]
 impl<const N: usize> PartialEq<[u8; N]> for FixedStrBuf<N> {
     fn eq(&self, other: &[u8; N]) -> bool {
         self.effective_bytes() == other.effective_bytes()
     }
 }
 
+#[cfg(feature = "cmp_foreign")]
 impl<const N: usize> PartialEq<FixedStrBuf<N>> for [u8; N] {
     fn eq(&self, other: &FixedStrBuf<N>) -> bool {
         self.effective_bytes() == other.effective_bytes()
@@ -315,14 +667,14 @@ impl<const N: usize> PartialEq<FixedStrBuf<N>> for FixedStr<N> {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "alloc", feature = "cmp_foreign"))]
 impl<const N: usize> PartialEq<Vec<u8>> for FixedStrBuf<N> {
     fn eq(&self, other: &Vec<u8>) -> bool {
         self.effective_bytes() == other.effective_bytes()
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "alloc", feature = "cmp_foreign"))]
 impl<const N: usize> PartialEq<FixedStrBuf<N>> for Vec<u8> {
     fn eq(&self, other: &FixedStrBuf<N>) -> bool {
         self.effective_bytes() == other.effective_bytes()
@@ -364,15 +716,98 @@ mod buffer_tests {
     #[test]
     fn test_push_str_lossy() {
         let mut buf = FixedStrBuf::<5>::new();
-        // "Hello" fits exactly, so push_str_lossy returns true.
-        assert!(buf.push_str_lossy("Hello"));
+        // "Hello" fits exactly, so push_str_lossy reports a complete push.
+        assert_eq!(buf.push_str_lossy("Hello"), PushOutcome::Complete);
         // Any additional push will result in truncation.
         let result = buf.push_str_lossy(", world!");
-        assert!(!result);
+        assert_eq!(
+            result,
+            PushOutcome::Truncated {
+                pushed: 0,
+                dropped: 8
+            }
+        );
+        assert!(result.is_truncated());
+        assert_eq!(result.dropped(), 8);
+        assert_eq!(PushOutcome::Complete.dropped(), 0);
         let fixed: FixedStr<5> = buf.finalize();
         assert_eq!(fixed.as_str(), "Hello");
     }
 
+    #[test]
+    fn test_push_uint_radix() {
+        let mut buf = FixedStrBuf::<64>::new();
+        buf.push_uint_radix(5, 2, true).unwrap();
+        buf.push_uint_radix(8, 8, true).unwrap();
+        buf.push_uint_radix(0, 16, true).unwrap();
+        buf.push_uint_radix(35, 36, true).unwrap();
+        buf.push_uint_radix(255, 10, false).unwrap();
+        assert_eq!(buf.finalize().as_str(), "0b1010o100x0z255");
+
+        let mut buf = FixedStrBuf::<4>::new();
+        assert_eq!(
+            buf.push_uint_radix(0x100, 16, true),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 5
+            })
+        );
+        assert!(buf.is_empty());
+        let mut buf = FixedStrBuf::<128>::new();
+        buf.push_uint_radix(u128::MAX, 2, false).unwrap();
+        assert_eq!(buf.len(), 128);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_push_uint_radix_invalid() {
+        let _ = FixedStrBuf::<8>::new().push_uint_radix(1, 37, false);
+    }
+
+    #[test]
+    fn test_append_overflowing() {
+        let mut buf = FixedStrBuf::<4>::new();
+        assert!(buf.append_overflowing("ab").is_none());
+        // "é" would straddle the boundary, so it moves to the overflow builder as a whole.
+        let (overflow, rest) = buf.append_overflowing("cé!").unwrap();
+        assert_eq!(buf.finalize().as_str(), "abc");
+        assert_eq!(overflow.finalize().as_str(), "é!");
+        assert_eq!(rest, "");
+    }
+
+    #[test]
+    fn test_append_overflowing_longer_than_capacity() {
+        let mut buf = FixedStrBuf::<3>::new();
+        let (mut overflow, rest) = buf.append_overflowing("abcdeféghi").unwrap();
+        assert_eq!(buf.finalize().as_str(), "abc");
+        // "é" does not fit after "def", so it stays in the rest.
+        assert_eq!(overflow.finalize().as_str(), "def");
+        assert_eq!(rest, "éghi");
+
+        let (last, rest) = overflow.append_overflowing(rest).unwrap();
+        assert_eq!(last.finalize().as_str(), "ég");
+        assert_eq!(rest, "hi");
+    }
+
+    #[test]
+    fn test_push_with_remaining() {
+        let mut buf = FixedStrBuf::<8>::new();
+        assert_eq!(buf.try_push_str_with_remaining("abc"), Ok(5));
+        assert_eq!(buf.try_push_char_with_remaining('é'), Ok(3));
+        assert!(buf.try_push_str_with_remaining("toolong").is_err());
+        assert_eq!(
+            buf.push_str_lossy_with_remaining("xyzw"),
+            (
+                PushOutcome::Truncated {
+                    pushed: 3,
+                    dropped: 1
+                },
+                0
+            )
+        );
+        assert_eq!(buf.finalize().as_str(), "abcéxyz");
+    }
+
     #[test]
     fn test_finalize_trailing_zeros() {
         let mut buf = FixedStrBuf::<10>::new();
@@ -392,12 +827,12 @@ mod buffer_tests {
 
         buf.clear();
         assert_eq!(buf.len(), 0);
-        assert_eq!(&buf, &[0u8; 10]);
+        assert_eq!(buf.buffer, [0u8; 10]);
 
         // Can reuse safely
         buf.try_push_str("Rust").unwrap();
         assert_eq!(buf.len(), 4);
-        assert_eq!(&buf[..4], b"Rust");
+        assert_eq!(&buf.buffer[..4], b"Rust");
     }
 
     #[test]
@@ -482,4 +917,74 @@ mod buffer_tests {
         assert_eq!(bytes[..3], *b"Hey");
         assert_eq!(bytes[3..], [0u8; 2]);
     }
+
+    #[test]
+    fn test_chars() {
+        let mut buf = FixedStrBuf::<8>::new();
+        assert_eq!(buf.chars().next(), None);
+        buf.try_push_str("ab").unwrap();
+        buf.try_push_char('€').unwrap();
+        assert!(buf.chars().eq("ab€".chars()));
+        assert_eq!(buf.chars().next_back(), Some('€'));
+        let raw = FixedStrBuf::<4>::try_from(&b"a\xFFb"[..]).unwrap();
+        assert!(raw.chars().eq(['a']));
+    }
+
+    #[test]
+    fn test_finalize_strict() {
+        let mut buf = FixedStrBuf::<6>::new();
+        buf.try_push_str("Hi").unwrap();
+        assert_eq!(buf.finalize_strict().unwrap().as_bytes(), b"Hi\0\0\0\0");
+        let raw = FixedStrBuf::<6>::try_from(&b"a\0\xFF"[..]).unwrap();
+        // Bytes after a null byte are not part of the content.
+        assert_eq!(raw.finalize_strict().unwrap().as_bytes(), b"a\0\0\0\0\0");
+        let raw = FixedStrBuf::<6>::try_from(&b"ab\xE2\x82"[..]).unwrap();
+        assert_eq!(
+            raw.finalize_strict(),
+            Err(FixedStrError::InvalidUtf8 { valid_up_to: 2 })
+        );
+    }
+
+    #[test]
+    fn test_push_bytes() {
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.try_push_bytes(b"ab").unwrap();
+        assert!(buf.is_utf8_guaranteed());
+        // Half of "€" makes the content invalid until the rest arrives.
+        buf.push_bytes_unchecked(b"\xE2\x82").unwrap();
+        assert!(!buf.is_utf8_guaranteed());
+        assert_eq!(
+            buf.try_push_bytes(b"x"),
+            Err(FixedStrError::InvalidUtf8 { valid_up_to: 2 })
+        );
+        assert_eq!(buf.len(), 4);
+        assert_eq!(buf.buffer[4], 0);
+        buf.try_push_bytes(b"\xAC").unwrap();
+        assert!(buf.is_utf8_guaranteed());
+        assert_eq!(buf.try_as_str(), Ok("ab€"));
+        assert!(matches!(
+            buf.push_bytes_unchecked(b"abcd"),
+            Err(FixedStrError::Overflow { .. })
+        ));
+        assert!(FixedStrBuf::<4>::try_from(&b"\xFF"[..]).map_or(false, |b| !b.is_utf8_guaranteed()));
+        buf.clear();
+        assert_eq!(buf, FixedStrBuf::new());
+    }
+
+    #[test]
+    fn test_truncate_utf8_flag() {
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.try_push_str("é").unwrap();
+        buf.truncate(1);
+        assert!(!buf.is_utf8_guaranteed());
+        buf.push_bytes_unchecked(b"a").unwrap();
+        assert!(!buf.is_utf8_guaranteed());
+        assert_eq!(
+            buf.try_push_bytes(b"a"),
+            Err(FixedStrError::InvalidUtf8 { valid_up_to: 0 })
+        );
+        buf.truncate(0);
+        buf.try_push_bytes(b"a").unwrap();
+        assert!(buf.is_utf8_guaranteed());
+    }
 }
@@ -0,0 +1,146 @@
+// fixed_str/src/fs_checked.rs
+
+use super::*;
+
+/// Returns `FixedStrError::ZeroCapacity` for `N == 0` instead of panicking.
+const fn check_capacity<const N: usize>() -> Result<(), FixedStrError> {
+    if N == 0 {
+        Err(FixedStrError::ZeroCapacity)
+    } else {
+        Ok(())
+    }
+}
+
+impl<const N: usize> FixedStr<N> {
+    /// Creates a new `FixedStr` like [`FixedStr::new`], but returns an error instead of panicking.
+    ///
+    /// Overlong input is truncated at the last valid UTF‑8 boundary, or follows the installed
+    /// [`TruncationPolicy`] with the `truncation_policy` feature.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::ZeroCapacity` if `N == 0`.
+    /// - With the `truncation_policy` feature, returns `FixedStrError::Overflow` if the input
+    ///   does not fit and the installed policy is `TruncationPolicy::Error`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, FixedStrError};
+    ///
+    /// assert_eq!(FixedStr::<5>::checked_new("Hello, World!").unwrap().as_str(), "Hello");
+    /// assert_eq!(FixedStr::<0>::checked_new("Hi"), Err(FixedStrError::ZeroCapacity));
+    /// ```
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn checked_new(input: &str) -> Result<Self, FixedStrError> {
+        check_capacity::<N>()?;
+        #[cfg(feature = "truncation_policy")]
+        {
+            Self::store_with_policy(input, crate::truncation_policy::truncation_policy())
+        }
+        #[cfg(not(feature = "truncation_policy"))]
+        {
+            let data = copy_into_array(input.as_bytes(), BufferCopyMode::Truncate)?;
+            Ok(Self { data })
+        }
+    }
+
+    /// Creates a `FixedStr` from a byte slice like [`FixedStr::from_slice`], but returns an
+    /// error instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::ZeroCapacity` if `N == 0`.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn checked_from_slice(input: &[u8]) -> Result<Self, FixedStrError> {
+        check_capacity::<N>()?;
+        let data = copy_into_array(input, BufferCopyMode::Truncate)?;
+        Ok(Self { data })
+    }
+
+    /// Creates a `FixedStr` from a byte array like [`FixedStr::from_bytes`], but returns an
+    /// error instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::ZeroCapacity` if `N == 0`.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn checked_from_bytes(bytes: [u8; N]) -> Result<Self, FixedStrError> {
+        Self::checked_from_slice(&bytes)
+    }
+
+    /// Replaces the content like [`FixedStr::set`], but returns an error instead of panicking.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::ZeroCapacity` if `N == 0`.
+    /// - Returns `FixedStrError::Overflow` if the input exceeds the capacity. The content is
+    ///   left unchanged.
+    pub fn checked_set(&mut self, input: &str) -> Result<(), FixedStrError> {
+        check_capacity::<N>()?;
+        self.data = copy_into_array(input.effective_bytes(), BufferCopyMode::Exact)?;
+        Ok(())
+    }
+
+    /// Replaces the content like [`FixedStr::set_lossy`], but returns an error instead of
+    /// panicking.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::ZeroCapacity` if `N == 0`.
+    /// - With the `truncation_policy` feature, returns `FixedStrError::Overflow` if the input
+    ///   does not fit and the installed policy is `TruncationPolicy::Error`. The content is
+    ///   left unchanged.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn checked_set_lossy(&mut self, input: &str) -> Result<(), FixedStrError> {
+        *self = Self::checked_new(input)?;
+        Ok(())
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod checked_tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_constructors() {
+        assert_eq!(FixedStr::<3>::checked_new("héllo").unwrap().as_str(), "hé");
+        assert_eq!(
+            FixedStr::<4>::checked_from_slice(b"ab\0c")
+                .unwrap()
+                .as_str(),
+            "ab"
+        );
+        assert_eq!(
+            FixedStr::<2>::checked_from_bytes(*b"ok").unwrap().as_str(),
+            "ok"
+        );
+
+        assert_eq!(
+            FixedStr::<0>::checked_new("x"),
+            Err(FixedStrError::ZeroCapacity)
+        );
+        assert_eq!(
+            FixedStr::<0>::checked_from_slice(b""),
+            Err(FixedStrError::ZeroCapacity)
+        );
+        assert_eq!(
+            FixedStr::<0>::checked_from_bytes([]),
+            Err(FixedStrError::ZeroCapacity)
+        );
+    }
+
+    #[test]
+    fn test_checked_set() {
+        let mut fixed = FixedStr::<4>::new("abc");
+        assert!(fixed.checked_set("abcde").is_err());
+        assert_eq!(fixed.as_str(), "abc");
+        fixed.checked_set_lossy("wxyz!").unwrap();
+        assert_eq!(fixed.as_str(), "wxyz");
+
+        let mut empty = FixedStr::<0>::default();
+        assert_eq!(empty.checked_set(""), Err(FixedStrError::ZeroCapacity));
+        assert_eq!(
+            empty.checked_set_lossy(""),
+            Err(FixedStrError::ZeroCapacity)
+        );
+    }
+}
@@ -0,0 +1,184 @@
+// fixed_str/src/fs_chunks.rs
+
+use super::*;
+
+/// Packs a sequence of strings into `FixedStr<N>` chunks, joined by a separator.
+///
+/// Items are added to the current chunk greedily, together with the separator, as long as
+/// they fit. An item is never split across two chunks: if it does not fit into the remainder
+/// of the current chunk, it starts the next one.
+///
+/// **Note:** An item that is longer than `N` on its own cannot be kept whole. It is emitted
+/// as a single chunk and truncated at the last valid UTF‑8 boundary.
+///
+/// # Examples
+/// ```
+/// use fixed_str::collect_fixed_chunks;
+///
+/// let items = ["alpha", "beta", "gamma", "delta"];
+/// let chunks: Vec<_> = collect_fixed_chunks::<12, _>(items, ", ").collect();
+/// assert_eq!(chunks.len(), 2);
+/// assert_eq!(chunks[0].as_str(), "alpha, beta");
+/// assert_eq!(chunks[1].as_str(), "gamma, delta");
+/// ```
+///
+/// # Panics
+/// Panics if `N == 0`. Zero‑length strings are not supported.
+pub fn collect_fixed_chunks<'a, const N: usize, I>(
+    iter: I,
+    sep: &'a str,
+) -> FixedChunks<'a, I::IntoIter, N>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    require_capacity::<N>();
+    FixedChunks {
+        iter: iter.into_iter(),
+        sep,
+        pending: None,
+    }
+}
+
+/// An iterator producing `FixedStr<N>` chunks of separator‑joined items.
+///
+/// Created by [`collect_fixed_chunks`].
+pub struct FixedChunks<'a, I, const N: usize> {
+    iter: I,
+    sep: &'a str,
+    /// An item that did not fit into the previous chunk.
+    pending: Option<&'a str>,
+}
+
+impl<'a, I, const N: usize> Iterator for FixedChunks<'a, I, N>
+where
+    I: Iterator<Item = &'a str>,
+{
+    type Item = FixedStr<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.pending.take().or_else(|| self.iter.next())?;
+        let mut buf = FixedStrBuf::<N>::new();
+        if buf.try_push_str(first).is_err() {
+            let _ = buf.push_str_lossy(first);
+            return Some(buf.finalize());
+        }
+
+        for item in self.iter.by_ref() {
+            let len = buf.len();
+            if buf.try_push_str(self.sep).is_err() || buf.try_push_str(item).is_err() {
+                buf.truncate(len);
+                self.pending = Some(item);
+                break;
+            }
+        }
+        Some(buf.finalize())
+    }
+}
+
+/// Splits `input` across the slots of `out`, filling each slot as far as possible.
+///
+/// Every slot receives up to `N` bytes, cut at a valid UTF‑8 boundary, so characters are
+/// never split between slots. Slots are filled in order until either the input or the
+/// slots run out; unused slots are left untouched.
+///
+/// Returns the number of slots written and the part of the input that did not fit.
+///
+/// **Note:** If the input contains a null byte (`\0`), it ends there and any content
+/// after the first null byte is ignored.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{fill_slice_from_str, FixedStr};
+///
+/// let mut packets = [FixedStr::<4>::default(); 2];
+/// let (used, rest) = fill_slice_from_str(&mut packets, "Hello, world");
+/// assert_eq!(used, 2);
+/// assert_eq!(packets[0].as_str(), "Hell");
+/// assert_eq!(packets[1].as_str(), "o, w");
+/// assert_eq!(rest, "orld");
+/// ```
+///
+/// # Panics
+/// Panics if `N == 0`. Zero‑length strings are not supported.
+pub fn fill_slice_from_str<'a, const N: usize>(
+    out: &mut [FixedStr<N>],
+    input: &'a str,
+) -> (usize, &'a str) {
+    require_capacity::<N>();
+    let mut rest = &input[..find_first_null(input.as_bytes())];
+    let mut used = 0;
+    while used < out.len() && !rest.is_empty() {
+        let take = find_valid_boundary(rest.as_bytes(), N);
+        if take == 0 {
+            // The next character is wider than a whole slot.
+            break;
+        }
+        out[used] = FixedStr::from_slice(&rest.as_bytes()[..take]);
+        rest = &rest[take..];
+        used += 1;
+    }
+    (used, rest)
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod chunk_tests {
+    use super::*;
+
+    #[test]
+    fn test_chunks_pack_greedily() {
+        let items = ["a", "bb", "ccc", "dddd"];
+        let mut chunks = collect_fixed_chunks::<6, _>(items, ",");
+        assert_eq!(chunks.next().unwrap().as_str(), "a,bb");
+        assert_eq!(chunks.next().unwrap().as_str(), "ccc");
+        assert_eq!(chunks.next().unwrap().as_str(), "dddd");
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_chunks_exact_fit() {
+        let items = ["ab", "cd"];
+        let mut chunks = collect_fixed_chunks::<5, _>(items, "|");
+        assert_eq!(chunks.next().unwrap().as_str(), "ab|cd");
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_chunks_oversized_item_is_truncated_alone() {
+        let items = ["ok", "much too long", "end"];
+        let mut chunks = collect_fixed_chunks::<4, _>(items, " ");
+        assert_eq!(chunks.next().unwrap().as_str(), "ok");
+        assert_eq!(chunks.next().unwrap().as_str(), "much");
+        assert_eq!(chunks.next().unwrap().as_str(), "end");
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_fill_slice_respects_boundaries() {
+        let mut out = [FixedStr::<3>::default(); 4];
+        let (used, rest) = fill_slice_from_str(&mut out, "aébéé");
+        assert_eq!(used, 3);
+        assert_eq!(out[0].as_str(), "aé");
+        assert_eq!(out[1].as_str(), "bé");
+        assert_eq!(out[2].as_str(), "é");
+        assert!(out[3].is_empty());
+        assert_eq!(rest, "");
+    }
+
+    #[test]
+    fn test_fill_slice_char_wider_than_slot() {
+        let mut out = [FixedStr::<2>::default(); 2];
+        let (used, rest) = fill_slice_from_str(&mut out, "a😊");
+        assert_eq!(used, 1);
+        assert_eq!(rest, "😊");
+    }
+
+    #[test]
+    fn test_chunks_empty_input() {
+        let mut chunks = collect_fixed_chunks::<4, _>(core::iter::empty(), ",");
+        assert!(chunks.next().is_none());
+    }
+}
@@ -0,0 +1,149 @@
+// fixed_str/src/fs_codepage.rs
+
+use super::*;
+
+/// The characters of CP437 bytes `0x80..=0xFF`; bytes below `0x80` are ASCII.
+#[rustfmt::skip]
+const CP437_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', // 0x80
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ', // 0x90
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»', // 0xA0
+    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐', // 0xB0
+    '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧', // 0xC0
+    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀', // 0xD0
+    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', // 0xE0
+    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{A0}', // 0xF0
+];
+
+/// Maps a CP437 byte to its character.
+fn cp437_to_char(byte: u8) -> char {
+    if byte < 0x80 {
+        char::from(byte)
+    } else {
+        CP437_HIGH[usize::from(byte - 0x80)]
+    }
+}
+
+/// Maps a character to its CP437 byte, if it has one.
+fn char_to_cp437(c: char) -> Option<u8> {
+    if c.is_ascii() {
+        return Some(c as u8);
+    }
+    CP437_HIGH
+        .iter()
+        .position(|&high| high == c)
+        .map(|index| 0x80 + index as u8)
+}
+
+impl<const N: usize> FixedStr<N> {
+    /// Decodes single‑byte input up to its first null byte, truncating after the last
+    /// character that fits.
+    fn decode_single_byte(input: &[u8], decode: impl Fn(u8) -> char) -> Self {
+        let mut buf = FixedStrBuf::<N>::new();
+        for &byte in input.effective_bytes() {
+            if buf.try_push_char(decode(byte)).is_err() {
+                break;
+            }
+        }
+        buf.finalize()
+    }
+
+    /// Encodes the effective string one byte per character, replacing unmappable ones.
+    fn encode_single_byte(&self, encode: impl Fn(char) -> Option<u8>) -> [u8; N] {
+        // Every character needs at least one UTF‑8 byte, so the result always fits.
+        let mut out = [0u8; N];
+        for (slot, c) in out.iter_mut().zip(self.as_str().chars()) {
+            *slot = encode(c).unwrap_or(b'?');
+        }
+        out
+    }
+
+    /// Creates a `FixedStr` from ISO‑8859‑1 (Latin‑1) encoded bytes.
+    ///
+    /// Each byte is decoded to the character with the same code point. Bytes from `0x80`
+    /// take two bytes in UTF‑8, so the result is truncated after the last character that
+    /// fits into `N` bytes. The input ends at its first null byte (`\0`).
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let fs = FixedStr::<8>::from_latin1(b"caf\xE9");
+    /// assert_eq!(fs.as_str(), "café");
+    /// assert_eq!(&fs.to_latin1_lossy()[..5], b"caf\xE9\0");
+    /// ```
+    pub fn from_latin1(input: &[u8]) -> Self {
+        Self::decode_single_byte(input, char::from)
+    }
+
+    /// Encodes the effective string as ISO‑8859‑1 (Latin‑1), zero‑padded to `N` bytes.
+    ///
+    /// Characters above `U+00FF` are replaced with `?`.
+    pub fn to_latin1_lossy(&self) -> [u8; N] {
+        self.encode_single_byte(|c| u8::try_from(u32::from(c)).ok())
+    }
+
+    /// Creates a `FixedStr` from bytes in code page 437, the character set of DOS‑era formats.
+    ///
+    /// Bytes below `0x80` are decoded as ASCII, the others as CP437's accented letters,
+    /// box‑drawing and mathematical symbols. As with [`FixedStr::from_latin1`], the result is
+    /// truncated after the last character that fits, and the input ends at its first null byte.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let fs = FixedStr::<16>::from_cp437(b"\xC9\xCD\xBB 1\xF8");
+    /// assert_eq!(fs.as_str(), "╔═╗ 1°");
+    /// assert_eq!(&fs.to_cp437_lossy()[..6], b"\xC9\xCD\xBB 1\xF8");
+    /// ```
+    pub fn from_cp437(input: &[u8]) -> Self {
+        Self::decode_single_byte(input, cp437_to_char)
+    }
+
+    /// Encodes the effective string in code page 437, zero‑padded to `N` bytes.
+    ///
+    /// Characters without a CP437 byte are replaced with `?`.
+    pub fn to_cp437_lossy(&self) -> [u8; N] {
+        self.encode_single_byte(char_to_cp437)
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod codepage_tests {
+    use super::*;
+
+    #[test]
+    fn test_latin1() {
+        let all: [u8; 255] = core::array::from_fn(|i| i as u8 + 1);
+        let fixed = FixedStr::<512>::from_latin1(&all);
+        assert_eq!(fixed.as_str().chars().count(), 255);
+        assert_eq!(fixed.to_latin1_lossy()[..255], all[..]);
+
+        // "é" needs two bytes and does not fit.
+        assert_eq!(FixedStr::<4>::from_latin1(b"abc\xE9"), FixedStr::new("abc"));
+        assert_eq!(FixedStr::<4>::from_latin1(b"a\0b"), FixedStr::new("a"));
+        assert_eq!(&FixedStr::<8>::new("a€b").to_latin1_lossy()[..4], b"a?b\0");
+    }
+
+    #[test]
+    fn test_cp437_round_trip() {
+        let high: [u8; 128] = core::array::from_fn(|i| 0x80 + i as u8);
+        let fixed = FixedStr::<512>::from_cp437(&high);
+        assert_eq!(fixed.to_cp437_lossy()[..128], high[..]);
+        assert_eq!(
+            &FixedStr::<8>::new("aé€").to_cp437_lossy()[..4],
+            b"a\x82?\0"
+        );
+    }
+}
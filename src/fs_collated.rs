@@ -0,0 +1,158 @@
+// fixed_str/src/fs_collated.rs
+
+use super::*;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// A wrapper that orders strings for humans rather than by bytes.
+///
+/// `FixedStr` orders by its UTF‑8 bytes, which puts every uppercase letter before every
+/// lowercase one and every accented letter after `z`. `Collated` implements a locale‑free
+/// subset of the Unicode Collation Algorithm on the canonical decomposition (NFD) of the
+/// content, comparing level by level:
+///
+/// 1. the base characters, ignoring case and accents;
+/// 2. the accents (combining marks), by code point;
+/// 3. the case, lowercase first;
+/// 4. the bytes, so that only identical strings compare equal.
+///
+/// It wraps any `T: AsRef<str>`, such as [`FixedStr`] or [`AsciiFixedStr`] (requires the
+/// `unicode_normalization` feature).
+///
+/// # Examples
+/// ```
+/// use fixed_str::{Collated, FixedStr};
+///
+/// let mut names = ["b", "É", "a", "B", "e"].map(|s| Collated(FixedStr::<4>::new(s)));
+/// names.sort();
+/// assert_eq!(names.map(|name| name.0.as_str().len()), [1, 1, 1, 1, 2]);
+/// assert_eq!(names[1].0.as_str(), "b");
+/// assert_eq!(names[4].0.as_str(), "É");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Collated<T>(pub T);
+
+impl<T> Collated<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: AsRef<str>> Collated<T> {
+    /// Compares with a value of any wrapped type, following the order described on
+    /// [`Collated`].
+    pub fn cmp_collated<U: AsRef<str>>(&self, other: &Collated<U>) -> Ordering {
+        collate(self.0.as_ref(), other.0.as_ref())
+    }
+}
+
+/// Compares two strings level by level, as described on [`Collated`].
+fn collate(a: &str, b: &str) -> Ordering {
+    primary_key(a)
+        .cmp(primary_key(b))
+        .then_with(|| secondary_key(a).cmp(secondary_key(b)))
+        .then_with(|| tertiary_key(a).cmp(tertiary_key(b)))
+        .then_with(|| a.cmp(b))
+}
+
+/// The base characters, case‑folded.
+fn primary_key(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.nfd()
+        .filter(|&c| !is_combining_mark(c))
+        .flat_map(char::to_lowercase)
+}
+
+/// The marks of each base character, with `None` separating the base characters.
+fn secondary_key(s: &str) -> impl Iterator<Item = Option<char>> + '_ {
+    s.nfd().map(|c| Some(c).filter(|&c| is_combining_mark(c)))
+}
+
+/// Whether each base character is uppercase.
+fn tertiary_key(s: &str) -> impl Iterator<Item = bool> + '_ {
+    s.nfd()
+        .filter(|&c| !is_combining_mark(c))
+        .map(char::is_uppercase)
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<T> From<T> for Collated<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: AsRef<str>, U: AsRef<str>> PartialEq<Collated<U>> for Collated<T> {
+    fn eq(&self, other: &Collated<U>) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+impl<T: AsRef<str>> Eq for Collated<T> {}
+
+impl<T: AsRef<str>, U: AsRef<str>> PartialOrd<Collated<U>> for Collated<T> {
+    fn partial_cmp(&self, other: &Collated<U>) -> Option<Ordering> {
+        Some(self.cmp_collated(other))
+    }
+}
+
+impl<T: AsRef<str>> Ord for Collated<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_collated(other)
+    }
+}
+
+/// Hashes the string content, consistent with equality.
+impl<T: AsRef<str>> Hash for Collated<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ref().hash(state);
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Collated<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod collated_tests {
+    use super::*;
+
+    fn sorted<const K: usize>(names: [&str; K]) -> [&str; K] {
+        let mut names = names.map(Collated);
+        names.sort();
+        names.map(Collated::into_inner)
+    }
+
+    #[test]
+    fn test_levels() {
+        // Case and accents only matter when the base characters are equal.
+        assert_eq!(sorted(["b", "B", "a", "A"]), ["a", "A", "b", "B"]);
+        assert_eq!(sorted(["f", "\u{e9}", "e", "E"]), ["e", "E", "\u{e9}", "f"]);
+        assert_eq!(
+            sorted(["cote", "c\u{f4}t\u{e9}", "cot\u{e9}"]),
+            ["cote", "cot\u{e9}", "c\u{f4}t\u{e9}"]
+        );
+        assert_eq!(sorted(["ab", "a", "Ab"]), ["a", "ab", "Ab"]);
+    }
+
+    #[test]
+    fn test_equality() {
+        let composed = Collated(FixedStr::<4>::new("\u{e9}"));
+        let decomposed = Collated(FixedStr::<4>::new("e\u{301}"));
+        // Canonically equivalent strings are adjacent but still distinct.
+        assert_ne!(composed, decomposed);
+        assert_eq!(composed.cmp_collated(&decomposed), "\u{e9}".cmp("e\u{301}"));
+        assert!(decomposed < Collated(FixedStr::<4>::new("f")));
+        assert_eq!(Collated(FixedStr::<8>::new("x")), Collated("x"));
+    }
+}
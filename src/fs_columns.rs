@@ -0,0 +1,249 @@
+// fixed_str/src/fs_columns.rs
+
+use super::*;
+
+/// How a [`Column`] trims ASCII whitespace around its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trim {
+    /// Keeps the content as‑is.
+    None,
+    /// Removes leading whitespace.
+    Start,
+    /// Removes trailing whitespace, the usual padding of fixed‑width files.
+    End,
+    /// Removes leading and trailing whitespace.
+    Both,
+}
+
+impl Trim {
+    /// Applies the policy to `bytes`.
+    fn apply(self, mut bytes: &[u8]) -> &[u8] {
+        if matches!(self, Self::Start | Self::Both) {
+            while let [first, rest @ ..] = bytes {
+                if !first.is_ascii_whitespace() {
+                    break;
+                }
+                bytes = rest;
+            }
+        }
+        if matches!(self, Self::End | Self::Both) {
+            while let [rest @ .., last] = bytes {
+                if !last.is_ascii_whitespace() {
+                    break;
+                }
+                bytes = rest;
+            }
+        }
+        bytes
+    }
+}
+
+impl Default for Trim {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// A column of a fixed‑width text line: `width` bytes starting at byte `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Column {
+    /// The byte offset of the column within the line.
+    pub start: usize,
+    /// The width of the column in bytes.
+    pub width: usize,
+    /// How whitespace around the content is trimmed.
+    pub trim: Trim,
+}
+
+impl Column {
+    /// Creates a column that keeps its content as‑is.
+    pub const fn new(start: usize, width: usize) -> Self {
+        Self {
+            start,
+            width,
+            trim: Trim::None,
+        }
+    }
+
+    /// Returns the column with the given trim policy.
+    pub const fn trim(self, trim: Trim) -> Self {
+        Self { trim, ..self }
+    }
+}
+
+/// Splits fixed‑width text lines into `FixedStr<N>` fields by column.
+///
+/// Lines shorter than a column are treated as if padded, so a missing column is empty.
+/// With the `std` feature, [`ColumnLayout::read_lines`] iterates over the lines of a
+/// `BufRead`.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{Column, ColumnLayout, Trim};
+///
+/// const BANK: ColumnLayout<3> = ColumnLayout::new([
+///     Column::new(0, 8).trim(Trim::End),
+///     Column::new(8, 10).trim(Trim::Start),
+///     Column::new(18, 3),
+/// ]);
+///
+/// let [date, amount, currency] = BANK.split::<10>("20250325    129.50EUR").unwrap();
+/// assert_eq!(date.as_str(), "20250325");
+/// assert_eq!(amount.as_str(), "129.50");
+/// assert_eq!(currency.as_str(), "EUR");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColumnLayout<const K: usize> {
+    columns: [Column; K],
+}
+
+impl<const K: usize> ColumnLayout<K> {
+    /// Creates a layout from the given columns.
+    pub const fn new(columns: [Column; K]) -> Self {
+        Self { columns }
+    }
+
+    /// Returns the columns of the layout.
+    pub const fn columns(&self) -> &[Column; K] {
+        &self.columns
+    }
+
+    /// Splits one line into its fields. A trailing line ending is not stripped.
+    ///
+    /// # Errors
+    /// Returns `RecordError::Field` for the first column whose content exceeds `N` bytes
+    /// after trimming, or is not valid UTF‑8.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn split<const N: usize>(
+        &self,
+        line: impl AsRef<[u8]>,
+    ) -> Result<[FixedStr<N>; K], RecordError> {
+        require_capacity::<N>();
+        let line = line.as_ref();
+        let mut fields = [FixedStr { data: [0; N] }; K];
+        for (index, (field, column)) in fields.iter_mut().zip(&self.columns).enumerate() {
+            let start = column.start.min(line.len());
+            let end = column.start.saturating_add(column.width).min(line.len());
+            let content = column.trim.apply(&line[start..end]);
+            *field = FixedStr::try_from(content).map_err(|error| RecordError::Field {
+                index,
+                offset: column.start,
+                error,
+            })?;
+        }
+        Ok(fields)
+    }
+
+    /// Returns an iterator that splits each line of `reader` into its fields.
+    ///
+    /// Line endings (`\n` or `\r\n`) are stripped before splitting. A line that cannot be
+    /// split yields an `io::Error` of kind `InvalidData` wrapping the [`RecordError`].
+    #[cfg(feature = "std")]
+    pub fn read_lines<const N: usize, R: std::io::BufRead>(
+        &self,
+        reader: R,
+    ) -> ColumnLines<'_, R, N, K> {
+        ColumnLines {
+            layout: self,
+            reader,
+            line: Vec::new(),
+        }
+    }
+}
+
+/// An iterator over the fields of each line of a `BufRead` (see [`ColumnLayout::read_lines`]).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ColumnLines<'a, R, const N: usize, const K: usize> {
+    layout: &'a ColumnLayout<K>,
+    reader: R,
+    line: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::BufRead, const N: usize, const K: usize> Iterator for ColumnLines<'_, R, N, K> {
+    type Item = std::io::Result<[FixedStr<N>; K]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.clear();
+        match self.reader.read_until(b'\n', &mut self.line) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(e) => return Some(Err(e)),
+        }
+        let mut line = &self.line[..];
+        if let [rest @ .., b'\n'] = line {
+            line = rest;
+        }
+        if let [rest @ .., b'\r'] = line {
+            line = rest;
+        }
+        Some(
+            self.layout
+                .split(line)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e)),
+        )
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod columns_tests {
+    use super::*;
+
+    const LAYOUT: ColumnLayout<2> =
+        ColumnLayout::new([Column::new(0, 4).trim(Trim::Both), Column::new(4, 4)]);
+
+    #[test]
+    fn test_split() {
+        let [a, b] = LAYOUT.split::<4>(" ab abcd").unwrap();
+        assert_eq!((a.as_str(), b.as_str()), ("ab", "abcd"));
+        // Short lines leave the missing columns empty.
+        let [a, b] = LAYOUT.split::<4>(b"xy".as_slice()).unwrap();
+        assert_eq!((a.as_str(), b.as_str()), ("xy", ""));
+        assert_eq!(Trim::End.apply(b" a \t"), b" a");
+        assert_eq!(Trim::Start.apply(b"   "), b"");
+    }
+
+    #[test]
+    fn test_split_errors() {
+        assert_eq!(
+            LAYOUT.split::<2>("abc"),
+            Err(RecordError::Field {
+                index: 0,
+                offset: 0,
+                error: FixedStrError::Overflow {
+                    available: 2,
+                    found: 3
+                },
+            })
+        );
+        assert_eq!(
+            LAYOUT.split::<4>(b"abcd\xFF".as_slice()),
+            Err(RecordError::Field {
+                index: 1,
+                offset: 4,
+                error: FixedStrError::InvalidUtf8 { valid_up_to: 0 },
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_lines() {
+        let input = &b"ab  cd\r\nef\n\xFF\n"[..];
+        let mut lines = LAYOUT.read_lines::<4, _>(input);
+        let [a, b] = lines.next().unwrap().unwrap();
+        assert_eq!((a.as_str(), b.as_str()), ("ab", "cd"));
+        let [a, b] = lines.next().unwrap().unwrap();
+        assert_eq!((a.as_str(), b.as_str()), ("ef", ""));
+        let err = lines.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(lines.next().is_none());
+    }
+}
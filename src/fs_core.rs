@@ -17,12 +17,72 @@ use super::*;
 /// assert_eq!(fs.as_str(), "Hello");
 /// ```
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
 #[repr(transparent)]
 pub struct FixedStr<const N: usize> {
     pub(super) data: [u8; N],
 }
 
+/// Describes what a reporting lossy constructor such as [`FixedStr::new_lossy_report`] dropped.
+///
+/// Input after a null byte (`\0`) ends the string by design and is not counted as dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TruncationInfo {
+    /// The byte offset in the input where it was cut, i.e. the number of bytes kept.
+    pub cut_at: usize,
+    /// The number of bytes dropped.
+    pub bytes_dropped: usize,
+    /// The number of characters dropped.
+    pub chars_dropped: usize,
+}
+
+impl TruncationInfo {
+    /// Returns `true` if any input was dropped.
+    pub const fn is_truncated(&self) -> bool {
+        self.bytes_dropped > 0
+    }
+
+    /// Turns any loss into an error, for callers that treat truncation as a failure.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Truncated` if any input was dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, FixedStrError};
+    ///
+    /// let (_, info) = FixedStr::<4>::new_lossy_report("abcdef");
+    /// assert_eq!(info.check(), Err(FixedStrError::Truncated { kept: 4, dropped: 2 }));
+    /// ```
+    pub const fn check(&self) -> Result<(), FixedStrError> {
+        if self.is_truncated() {
+            Err(FixedStrError::Truncated {
+                kept: self.cut_at,
+                dropped: self.bytes_dropped,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
 impl<const N: usize> FixedStr<N> {
+    /// The sentinel value: a buffer with every byte set to `0xFF`.
+    ///
+    /// This matches the state of erased flash memory and is commonly used to mean "unset".
+    /// The sentinel is distinct from the empty string: it is neither `is_empty()` nor equal
+    /// to `""`, and it orders after every valid string.
+    pub const SENTINEL: Self = Self { data: [0xFF; N] };
+
     /// Returns the maximum capacity of the `FixedStr`.
     pub const fn capacity(&self) -> usize {
         N
@@ -35,7 +95,20 @@ impl<const N: usize> FixedStr<N> {
 
     /// Returns the number of valid bytes in the effective string (up to the first null byte).
     pub fn len(&self) -> usize {
-        find_first_null(self)
+        find_first_null(&self.data)
+    }
+
+    /// Returns the effective length like [`FixedStr::len`], in a const context.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// const NAME: FixedStr<8> = FixedStr::new_const("eth0");
+    /// const _: () = assert!(NAME.len_const() == 4);
+    /// ```
+    pub const fn len_const(&self) -> usize {
+        find_first_null_const(&self.data)
     }
 
     /// Returns whether the effective string is empty.
@@ -43,6 +116,11 @@ impl<const N: usize> FixedStr<N> {
         self.len() == 0
     }
 
+    /// Returns `true` if every byte of the buffer is `0xFF` (see [`FixedStr::SENTINEL`]).
+    pub fn is_sentinel(&self) -> bool {
+        self.data.iter().all(|&b| b == 0xFF)
+    }
+
     //****************************************************************************
     //  Constructors
     //****************************************************************************
@@ -69,11 +147,56 @@ impl<const N: usize> FixedStr<N> {
     /// assert_eq!(fs.as_str(), "Hello");
     /// ```
     ///
+    /// With the `truncation_policy` feature, overlong input follows the installed
+    /// [`TruncationPolicy`](crate::TruncationPolicy) instead.
+    ///
     /// # Panics
     /// Panics if `N == 0`. Zero‑length strings are not supported.
+    /// With the `truncation_policy` feature, also panics if the input does not fit and the
+    /// installed policy is `TruncationPolicy::Error`.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
     pub fn new(input: &str) -> Self {
-        let buf = copy_into_buffer(input.as_bytes(), BufferCopyMode::Truncate).unwrap();
-        Self { data: buf }
+        #[cfg(feature = "truncation_policy")]
+        {
+            crate::truncation_policy::apply_global(input)
+        }
+        #[cfg(not(feature = "truncation_policy"))]
+        {
+            let buf = copy_into_buffer(input.as_bytes(), BufferCopyMode::Truncate).unwrap();
+            Self { data: buf }
+        }
+    }
+
+    /// Creates a new `FixedStr` like [`FixedStr::new`], also reporting what was dropped.
+    ///
+    /// Overlong input is always truncated at the last valid UTF‑8 boundary; the installed
+    /// [`TruncationPolicy`](crate::TruncationPolicy) is not consulted.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let (fs, info) = FixedStr::<3>::new_lossy_report("añejo");
+    /// assert_eq!(fs.as_str(), "añ");
+    /// assert_eq!((info.cut_at, info.bytes_dropped, info.chars_dropped), (3, 3, 3));
+    /// ```
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn new_lossy_report(input: &str) -> (Self, TruncationInfo) {
+        let bytes = input.effective_bytes();
+        let data = copy_into_buffer(bytes, BufferCopyMode::Truncate).unwrap();
+        let fixed = Self { data };
+        let cut_at = fixed.len();
+        // `cut_at` is a character boundary of `input`, as truncation keeps valid UTF‑8.
+        let dropped = &input[cut_at..bytes.len()];
+        let info = TruncationInfo {
+            cut_at,
+            bytes_dropped: dropped.len(),
+            chars_dropped: dropped.chars().count(),
+        };
+        (fixed, info)
     }
 
     /// Creates a new `FixedStr` at compile time with safe truncation.
@@ -88,7 +211,7 @@ impl<const N: usize> FixedStr<N> {
     /// # Panics
     /// Panics if `N == 0`. Zero‑length strings are not supported.
     pub const fn new_const(input: &str) -> Self {
-        panic_on_zero(N);
+        require_capacity::<N>();
         let bytes = input.as_bytes();
         let mut buf = [0u8; N];
         let mut i = 0;
@@ -102,6 +225,15 @@ impl<const N: usize> FixedStr<N> {
         Self { data: buf }
     }
 
+    /// Returns the sentinel value, with every byte set to `0xFF`.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub const fn sentinel() -> Self {
+        require_capacity::<N>();
+        Self::SENTINEL
+    }
+
     /// Creates a `FixedStr` from a byte slice.
     ///
     /// If the slice is shorter than `N` bytes, all bytes are copied and the remaining
@@ -112,6 +244,7 @@ impl<const N: usize> FixedStr<N> {
     ///
     /// # Panics
     /// Panics if `N == 0`. Zero‑length strings are not supported.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
     pub fn from_slice(input: &[u8]) -> Self {
         Self {
             data: copy_into_buffer(input, BufferCopyMode::Truncate).unwrap(),
@@ -143,6 +276,7 @@ impl<const N: usize> FixedStr<N> {
     ///
     /// # Panics
     /// Panics if `N == 0`. Zero‑length strings are not supported.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
     pub fn from_bytes(bytes: [u8; N]) -> Self {
         Self {
             data: copy_into_buffer(&bytes, BufferCopyMode::Truncate).unwrap(),
@@ -165,6 +299,185 @@ impl<const N: usize> FixedStr<N> {
         }
     }
 
+    /// Creates a `FixedStr` from hex‑encoded bytes, such as a hex ID read from a fixed field.
+    ///
+    /// Each pair of hex digits (in either case) becomes one byte of the string, which is
+    /// zero‑padded. If the decoded bytes contain a null byte (`\0`), the string ends there.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::Overflow` if the input decodes to more than `N` bytes.
+    /// - Returns `FixedStrError::InvalidHex` if the input has an odd length or a character
+    ///   that is not a hex digit.
+    /// - Returns `FixedStrError::InvalidUtf8` if the decoded bytes are not valid UTF‑8.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let id = FixedStr::<8>::from_hex_bytes(b"49443432").unwrap();
+    /// assert_eq!(id.as_str(), "ID42");
+    /// ```
+    pub fn from_hex_bytes(hex: &[u8]) -> Result<Self, FixedStrError> {
+        require_capacity::<N>();
+        if hex.len() % 2 == 1 {
+            return Err(FixedStrError::InvalidHex {
+                position: hex.len(),
+            });
+        }
+        let len = hex.len() / 2;
+        if len > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: len,
+            });
+        }
+        let mut data = [0u8; N];
+        crate::string_helpers::decode_hex_into(hex, &mut data[..len])?;
+        let result = Self { data };
+        result.try_as_str()?;
+        Ok(result)
+    }
+
+    /// Creates a right‑aligned decimal field that is exactly `N` bytes wide.
+    ///
+    /// The digits of `value` are placed at the end of the buffer and all preceding bytes
+    /// are filled with `pad_char`, e.g. `'0'` or `' '`, as used by many fixed‑width formats.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let amount = FixedStr::<8>::from_uint_right_aligned(4200, '0').unwrap();
+    /// assert_eq!(amount.as_str(), "00004200");
+    /// ```
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::NonAscii { position: 0 }` if `pad_char` is not ASCII.
+    /// - Returns `FixedStrError::InteriorNul { position: 0 }` if `pad_char` is `'\0'`.
+    /// - Returns `FixedStrError::Overflow` if the number has more than `N` digits.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn from_uint_right_aligned(value: u64, pad_char: char) -> Result<Self, FixedStrError> {
+        require_capacity::<N>();
+        if !pad_char.is_ascii() {
+            return Err(FixedStrError::NonAscii { position: 0 });
+        }
+        if pad_char == '\0' {
+            return Err(FixedStrError::InteriorNul { position: 0 });
+        }
+        let mut digits = [0u8; 20];
+        let mut count = 0;
+        let mut rest = value;
+        loop {
+            digits[count] = b'0' + (rest % 10) as u8;
+            count += 1;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        if count > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: count,
+            });
+        }
+
+        let mut data = [pad_char as u8; N];
+        for (dst, &digit) in data[N - count..]
+            .iter_mut()
+            .zip(digits[..count].iter().rev())
+        {
+            *dst = digit;
+        }
+        Ok(Self { data })
+    }
+
+    /// Creates a `FixedStr` holding a single character.
+    ///
+    /// Every character fits in four bytes, so this cannot fail; a capacity below 4 is
+    /// rejected at compile time. Use `TryFrom<char>` for smaller capacities. `From<char>`
+    /// is not implemented because it would conflict with `TryFrom<char>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// assert_eq!(FixedStr::<4>::from_char('€').as_str(), "€");
+    /// ```
+    ///
+    /// # Panics
+    /// Fails to compile if `N < 4`.
+    pub fn from_char(c: char) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = CharFits::<N>::FITS;
+        let mut data = [0u8; N];
+        c.encode_utf8(&mut data);
+        Self { data }
+    }
+
+    /// Creates a new `FixedStr` and checks its content against a [`Validator`].
+    ///
+    /// Unlike [`FixedStr::new`], the input is never truncated: if it does not fit, an
+    /// `Overflow` error is returned. The validator receives the effective string exactly
+    /// as it is stored, so any content after a null byte (`\0`) is not seen.
+    ///
+    /// # Errors
+    /// - Returns `ValidationError::Invalid` if the input exceeds the capacity.
+    /// - Returns `ValidationError::Rule` if the validator rejects the content.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn new_validated<V: Validator>(
+        input: &str,
+        validator: &V,
+    ) -> Result<Self, ValidationError<V::Error>> {
+        let result = Self {
+            data: copy_into_buffer(input.effective_bytes(), BufferCopyMode::Exact)?,
+        };
+        validator
+            .validate(result.as_str())
+            .map_err(ValidationError::Rule)?;
+        Ok(result)
+    }
+
+    /// Converts an array of strings into an array of `FixedStr`, without truncation.
+    ///
+    /// Each input is stored as with [`FixedStr::set`]. On failure, the index of the first
+    /// input that does not fit is returned together with its error.
+    ///
+    /// # Errors
+    /// Returns `(index, FixedStrError::Overflow)` for the first input exceeding the capacity.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let names = FixedStr::<4>::try_from_all(&["GET", "PUT", "POST"]).unwrap();
+    /// assert_eq!(names[2].as_str(), "POST");
+    ///
+    /// let err = FixedStr::<4>::try_from_all(&["GET", "DELETE"]).unwrap_err();
+    /// assert_eq!(err.0, 1);
+    /// ```
+    pub fn try_from_all<const M: usize>(
+        inputs: &[&str; M],
+    ) -> Result<[Self; M], (usize, FixedStrError)> {
+        require_capacity::<N>();
+        let mut result = [Self { data: [0u8; N] }; M];
+        for (index, (slot, input)) in result.iter_mut().zip(inputs).enumerate() {
+            slot.data = copy_into_buffer(input.effective_bytes(), BufferCopyMode::Exact)
+                .map_err(|e| (index, e))?;
+        }
+        Ok(result)
+    }
+
     //****************************************************************************
     //  Modifiers
     //****************************************************************************
@@ -198,10 +511,97 @@ impl<const N: usize> FixedStr<N> {
     /// assert_eq!(fs.as_str(), "World");
     /// ```
     ///
+    /// With the `truncation_policy` feature, overlong input follows the installed
+    /// [`TruncationPolicy`](crate::TruncationPolicy) instead.
+    ///
     /// # Panics
     /// Panics if `N == 0`. Zero‑length strings are not supported.
+    /// With the `truncation_policy` feature, also panics if the input does not fit and the
+    /// installed policy is `TruncationPolicy::Error`.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
     pub fn set_lossy(&mut self, input: &str) {
-        self.data = copy_into_buffer(input.effective_bytes(), BufferCopyMode::Truncate).unwrap();
+        #[cfg(feature = "truncation_policy")]
+        {
+            *self = crate::truncation_policy::apply_global(input);
+        }
+        #[cfg(not(feature = "truncation_policy"))]
+        {
+            self.data =
+                copy_into_buffer(input.effective_bytes(), BufferCopyMode::Truncate).unwrap();
+        }
+    }
+
+    /// Updates the `FixedStr` like [`FixedStr::set_lossy`], also reporting what was dropped.
+    ///
+    /// As with [`FixedStr::new_lossy_report`], overlong input is always truncated at the last
+    /// valid UTF‑8 boundary; the installed [`TruncationPolicy`](crate::TruncationPolicy) is
+    /// not consulted.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let mut fs = FixedStr::<5>::new("Hello");
+    /// assert!(!fs.set_lossy_report("World").is_truncated());
+    /// let info = fs.set_lossy_report("World!");
+    /// assert_eq!((fs.as_str(), info.bytes_dropped), ("World", 1));
+    /// ```
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn set_lossy_report(&mut self, input: &str) -> TruncationInfo {
+        let (fixed, info) = Self::new_lossy_report(input);
+        *self = fixed;
+        info
+    }
+
+    /// Replaces the content with a byte slice, copying at most `chunk` bytes at a time.
+    ///
+    /// Behaves like [`FixedStr::from_slice`], but `yield_fn` is called between chunks, so
+    /// the copy of a large buffer (`N ≥ 4096`) can be interleaved with interrupt handling
+    /// or other cooperative work. The zero padding is written in chunks as well.
+    ///
+    /// **Note:** While the copy is in progress, the content is a mix of old and new bytes.
+    ///
+    /// # Panics
+    /// Panics if `chunk == 0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let mut fs = FixedStr::<8>::new("");
+    /// let mut yields = 0;
+    /// fs.copy_from_slice_chunked(b"abcde", 3, || yields += 1);
+    /// assert_eq!(fs.as_str(), "abcde");
+    /// // Chunks of 3, 3 and 2 bytes.
+    /// assert_eq!(yields, 2);
+    /// ```
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn copy_from_slice_chunked(
+        &mut self,
+        input: &[u8],
+        chunk: usize,
+        mut yield_fn: impl FnMut(),
+    ) {
+        assert!(chunk > 0, "chunk must be greater than zero");
+        let len = find_valid_utf8_len(input, N);
+        #[cfg(feature = "debug_lossy")]
+        crate::lossy_hook::report_if_lossy(N, find_first_null(input), len);
+        let mut start = 0;
+        while start < N {
+            if start > 0 {
+                yield_fn();
+            }
+            let end = N.min(start + chunk);
+            let copied = len.clamp(start, end);
+            if copied > start {
+                self.data[start..copied].copy_from_slice(&input[start..copied]);
+            }
+            self.data[copied..end].fill(0);
+            start = end;
+        }
     }
 
     /// Clears the `FixedStr`, setting all bytes to zero.
@@ -221,6 +621,50 @@ impl<const N: usize> FixedStr<N> {
         }
     }
 
+    /// Shortens the effective string to at most `max_bytes` by eliding its middle.
+    ///
+    /// The start and the end of the string are kept and joined with an ellipsis (`…`),
+    /// e.g. `"/usr/local/share/doc"` becomes `"/usr/…e/doc"`. Both parts are cut at valid
+    /// UTF‑8 boundaries, so the result may be slightly shorter than `max_bytes`.
+    ///
+    /// If the string already fits, this method does nothing. If `max_bytes` is too small
+    /// to hold the 3‑byte ellipsis, the string is simply truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let mut fs = FixedStr::<32>::new("/usr/local/share/doc");
+    /// fs.truncate_middle(13);
+    /// assert_eq!(fs.as_str(), "/usr/…e/doc");
+    /// ```
+    pub fn truncate_middle(&mut self, max_bytes: usize) {
+        const ELLIPSIS: &str = "…";
+        let max_bytes = max_bytes.min(N);
+        let s = self.as_str();
+        if s.len() <= max_bytes {
+            return;
+        }
+        if max_bytes < ELLIPSIS.len() {
+            let keep = find_valid_utf8_len(s.as_bytes(), max_bytes);
+            self.truncate(keep);
+            return;
+        }
+
+        let budget = max_bytes - ELLIPSIS.len();
+        let head = find_valid_utf8_len(s.as_bytes(), budget - budget / 2);
+        let mut tail = s.len() - budget / 2;
+        while !s.is_char_boundary(tail) {
+            tail += 1;
+        }
+
+        let mut buf = FixedStrBuf::<N>::new();
+        let _ = buf.push_str_lossy(&s[..head]);
+        let _ = buf.push_str_lossy(ELLIPSIS);
+        let _ = buf.push_str_lossy(&s[tail..]);
+        *self = buf.finalize();
+    }
+
     //****************************************************************************
     //  Accessors
     //****************************************************************************
@@ -228,14 +672,195 @@ impl<const N: usize> FixedStr<N> {
     /// Returns the string slice representation of the effective string.
     #[track_caller]
     pub fn as_str(&self) -> &str {
-        truncate_utf8_lossy(self, N)
+        truncate_utf8_lossy(&self.data, N)
     }
 
     /// Attempts to interpret the stored effective bytes as a UTF‑8 string.
     ///
     /// Returns an error if the data up to the first null byte is not valid UTF‑8.
     pub fn try_as_str(&self) -> Result<&str, FixedStrError> {
-        str::from_utf8(self.effective_bytes()).map_err(|_| FixedStrError::InvalidUtf8)
+        str::from_utf8(self.effective_bytes()).map_err(FixedStrError::from)
+    }
+
+    /// Compares the effective bytes with those of a byte array in a const context.
+    ///
+    /// Follows the same semantics as `PartialEq<[u8; N]>`: both sides end at their first
+    /// null byte, and any bytes after it are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// const MAGIC: FixedStr<4> = FixedStr::new_const("FSv1");
+    /// const _: () = assert!(MAGIC.eq_bytes_const(b"FSv1"));
+    /// ```
+    pub const fn eq_bytes_const(&self, other: &[u8; N]) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.data[i] != other[i] {
+                return false;
+            }
+            if self.data[i] == 0 {
+                return true;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Compares the effective bytes with those of another `FixedStr` in a const context.
+    ///
+    /// Unlike the derived `PartialEq`, bytes after the first null byte are ignored, as with
+    /// [`FixedStr::eq_bytes_const`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// const A: FixedStr<4> = FixedStr::new_const("ok");
+    /// const B: FixedStr<4> = FixedStr::new_const("ok\0x");
+    /// const _: () = assert!(A.const_eq(&B));
+    /// ```
+    pub const fn const_eq(&self, other: &FixedStr<N>) -> bool {
+        self.eq_bytes_const(&other.data)
+    }
+
+    /// Compares the effective bytes lexicographically in a const context.
+    ///
+    /// Returns the same ordering as `Ord`: a null byte ends the shorter string, which orders
+    /// first.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use fixed_str::FixedStr;
+    ///
+    /// const A: FixedStr<4> = FixedStr::new_const("ab");
+    /// const B: FixedStr<4> = FixedStr::new_const("abc");
+    /// const _: () = assert!(matches!(A.const_cmp(&B), Ordering::Less));
+    /// ```
+    pub const fn const_cmp(&self, other: &FixedStr<N>) -> Ordering {
+        let (a, b) = (&self.data, &other.data);
+        let mut i = 0;
+        while i < N {
+            if a[i] != b[i] {
+                // A null byte ends the shorter string, which orders first.
+                return if a[i] < b[i] {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+            if a[i] == 0 {
+                return Ordering::Equal;
+            }
+            i += 1;
+        }
+        Ordering::Equal
+    }
+
+    /// Extracts the bytes `START..START + LEN` of the raw buffer into a `FixedStr<LEN>`.
+    ///
+    /// The range is checked at compile time, so fixed headers can be decomposed into typed
+    /// fields without runtime bounds checks. The bytes are copied as‑is, including any
+    /// padding in the range.
+    ///
+    /// # Warning
+    /// A range that starts or ends inside a multi‑byte character copies only part of it,
+    /// so the result is invalid UTF‑8: [`as_str`](FixedStr::as_str) stops before the broken
+    /// character and [`try_as_str`](FixedStr::try_as_str) returns an error.
+    ///
+    /// # Panics
+    /// Fails to compile if `START + LEN > N` or `LEN == 0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// const HEADER: FixedStr<12> = FixedStr::new_const("FSTRv002data");
+    /// const MAGIC: FixedStr<4> = HEADER.sub_const::<0, 4>();
+    /// const VERSION: FixedStr<4> = HEADER.sub_const::<4, 4>();
+    /// assert_eq!(MAGIC.as_str(), "FSTR");
+    /// assert_eq!(VERSION.as_str(), "v002");
+    /// ```
+    pub const fn sub_const<const START: usize, const LEN: usize>(&self) -> FixedStr<LEN> {
+        #[allow(clippy::let_unit_value)]
+        let () = SubRange::<N, START, LEN>::IN_BOUNDS;
+        let mut data = [0u8; LEN];
+        let mut i = 0;
+        while i < LEN {
+            data[i] = self.data[START + i];
+            i += 1;
+        }
+        FixedStr { data }
+    }
+
+    /// Joins the effective bytes of `self` and `other` into a `FixedStr<O>` at compile time.
+    ///
+    /// The output capacity is checked at compile time, so the result is never truncated.
+    /// Use [`fixed_concat!`](crate::fixed_concat) to join string literals.
+    ///
+    /// # Panics
+    /// Fails to compile if `O < N + M`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// const ROOT: FixedStr<8> = FixedStr::new_const("/dev/");
+    /// const NAME: FixedStr<4> = FixedStr::new_const("tty0");
+    /// const PATH: FixedStr<12> = ROOT.concat_const(&NAME);
+    /// assert_eq!(PATH.as_str(), "/dev/tty0");
+    /// ```
+    pub const fn concat_const<const M: usize, const O: usize>(
+        &self,
+        other: &FixedStr<M>,
+    ) -> FixedStr<O> {
+        #[allow(clippy::let_unit_value)]
+        let () = ConcatFits::<N, M, O>::FITS;
+        let head = find_first_null_const(&self.data);
+        let tail = find_first_null_const(&other.data);
+        let mut data = [0u8; O];
+        let mut i = 0;
+        while i < head {
+            data[i] = self.data[i];
+            i += 1;
+        }
+        while i < head + tail {
+            data[i] = other.data[i - head];
+            i += 1;
+        }
+        FixedStr { data }
+    }
+
+    /// Joins string slices into a `FixedStr` at compile time.
+    ///
+    /// The parts are copied as‑is, in order. This backs the [`fixed_concat!`](crate::fixed_concat)
+    /// macro.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`, or if the parts exceed `N` bytes in total. In a const item, both
+    /// are compile errors.
+    pub const fn from_parts_const(parts: &[&str]) -> Self {
+        require_capacity::<N>();
+        let mut data = [0u8; N];
+        let mut len = 0;
+        let mut p = 0;
+        while p < parts.len() {
+            let bytes = parts[p].as_bytes();
+            assert!(
+                bytes.len() <= N - len,
+                "fixed_concat parts exceed the FixedStr capacity"
+            );
+            let mut i = 0;
+            while i < bytes.len() {
+                data[len + i] = bytes[i];
+                i += 1;
+            }
+            len += bytes.len();
+            p += 1;
+        }
+        Self { data }
     }
 
     /// Returns the raw byte array stored in the `FixedStr`.
@@ -243,6 +868,26 @@ impl<const N: usize> FixedStr<N> {
         &self.data
     }
 
+    /// Returns a reference to the raw buffer with its static size.
+    pub const fn as_array(&self) -> &[u8; N] {
+        &self.data
+    }
+
+    /// Returns the raw buffer, including the padding after the effective string.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// const ID: FixedStr<4> = FixedStr::new_const("ab");
+    /// const RAW: [u8; 4] = ID.into_array();
+    /// assert_eq!(&RAW, ID.as_array());
+    /// assert_eq!(RAW, *b"ab\0\0");
+    /// ```
+    pub const fn into_array(self) -> [u8; N] {
+        self.data
+    }
+
     #[cfg(feature = "const_mut_refs")]
     /// Returns the raw byte array stored in the `FixedStr` as mutable.
     pub const fn as_mut_bytes(&mut self) -> &mut [u8] {
@@ -255,6 +900,93 @@ impl<const N: usize> FixedStr<N> {
         &mut self.data
     }
 
+    /// Returns an adapter that displays the effective string in uppercase.
+    ///
+    /// Characters are case‑mapped while formatting, so neither the stored value
+    /// is modified nor a new value is allocated.
+    pub fn display_uppercase(&self) -> DisplayUppercase<'_> {
+        DisplayUppercase(self.as_str())
+    }
+
+    /// Returns an adapter that displays the effective string in lowercase.
+    ///
+    /// Characters are case‑mapped while formatting, so neither the stored value
+    /// is modified nor a new value is allocated.
+    pub fn display_lowercase(&self) -> DisplayLowercase<'_> {
+        DisplayLowercase(self.as_str())
+    }
+
+    /// Returns a [`PrefixMatcher`] that matches this value against input fed in chunks.
+    pub fn prefix_matcher(&self) -> PrefixMatcher<'_, N> {
+        PrefixMatcher::new(self)
+    }
+
+    /// Returns `true` if the effective string matches the glob `pattern`.
+    ///
+    /// `*` matches any sequence of characters, including `/`, and `?` matches exactly one
+    /// character; every other character matches itself. There is no escape syntax.
+    /// Matching does not allocate.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let topic = FixedStr::<32>::new("sensors/kitchen/temp1");
+    /// assert!(topic.matches_glob("sensors/*/temp?"));
+    /// assert!(!topic.matches_glob("sensors/*/humidity?"));
+    /// ```
+    pub fn matches_glob(&self, pattern: &str) -> bool {
+        crate::fs_matcher::glob_match(pattern, self.as_str())
+    }
+
+    /// Compares the effective strings in natural order, comparing runs of digits by their
+    /// numeric value (see [`NaturalOrd`]).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use fixed_str::FixedStr;
+    ///
+    /// let a = FixedStr::<8>::new("file2");
+    /// let b = FixedStr::<16>::new("file10");
+    /// assert_eq!(a.natural_cmp(&b), Ordering::Less);
+    /// assert_eq!(a.cmp(&FixedStr::new("file10")), Ordering::Greater);
+    /// ```
+    pub fn natural_cmp<const M: usize>(&self, other: &FixedStr<M>) -> Ordering {
+        crate::fs_ord::natural_cmp(self.effective_bytes(), other.effective_bytes())
+    }
+
+    /// Returns a copy with the trailing padding rewritten from `from` to `to`
+    /// (e.g. between NUL, space and `0xFF` conventions).
+    ///
+    /// Only the run of `from` bytes at the end of the buffer is replaced; the same
+    /// byte value inside the string is kept.
+    ///
+    /// # Warning
+    /// Only NUL bytes end the effective string. After converting to a non‑zero `to`,
+    /// [`len`](FixedStr::len), [`as_str`](FixedStr::as_str) and comparisons treat the
+    /// padding as content; convert back to `0` before using the value as a string.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let fixed = FixedStr::<6>::new("a b");
+    /// let spaced = fixed.convert_padding(0, b' ');
+    /// assert_eq!(spaced.as_bytes(), b"a b   ");
+    /// assert_eq!(spaced.convert_padding(b' ', 0), fixed);
+    /// ```
+    pub fn convert_padding(&self, from: u8, to: u8) -> Self {
+        let mut data = self.data;
+        for b in data.iter_mut().rev() {
+            if *b != from {
+                break;
+            }
+            *b = to;
+        }
+        Self { data }
+    }
+
     /// Returns an iterator over the entire internal byte array,
     /// including trailing zeroes beyond the effective string.
     pub fn byte_iter(&self) -> impl Iterator<Item = u8> + '_ {
@@ -262,25 +994,78 @@ impl<const N: usize> FixedStr<N> {
     }
 
     //****************************************************************************
-    //  std Functions
+    //  alloc Functions
     //****************************************************************************
 
     /// Converts the `FixedStr` to an owned `String`.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn into_string(self) -> String {
-        self.as_str().to_string()
+        String::from(self.as_str())
     }
 
     /// Attempts to convert the `FixedStr` to an owned `String`.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn try_into_string(self) -> Result<String, FixedStrError> {
-        self.try_as_str().map(str::to_string)
+        self.try_as_str().map(String::from)
     }
 
     /// Converts the `FixedStr` to an owned `String` in a lossy manner,
     /// replacing any invalid UTF‑8 sequences with the Unicode replacement character.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn to_string_lossy(&self) -> String {
         String::from_utf8_lossy(&self.data[..self.len()]).into_owned()
     }
 }
+
+/// Compile‑time bounds check for [`FixedStr::sub_const`].
+struct SubRange<const N: usize, const START: usize, const LEN: usize>;
+
+impl<const N: usize, const START: usize, const LEN: usize> SubRange<N, START, LEN> {
+    const IN_BOUNDS: () = {
+        require_capacity::<LEN>();
+        assert!(
+            START <= N && LEN <= N - START,
+            "sub_const range exceeds the FixedStr capacity"
+        );
+    };
+}
+
+/// Compile‑time capacity check for [`FixedStr::concat_const`].
+struct ConcatFits<const N: usize, const M: usize, const O: usize>;
+
+impl<const N: usize, const M: usize, const O: usize> ConcatFits<N, M, O> {
+    const FITS: () = {
+        require_capacity::<O>();
+        assert!(
+            M <= O && N <= O - M,
+            "concat_const output is smaller than the combined capacities"
+        );
+    };
+}
+
+/// Compile‑time capacity check for [`FixedStr::from_char`].
+struct CharFits<const N: usize>;
+
+impl<const N: usize> CharFits<N> {
+    const FITS: () = assert!(N >= 4, "from_char requires a capacity of at least 4 bytes");
+}
+
+/// Joins string literals or `&str` constants into a `FixedStr<N>` at compile time.
+///
+/// The first argument is the capacity `N`, followed by the parts. In a const item, parts
+/// exceeding `N` bytes in total are a compile error.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{fixed_concat, FixedStr};
+///
+/// const PREFIX: &str = "/sys/class/";
+/// const LED: FixedStr<24> = fixed_concat!(24; PREFIX, "leds/", "red");
+/// assert_eq!(LED.as_str(), "/sys/class/leds/red");
+/// ```
+#[macro_export]
+macro_rules! fixed_concat {
+    ($n:expr; $($part:expr),* $(,)?) => {
+        $crate::FixedStr::<$n>::from_parts_const(&[$($part),*])
+    };
+}
@@ -0,0 +1,267 @@
+// fixed_str/src/fs_cstr.rs
+
+use super::*;
+#[cfg(feature = "std")]
+use std::os::raw::c_char;
+
+/// The C `char` type without `std`, matching `core::ffi::c_char` (which requires rustc 1.64).
+#[cfg(all(
+    not(feature = "std"),
+    not(windows),
+    not(target_vendor = "apple"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "csky",
+        target_arch = "hexagon",
+        target_arch = "msp430",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "riscv32",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+        target_arch = "xtensa",
+    )
+))]
+#[allow(non_camel_case_types)]
+type c_char = u8;
+/// The C `char` type without `std`, matching `core::ffi::c_char` (which requires rustc 1.64).
+#[cfg(all(
+    not(feature = "std"),
+    not(all(
+        not(windows),
+        not(target_vendor = "apple"),
+        any(
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "csky",
+            target_arch = "hexagon",
+            target_arch = "msp430",
+            target_arch = "powerpc",
+            target_arch = "powerpc64",
+            target_arch = "riscv32",
+            target_arch = "riscv64",
+            target_arch = "s390x",
+            target_arch = "xtensa",
+        )
+    ))
+))]
+#[allow(non_camel_case_types)]
+type c_char = i8;
+
+/// A fixed‑capacity UTF‑8 string that is always NUL‑terminated, for handing to C.
+///
+/// Unlike [`FixedStr`], whose content may fill all `N` bytes, `FixedCStr<N>` reserves the
+/// last byte for the terminator, so it holds at most `N - 1` bytes. The content never
+/// contains a null byte (`\0`) and every byte after it is zero, so [`FixedCStr::as_ptr`]
+/// always points to a valid C string.
+///
+/// # Examples
+/// ```
+/// use fixed_str::FixedCStr;
+///
+/// let name = FixedCStr::<8>::new("eth0").unwrap();
+/// assert_eq!(name.as_bytes_with_nul(), b"eth0\0");
+/// assert!(FixedCStr::<4>::new("eth0").is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct FixedCStr<const N: usize> {
+    data: [u8; N],
+}
+
+impl<const N: usize> FixedCStr<N> {
+    /// The maximum length of the content in bytes, excluding the terminator.
+    pub const MAX_LEN: usize = N - 1;
+
+    /// Creates a new `FixedCStr` without truncation.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::Overflow` if the input exceeds `N - 1` bytes.
+    /// - Returns `FixedStrError::InteriorNul` if the input contains a null byte.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn new(input: &str) -> Result<Self, FixedStrError> {
+        require_capacity::<N>();
+        let bytes = input.as_bytes();
+        if let Some(position) = bytes.iter().position(|&b| b == 0) {
+            return Err(FixedStrError::InteriorNul { position });
+        }
+        if bytes.len() > Self::MAX_LEN {
+            return Err(FixedStrError::Overflow {
+                available: Self::MAX_LEN,
+                found: bytes.len(),
+            });
+        }
+        let mut data = [0u8; N];
+        data[..bytes.len()].copy_from_slice(bytes);
+        Ok(Self { data })
+    }
+
+    /// Creates a new `FixedCStr`, truncating the input at the last valid UTF‑8 boundary
+    /// that fits into `N - 1` bytes.
+    ///
+    /// **Note:** If the input contains a null byte (`\0`), the string terminates at that point.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn new_lossy(input: &str) -> Self {
+        require_capacity::<N>();
+        let len = find_valid_utf8_len(input.as_bytes(), Self::MAX_LEN);
+        #[cfg(feature = "debug_lossy")]
+        crate::lossy_hook::report_if_lossy(N, find_first_null(input.as_bytes()), len);
+        let mut data = [0u8; N];
+        data[..len].copy_from_slice(&input.as_bytes()[..len]);
+        Self { data }
+    }
+
+    /// Returns the size of the buffer, including the terminator.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the length of the content in bytes, excluding the terminator.
+    pub fn len(&self) -> usize {
+        find_first_null(&self.data)
+    }
+
+    /// Returns `true` if the content is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data[0] == 0
+    }
+
+    /// Returns the content as a string slice.
+    pub fn as_str(&self) -> &str {
+        truncate_utf8_lossy(&self.data, Self::MAX_LEN)
+    }
+
+    /// Returns the content bytes, excluding the terminator.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len()]
+    }
+
+    /// Returns the content bytes including the terminator.
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        &self.data[..=self.len()]
+    }
+
+    /// Returns a pointer to the NUL‑terminated content, valid for as long as `self` is.
+    pub const fn as_ptr(&self) -> *const c_char {
+        self.data.as_ptr() as *const c_char
+    }
+
+    /// Returns the content as a `CStr`.
+    #[cfg(feature = "std")]
+    pub fn as_c_str(&self) -> &std::ffi::CStr {
+        // SAFETY: `as_bytes_with_nul` ends at the first null byte, which always exists.
+        unsafe { std::ffi::CStr::from_bytes_with_nul_unchecked(self.as_bytes_with_nul()) }
+    }
+
+    /// Returns the raw buffer, including the terminator and padding.
+    pub const fn as_raw_bytes(&self) -> &[u8; N] {
+        &self.data
+    }
+
+    /// Copies the content into a `FixedStr` of the same size.
+    pub fn to_fixed_str(&self) -> FixedStr<N> {
+        FixedStr { data: self.data }
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> Default for FixedCStr<N> {
+    fn default() -> Self {
+        require_capacity::<N>();
+        Self { data: [0u8; N] }
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedCStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedCStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> NoAllocDisplay for FixedCStr<N> {}
+
+impl<const N: usize> core::convert::TryFrom<&str> for FixedCStr<N> {
+    type Error = FixedStrError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize> From<FixedCStr<N>> for FixedStr<N> {
+    fn from(cstr: FixedCStr<N>) -> Self {
+        cstr.to_fixed_str()
+    }
+}
+
+impl<const N: usize> FixedStrMeta for FixedCStr<N> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::FixedCStr;
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod cstr_tests {
+    use super::*;
+
+    #[test]
+    fn test_new_reserves_terminator() {
+        let full = FixedCStr::<5>::new("abcd").unwrap();
+        assert_eq!(full.as_str(), "abcd");
+        assert_eq!(full.as_bytes_with_nul(), b"abcd\0");
+        assert_eq!(
+            FixedCStr::<5>::new("abcde"),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 5
+            })
+        );
+        assert_eq!(
+            FixedCStr::<5>::new("a\0b"),
+            Err(FixedStrError::InteriorNul { position: 1 })
+        );
+        let empty = FixedCStr::<1>::default();
+        assert!(empty.is_empty());
+        assert_eq!(empty.as_bytes_with_nul(), b"\0");
+    }
+
+    #[test]
+    fn test_new_lossy() {
+        // "é" would end in the terminator byte.
+        let fixed = FixedCStr::<4>::new_lossy("abé");
+        assert_eq!(fixed.as_str(), "ab");
+        assert_eq!(
+            FixedCStr::<8>::new_lossy("ab\0cd").as_raw_bytes(),
+            &[b'a', b'b', 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(FixedStr::from(fixed), FixedStr::<4>::new("ab"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_as_c_str() {
+        let fixed = FixedCStr::<8>::new("héllo").unwrap();
+        assert_eq!(fixed.as_c_str().to_str(), Ok("héllo"));
+        // SAFETY: the pointer refers to a NUL-terminated buffer that outlives the call.
+        let from_ptr = unsafe { std::ffi::CStr::from_ptr(fixed.as_ptr()) };
+        assert_eq!(from_ptr.to_bytes(), "héllo".as_bytes());
+    }
+}
@@ -0,0 +1,127 @@
+// fixed_str/src/fs_debug.rs
+
+use super::*;
+
+/// Implements `Debug` for a struct, annotating its `FixedStr` fields with length and capacity.
+///
+/// `FixedStr<N>` fields are rendered as `"abc" (3/16)`, i.e. the effective string followed by
+/// its effective length and capacity. All other fields use their regular `Debug` output.
+/// List the fields in the order they should be printed.
+///
+/// **Note:** The struct must not have generic parameters.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{impl_debug_fixed_fields, FixedStr};
+///
+/// struct Record {
+///     name: FixedStr<16>,
+///     id: u32,
+/// }
+///
+/// impl_debug_fixed_fields!(Record { name, id });
+///
+/// let record = Record { name: FixedStr::new("abc"), id: 7 };
+/// assert_eq!(format!("{:?}", record), r#"Record { name: "abc" (3/16), id: 7 }"#);
+/// ```
+#[macro_export]
+macro_rules! impl_debug_fixed_fields {
+    ($ty:ident { $($field:ident),* $(,)? }) => {
+        impl ::core::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #[allow(unused_imports)]
+                use $crate::fs_debug::__private::{FieldDebug, FixedFieldDebug, OtherFieldDebug};
+                f.debug_struct(stringify!($ty))
+                    $(.field(stringify!($field), &(&FieldDebug(&self.$field)).wrap()))*
+                    .finish()
+            }
+        }
+    };
+}
+
+/// Support items for [`impl_debug_fixed_fields!`]; not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use super::*;
+
+    /// Wraps a field reference so method resolution can pick its rendering.
+    pub struct FieldDebug<'a, T>(pub &'a T);
+
+    impl<const N: usize> fmt::Debug for FieldDebug<'_, FixedStr<N>> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:?} ({}/{})", self.0, self.0.len(), N)
+        }
+    }
+
+    /// Picked for `FixedStr` fields, since it matches without an extra auto-reference.
+    pub trait FixedFieldDebug {
+        fn wrap(&self) -> &dyn fmt::Debug;
+    }
+
+    impl<const N: usize> FixedFieldDebug for FieldDebug<'_, FixedStr<N>> {
+        fn wrap(&self) -> &dyn fmt::Debug {
+            self
+        }
+    }
+
+    /// Picked for every other field.
+    pub trait OtherFieldDebug {
+        fn wrap(&self) -> &dyn fmt::Debug;
+    }
+
+    impl<T: fmt::Debug> OtherFieldDebug for &FieldDebug<'_, T> {
+        fn wrap(&self) -> &dyn fmt::Debug {
+            self.0
+        }
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod debug_tests {
+    use crate::*;
+
+    struct Record {
+        name: FixedStr<16>,
+        code: FixedStr<4>,
+        count: u32,
+        label: &'static str,
+    }
+
+    impl_debug_fixed_fields!(Record {
+        name,
+        code,
+        count,
+        label,
+    });
+
+    struct Plain {
+        id: u8,
+    }
+
+    impl_debug_fixed_fields!(Plain { id });
+
+    #[test]
+    fn test_debug_fixed_fields() {
+        let record = Record {
+            name: FixedStr::new("abc"),
+            code: FixedStr::new("WXYZ"),
+            count: 3,
+            label: "x",
+        };
+        assert_eq!(
+            format!("{:?}", record),
+            r#"Record { name: "abc" (3/16), code: "WXYZ" (4/4), count: 3, label: "x" }"#
+        );
+        assert_eq!(format!("{:?}", Plain { id: 1 }), "Plain { id: 1 }");
+    }
+
+    #[test]
+    fn test_debug_fixed_fields_alternate() {
+        let record = Plain { id: 2 };
+        assert_eq!(format!("{:#?}", record), "Plain {\n    id: 2,\n}");
+    }
+}
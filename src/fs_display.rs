@@ -0,0 +1,132 @@
+// fixed_str/src/fs_display.rs
+
+use super::*;
+use core::fmt::Write;
+
+/// A display adapter that prints a string in uppercase without allocating.
+///
+/// Created by [`FixedStr::display_uppercase`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayUppercase<'a>(pub(super) &'a str);
+
+/// A display adapter that prints a string in lowercase without allocating.
+///
+/// Created by [`FixedStr::display_lowercase`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayLowercase<'a>(pub(super) &'a str);
+
+impl fmt::Display for DisplayUppercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_chars(f, self.0.chars().flat_map(char::to_uppercase))
+    }
+}
+
+impl fmt::Display for DisplayLowercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_chars(f, self.0.chars().flat_map(char::to_lowercase))
+    }
+}
+
+/// Writes `chars` as [`fmt::Formatter::pad`] writes a `str`, honouring width, fill,
+/// alignment and precision, for content that is produced while formatting.
+pub(crate) fn pad_chars<I>(f: &mut fmt::Formatter<'_>, chars: I) -> fmt::Result
+where
+    I: Iterator<Item = char> + Clone,
+{
+    let precision = f.precision().unwrap_or(usize::MAX);
+    let padding = match f.width() {
+        Some(width) => width.saturating_sub(chars.clone().take(precision).count()),
+        None => 0,
+    };
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    for c in chars.take(precision) {
+        f.write_char(c)?;
+    }
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Marker for `Display` implementations that never allocate and keep no shared state.
+///
+/// Formatting such a value only writes to the given `Formatter`, so it is safe to use from
+/// allocation‑free sinks such as interrupt handlers or panic‑time loggers, and may be
+/// re‑entered (e.g. from within another `Display` implementation). Use it as a bound to require
+/// this statically:
+///
+/// ```
+/// use core::fmt::{self, Write};
+/// use fixed_str::{FixedStr, NoAllocDisplay};
+///
+/// /// Stands in for a serial port, counting the bytes sent.
+/// struct Uart(usize);
+///
+/// impl Write for Uart {
+///     fn write_str(&mut self, s: &str) -> fmt::Result {
+///         self.0 += s.len();
+///         Ok(())
+///     }
+/// }
+///
+/// fn log<T: NoAllocDisplay>(uart: &mut Uart, value: &T) -> fmt::Result {
+///     write!(uart, "{}", value)
+/// }
+///
+/// let mut uart = Uart(0);
+/// log(&mut uart, &FixedStr::<8>::new("ready")).unwrap();
+/// assert_eq!(uart.0, 5);
+/// ```
+pub trait NoAllocDisplay: fmt::Display {}
+
+impl<T: NoAllocDisplay + ?Sized> NoAllocDisplay for &T {}
+impl<const N: usize> NoAllocDisplay for FixedStr<N> {}
+impl<const N: usize> NoAllocDisplay for FixedStrBuf<N> {}
+impl<const N: usize> NoAllocDisplay for SecretFixedStr<N> {}
+impl NoAllocDisplay for DisplayUppercase<'_> {}
+impl NoAllocDisplay for DisplayLowercase<'_> {}
+impl NoAllocDisplay for FixedStrError {}
+impl NoAllocDisplay for TaggedError {}
+impl<E: NoAllocDisplay> NoAllocDisplay for ValidationError<E> {}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(all(test, feature = "std"))]
+mod display_tests {
+    use super::*;
+
+    #[test]
+    fn test_display_uppercase() {
+        let fixed = FixedStr::<16>::new("Hello, straße");
+        assert_eq!(format!("{}", fixed.display_uppercase()), "HELLO, STRASSE");
+        // The stored value is not modified.
+        assert_eq!(fixed.as_str(), "Hello, straße");
+    }
+
+    #[test]
+    fn test_display_case_flags() {
+        let fixed = FixedStr::<8>::new("straße");
+        assert_eq!(format!("[{:>9}]", fixed.display_uppercase()), "[  STRASSE]");
+        assert_eq!(
+            format!("[{:-^8.4}]", fixed.display_lowercase()),
+            "[--stra--]"
+        );
+        assert_eq!(format!("[{:<4.2}]", fixed.display_uppercase()), "[ST  ]");
+    }
+
+    #[test]
+    fn test_display_lowercase() {
+        let fixed = FixedStr::<16>::new("MiXeD ÄÖÜ");
+        assert_eq!(format!("{}", fixed.display_lowercase()), "mixed äöü");
+    }
+}
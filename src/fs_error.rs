@@ -17,7 +17,58 @@ pub enum FixedStrError {
         found: usize,
     },
     /// Thrown when the byte content cannot be parsed as valid UTF-8.
-    InvalidUtf8,
+    ///
+    /// - `valid_up_to`: The length of the valid UTF‑8 prefix.
+    InvalidUtf8 {
+        /// The byte offset of the first invalid sequence.
+        valid_up_to: usize,
+    },
+    /// Thrown when UTF‑16 code units contain an unpaired surrogate.
+    InvalidUtf16,
+    /// Thrown when a write‑once value (such as `LatchedFixedStr`) has already been set.
+    AlreadySet,
+    /// Thrown when escaped input contains an unknown or incomplete escape sequence.
+    ///
+    /// - `position`: The byte offset of the backslash starting the sequence.
+    InvalidEscape {
+        /// The byte offset of the offending escape sequence.
+        position: usize,
+    },
+    /// Thrown when hex‑encoded input contains a character that is not a hex digit, or ends early.
+    ///
+    /// - `position`: The byte offset of the offending character (the input length if it ends early).
+    InvalidHex {
+        /// The byte offset of the offending character.
+        position: usize,
+    },
+    /// Thrown when input was cut to fit and the caller asked for loss to be an error
+    /// (see [`TruncationInfo::check`]).
+    ///
+    /// - `kept`: The number of bytes kept.
+    /// - `dropped`: The number of bytes dropped.
+    Truncated {
+        /// The number of bytes kept.
+        kept: usize,
+        /// The number of bytes dropped.
+        dropped: usize,
+    },
+    /// Thrown by the `checked_*` constructors when `N == 0`, instead of panicking.
+    ZeroCapacity,
+    /// Thrown when the input contains a null byte where none is allowed (see [`FixedCStr`]).
+    ///
+    /// - `position`: The byte offset of the null byte.
+    InteriorNul {
+        /// The byte offset of the null byte.
+        position: usize,
+    },
+    /// Thrown when the input contains a non‑ASCII byte where only ASCII is allowed
+    /// (see [`AsciiFixedStr`]).
+    ///
+    /// - `position`: The byte offset of the non‑ASCII byte.
+    NonAscii {
+        /// The byte offset of the non‑ASCII byte.
+        position: usize,
+    },
 }
 
 impl fmt::Debug for FixedStrError {
@@ -29,7 +80,19 @@ impl fmt::Debug for FixedStrError {
             } => {
                 write!(f, "Overflow: available {}, found {}", remaining, found)
             }
-            Self::InvalidUtf8 => write!(f, "InvalidUtf8"),
+            Self::InvalidUtf8 { valid_up_to } => {
+                write!(f, "InvalidUtf8: valid_up_to {}", valid_up_to)
+            }
+            Self::InvalidUtf16 => write!(f, "InvalidUtf16"),
+            Self::AlreadySet => write!(f, "AlreadySet"),
+            Self::InvalidEscape { position } => write!(f, "InvalidEscape: position {}", position),
+            Self::InvalidHex { position } => write!(f, "InvalidHex: position {}", position),
+            Self::Truncated { kept, dropped } => {
+                write!(f, "Truncated: kept {}, dropped {}", kept, dropped)
+            }
+            Self::ZeroCapacity => write!(f, "ZeroCapacity"),
+            Self::InteriorNul { position } => write!(f, "InteriorNul: position {}", position),
+            Self::NonAscii { position } => write!(f, "NonAscii: position {}", position),
         }
     }
 }
@@ -47,14 +110,247 @@ impl fmt::Display for FixedStrError {
                     found, remaining
                 )
             }
-            Self::InvalidUtf8 => write!(f, "Invalid UTF-8"),
+            Self::InvalidUtf8 { valid_up_to } => {
+                write!(f, "Invalid UTF-8 after {} valid bytes", valid_up_to)
+            }
+            Self::InvalidUtf16 => write!(f, "Invalid UTF-16"),
+            Self::AlreadySet => write!(f, "Value has already been set"),
+            Self::InvalidEscape { position } => {
+                write!(f, "Invalid escape sequence at byte {}", position)
+            }
+            Self::InvalidHex { position } => write!(f, "Invalid hex digit at byte {}", position),
+            Self::Truncated { kept, dropped } => {
+                write!(f, "Truncated: kept {} bytes, dropped {}", kept, dropped)
+            }
+            Self::ZeroCapacity => write!(f, "Zero-length strings are not supported"),
+            Self::InteriorNul { position } => {
+                write!(f, "Interior null byte at offset {}", position)
+            }
+            Self::NonAscii { position } => write!(f, "Non-ASCII byte at offset {}", position),
+        }
+    }
+}
+
+impl From<core::str::Utf8Error> for FixedStrError {
+    fn from(err: core::str::Utf8Error) -> Self {
+        Self::InvalidUtf8 {
+            valid_up_to: err.valid_up_to(),
         }
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "core_error")]
+impl core::error::Error for FixedStrError {}
+
+#[cfg(all(feature = "std", not(feature = "core_error")))]
 impl std::error::Error for FixedStrError {}
 
+/// Error type returned by validated constructors such as [`FixedStr::new_validated`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError<E> {
+    /// The input could not be stored (e.g. it exceeds the capacity).
+    Invalid(FixedStrError),
+    /// The stored content was rejected by the validator.
+    Rule(E),
+}
+
+impl<E: fmt::Debug> fmt::Debug for ValidationError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(e) => write!(f, "Invalid({:?})", e),
+            Self::Rule(e) => write!(f, "Rule({:?})", e),
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ValidationError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(e) => write!(f, "{}", e),
+            Self::Rule(e) => write!(f, "Validation failed: {}", e),
+        }
+    }
+}
+
+impl<E> From<FixedStrError> for ValidationError<E> {
+    fn from(e: FixedStrError) -> Self {
+        Self::Invalid(e)
+    }
+}
+
+#[cfg(feature = "core_error")]
+impl<E: fmt::Debug + fmt::Display> core::error::Error for ValidationError<E> {}
+
+#[cfg(all(feature = "std", not(feature = "core_error")))]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ValidationError<E> {}
+
+/// Error type returned by the built‑in validators (such as [`AsciiOnly`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RejectedChar {
+    /// The byte offset of the rejected character.
+    pub position: usize,
+    /// The rejected character.
+    pub ch: char,
+}
+
+impl fmt::Display for RejectedChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Rejected character {:?} at byte {}",
+            self.ch, self.position
+        )
+    }
+}
+
+#[cfg(feature = "core_error")]
+impl core::error::Error for RejectedChar {}
+
+#[cfg(all(feature = "std", not(feature = "core_error")))]
+impl std::error::Error for RejectedChar {}
+
+/// Error type returned when decoding the tagged form (see [`FixedStr::decode_tagged`]).
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TaggedError {
+    /// The input ends before the header or payload is complete.
+    UnexpectedEnd {
+        /// The number of bytes required.
+        needed: usize,
+        /// The number of bytes available.
+        found: usize,
+    },
+    /// The header carries an unknown format version.
+    UnsupportedVersion(u8),
+    /// The header sets flags unknown to this version.
+    UnsupportedFlags(u8),
+    /// The value was written with a different capacity.
+    CapacityMismatch {
+        /// The capacity of the target type.
+        expected: usize,
+        /// The capacity stored in the header.
+        found: usize,
+    },
+    /// The payload could not be stored (e.g. invalid UTF‑8).
+    Invalid(FixedStrError),
+}
+
+impl fmt::Debug for TaggedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd { needed, found } => {
+                write!(f, "UnexpectedEnd: needed {}, found {}", needed, found)
+            }
+            Self::UnsupportedVersion(v) => write!(f, "UnsupportedVersion({})", v),
+            Self::UnsupportedFlags(flags) => write!(f, "UnsupportedFlags({:#04x})", flags),
+            Self::CapacityMismatch { expected, found } => {
+                write!(
+                    f,
+                    "CapacityMismatch: expected {}, found {}",
+                    expected, found
+                )
+            }
+            Self::Invalid(e) => write!(f, "Invalid({:?})", e),
+        }
+    }
+}
+
+impl fmt::Display for TaggedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd { needed, found } => write!(
+                f,
+                "Unexpected end: needed {} bytes, found {} bytes",
+                needed, found
+            ),
+            Self::UnsupportedVersion(v) => write!(f, "Unsupported tagged version {}", v),
+            Self::UnsupportedFlags(flags) => write!(f, "Unsupported tagged flags {:#04x}", flags),
+            Self::CapacityMismatch { expected, found } => write!(
+                f,
+                "Capacity mismatch: expected {} bytes, found {} bytes",
+                expected, found
+            ),
+            Self::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<FixedStrError> for TaggedError {
+    fn from(e: FixedStrError) -> Self {
+        Self::Invalid(e)
+    }
+}
+
+#[cfg(feature = "core_error")]
+impl core::error::Error for TaggedError {}
+
+#[cfg(all(feature = "std", not(feature = "core_error")))]
+impl std::error::Error for TaggedError {}
+
+/// Error type returned when parsing or emitting a fixed‑width record (see [`FixedRecord`]).
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RecordError {
+    /// The buffer length does not match the record width.
+    WrongLength {
+        /// The width of the record.
+        expected: usize,
+        /// The length of the buffer.
+        found: usize,
+    },
+    /// A field could not be parsed.
+    Field {
+        /// The position of the field in the record.
+        index: usize,
+        /// The byte offset of the field within the record.
+        offset: usize,
+        /// The error reported by the field.
+        error: FixedStrError,
+    },
+}
+
+impl fmt::Debug for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLength { expected, found } => {
+                write!(f, "WrongLength: expected {}, found {}", expected, found)
+            }
+            Self::Field {
+                index,
+                offset,
+                error,
+            } => write!(
+                f,
+                "Field: index {}, offset {}, error {:?}",
+                index, offset, error
+            ),
+        }
+    }
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLength { expected, found } => write!(
+                f,
+                "Wrong record length: expected {} bytes, found {} bytes",
+                expected, found
+            ),
+            Self::Field {
+                index,
+                offset,
+                error,
+            } => write!(f, "Field {} at byte {}: {}", index, offset, error),
+        }
+    }
+}
+
+#[cfg(feature = "core_error")]
+impl core::error::Error for RecordError {}
+
+#[cfg(all(feature = "std", not(feature = "core_error")))]
+impl std::error::Error for RecordError {}
+
 //******************************************************************************
 //  Tests
 //******************************************************************************
@@ -71,6 +367,27 @@ fn test_error_display() {
         format!("{}", overflow_error),
         "Overflow: tried to add 5 bytes with only 2 bytes available"
     );
-    let invalid_utf8_error = FixedStrError::InvalidUtf8;
-    assert_eq!(format!("{}", invalid_utf8_error), "Invalid UTF-8");
+    let invalid_utf8_error = FixedStrError::InvalidUtf8 { valid_up_to: 3 };
+    assert_eq!(
+        format!("{}", invalid_utf8_error),
+        "Invalid UTF-8 after 3 valid bytes"
+    );
+    let truncated_error = FixedStrError::Truncated {
+        kept: 4,
+        dropped: 2,
+    };
+    assert_eq!(
+        format!("{}", truncated_error),
+        "Truncated: kept 4 bytes, dropped 2"
+    );
+}
+
+#[cfg(all(test, feature = "core_error"))]
+#[test]
+fn test_core_error_trait() {
+    use super::*;
+    fn as_error(e: &dyn core::error::Error) -> Option<&(dyn core::error::Error + 'static)> {
+        e.source()
+    }
+    assert!(as_error(&FixedStrError::InvalidUtf8 { valid_up_to: 0 }).is_none());
 }
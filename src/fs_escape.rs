@@ -0,0 +1,205 @@
+// fixed_str/src/fs_escape.rs
+
+use super::*;
+
+/// Returns the escape letter for `byte`, if it must be escaped.
+const fn escape_letter(byte: u8) -> Option<u8> {
+    match byte {
+        b'\n' => Some(b'n'),
+        b'\\' => Some(b'\\'),
+        _ => None,
+    }
+}
+
+/// Returns the byte denoted by the escape letter, if the letter is known.
+const fn unescape_letter(letter: u8) -> Option<u8> {
+    match letter {
+        b'n' => Some(b'\n'),
+        b'\\' => Some(b'\\'),
+        _ => None,
+    }
+}
+
+impl<const N: usize> FixedStr<N> {
+    /// Appends the effective string to `out`, escaping newline and backslash.
+    ///
+    /// The bytes are written as `\n` and `\\`; everything else is copied unchanged. The
+    /// effective string ends before the first NUL, so the result contains no NUL or newline
+    /// bytes and can be stored in null‑padded or newline‑delimited areas and recovered with
+    /// [`FixedStr::unescape_into`].
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the escaped string does not fit into the remaining
+    /// space of `out`. `out` is left unchanged in that case.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, FixedStrBuf};
+    ///
+    /// let text = FixedStr::<16>::new("a\\b\nc");
+    /// let mut escaped = FixedStrBuf::<16>::new();
+    /// text.escape_into(&mut escaped).unwrap();
+    /// assert_eq!(escaped.finalize().as_str(), "a\\\\b\\nc");
+    /// ```
+    pub fn escape_into<const M: usize>(
+        &self,
+        out: &mut FixedStrBuf<M>,
+    ) -> Result<(), FixedStrError> {
+        let bytes = self.effective_bytes();
+        let needed = bytes.len()
+            + bytes
+                .iter()
+                .filter(|&&b| escape_letter(b).is_some())
+                .count();
+        if needed > out.remaining() {
+            return Err(FixedStrError::Overflow {
+                available: out.remaining(),
+                found: needed,
+            });
+        }
+        // Escaping only rewrites ASCII bytes, so the output is as valid as the input.
+        out.utf8 &= core::str::from_utf8(bytes).is_ok();
+        for &byte in bytes {
+            match escape_letter(byte) {
+                Some(letter) => {
+                    out.buffer[out.len] = b'\\';
+                    out.buffer[out.len + 1] = letter;
+                    out.len += 2;
+                }
+                None => {
+                    out.buffer[out.len] = byte;
+                    out.len += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends the effective string to `out`, reversing [`FixedStr::escape_into`].
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::InvalidEscape` if a backslash is not followed by `n` or `\`.
+    ///   This includes `\0`, as a null byte would end the string early.
+    /// - Returns `FixedStrError::Overflow` if the unescaped string does not fit into the
+    ///   remaining space of `out`.
+    ///
+    /// `out` is left unchanged on error.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, FixedStrBuf};
+    ///
+    /// let escaped = FixedStr::<16>::new("a\\\\b\\nc");
+    /// let mut text = FixedStrBuf::<16>::new();
+    /// escaped.unescape_into(&mut text).unwrap();
+    /// assert_eq!(text.finalize().as_str(), "a\\b\nc");
+    /// ```
+    pub fn unescape_into<const M: usize>(
+        &self,
+        out: &mut FixedStrBuf<M>,
+    ) -> Result<(), FixedStrError> {
+        let bytes = self.effective_bytes();
+        let mut needed = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'\\' {
+                match bytes.get(i + 1).and_then(|&letter| unescape_letter(letter)) {
+                    Some(_) => i += 1,
+                    None => return Err(FixedStrError::InvalidEscape { position: i }),
+                }
+            }
+            needed += 1;
+            i += 1;
+        }
+        if needed > out.remaining() {
+            return Err(FixedStrError::Overflow {
+                available: out.remaining(),
+                found: needed,
+            });
+        }
+        out.utf8 &= core::str::from_utf8(bytes).is_ok();
+        let mut i = 0;
+        while i < bytes.len() {
+            let mut byte = bytes[i];
+            if byte == b'\\' {
+                i += 1;
+                // Validated above.
+                byte = unescape_letter(bytes[i]).unwrap();
+            }
+            out.buffer[out.len] = byte;
+            out.len += 1;
+            i += 1;
+        }
+        Ok(())
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod escape_tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_round_trip() {
+        let text = FixedStr::<12>::new("a\\\nb\\n");
+        let mut escaped = FixedStrBuf::<24>::new();
+        text.escape_into(&mut escaped).unwrap();
+        let escaped = escaped.finalize();
+        assert_eq!(escaped.as_str(), "a\\\\\\nb\\\\n");
+        assert!(!escaped.effective_bytes().contains(&b'\n'));
+
+        let mut restored = FixedStrBuf::<12>::new();
+        escaped.unescape_into(&mut restored).unwrap();
+        assert_eq!(restored.finalize(), text);
+    }
+
+    #[test]
+    fn test_unescape_rejects_nul() {
+        let mut out = FixedStrBuf::<8>::new();
+        assert_eq!(
+            FixedStr::<8>::new("ab\\0cd").unescape_into(&mut out),
+            Err(FixedStrError::InvalidEscape { position: 2 })
+        );
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_escape_keeps_utf8_flag() {
+        let invalid = FixedStr::<4>::from_bytes_unsafe(*b"a\xFF\\n");
+        let mut escaped = FixedStrBuf::<8>::new();
+        invalid.escape_into(&mut escaped).unwrap();
+        assert!(!escaped.is_utf8_guaranteed());
+        let mut unescaped = FixedStrBuf::<8>::new();
+        invalid.unescape_into(&mut unescaped).unwrap();
+        assert!(!unescaped.is_utf8_guaranteed());
+
+        let mut out = FixedStrBuf::<8>::new();
+        FixedStr::<4>::new("é\\n").unescape_into(&mut out).unwrap();
+        assert!(out.is_utf8_guaranteed());
+    }
+
+    #[test]
+    fn test_escape_errors_leave_output_unchanged() {
+        let mut out = FixedStrBuf::<4>::new();
+        out.try_push_str("x").unwrap();
+        assert_eq!(
+            FixedStr::<4>::new("a\nb").escape_into(&mut out),
+            Err(FixedStrError::Overflow {
+                available: 3,
+                found: 4
+            })
+        );
+        assert_eq!(
+            FixedStr::<4>::new("a\\t").unescape_into(&mut out),
+            Err(FixedStrError::InvalidEscape { position: 1 })
+        );
+        assert_eq!(
+            FixedStr::<4>::new("ab\\").unescape_into(&mut out),
+            Err(FixedStrError::InvalidEscape { position: 2 })
+        );
+        assert_eq!(out.finalize().as_str(), "x");
+    }
+}
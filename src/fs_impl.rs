@@ -5,24 +5,53 @@ use super::*;
 /// Implements the Debug trait for `FixedStr`.
 ///
 /// If the effective string is valid UTF‑8, it is printed using the Debug format.
+/// The sentinel value is printed as `<sentinel>`.
 /// Otherwise, it prints a hex dump of the underlying data.
+///
+/// The alternate form (`{:#?}`) prints the capacity, effective length, string and a hex
+/// preview of the bytes after the terminator, to help track down stray bytes in the padding.
 impl<const N: usize> fmt::Debug for FixedStr<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_sentinel() {
+            return write!(f, "<sentinel>");
+        }
+        if f.alternate() {
+            return crate::string_helpers::debug_layout(f, "FixedStr", &self.data, self.len());
+        }
         match self.try_as_str() {
             Ok(s) => write!(f, "{:?}", s),
             Err(_) => write!(
                 f,
                 "<invalid UTF-8>\n{:?}",
-                fast_format_hex::<384>(&self.data, 16, Some(8))
+                fast_format_hex_styled::<384>(&self.data, &default_hex_style())
             ),
         }
     }
 }
 
 /// Implements the Display trait for `FixedStr` by displaying its effective string.
+///
+/// Width, fill, alignment and precision flags apply as for `str`, so `{:>10}` right‑aligns
+/// the string and `{:.5}` shows at most its first five characters.
 impl<const N: usize> fmt::Display for FixedStr<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
+        f.pad(self.as_str())
+    }
+}
+
+/// Formats all `N` bytes of the buffer, padding included, as lowercase hex digits.
+/// The alternate form (`{:#x}`) adds a `0x` prefix.
+impl<const N: usize> fmt::LowerHex for FixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::string_helpers::fmt_hex_bytes(&self.data, f, false)
+    }
+}
+
+/// Formats all `N` bytes of the buffer, padding included, as uppercase hex digits.
+/// The alternate form (`{:#X}`) adds a `0x` prefix.
+impl<const N: usize> fmt::UpperHex for FixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::string_helpers::fmt_hex_bytes(&self.data, f, true)
     }
 }
 
@@ -54,21 +83,6 @@ impl<const N: usize> Default for FixedStr<N> {
     }
 }
 
-/// Deref returns a reference to the underlying byte array.
-impl<const N: usize> core::ops::Deref for FixedStr<N> {
-    type Target = [u8];
-    fn deref(&self) -> &Self::Target {
-        &self.data
-    }
-}
-
-/// Mutable Deref returns a mutable reference to the underlying byte array.
-impl<const N: usize> core::ops::DerefMut for FixedStr<N> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.data
-    }
-}
-
 /// Attempts to construct a FixedStr from a byte slice using exact copy semantics.
 ///
 /// # Errors
@@ -82,20 +96,117 @@ impl<const N: usize> core::convert::TryFrom<&[u8]> for FixedStr<N> {
     fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
         let buf = copy_into_buffer(slice.effective_bytes(), BufferCopyMode::Exact)?;
         let result = Self { data: buf };
-        if result.is_valid() {
-            Ok(result)
-        } else {
-            Err(FixedStrError::InvalidUtf8)
+        result.try_as_str()?;
+        Ok(result)
+    }
+}
+
+/// Attempts to construct a FixedStr from a byte array of the same capacity.
+///
+/// The array always fits, so unlike `TryFrom<&[u8]>` no length check is needed. The string
+/// ends at the first null byte, and the remaining bytes are zeroed.
+///
+/// # Errors
+/// Returns `FixedStrError::InvalidUtf8` if the effective bytes are not valid UTF‑8.
+///
+/// # Panics
+/// Panics if `N == 0`.
+impl<const N: usize> core::convert::TryFrom<&[u8; N]> for FixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(bytes: &[u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from(&bytes[..])
+    }
+}
+
+/// Returns the raw buffer, including the padding after the effective string.
+impl<const N: usize> From<FixedStr<N>> for [u8; N] {
+    fn from(fixed: FixedStr<N>) -> Self {
+        fixed.into_array()
+    }
+}
+
+/// Formats `value` into a `FixedStr`, returning an overflow error if it does not fit.
+fn try_format<const N: usize>(value: impl fmt::Display) -> Result<FixedStr<N>, FixedStrError> {
+    use core::fmt::Write;
+
+    /// Appends formatted output to a builder, failing if it does not fit.
+    struct BufWriter<'a, const N: usize>(&'a mut FixedStrBuf<N>);
+
+    impl<const N: usize> Write for BufWriter<'_, N> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0.try_push_str(s).map_err(|_| fmt::Error)
+        }
+    }
+
+    /// Counts the bytes of a formatted value without storing them.
+    struct Counter(usize);
+
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.len();
+            Ok(())
         }
     }
+
+    let mut buf = FixedStrBuf::<N>::new();
+    if write!(BufWriter(&mut buf), "{}", value).is_err() {
+        let mut counter = Counter(0);
+        let _ = write!(counter, "{}", value);
+        return Err(FixedStrError::Overflow {
+            available: N,
+            found: counter.0,
+        });
+    }
+    Ok(buf.finalize())
 }
 
-/// Constructs a FixedStr from a &str using the standard constructor.
+/// Formats an unsigned integer in decimal.
 ///
-/// **Warning:** If the input contains a null byte or invalid UTF‑8, the string is truncated.
-impl<const N: usize> From<&str> for FixedStr<N> {
-    fn from(s: &str) -> Self {
-        Self::new(s)
+/// # Errors
+/// Returns `FixedStrError::Overflow` if the formatted number exceeds the capacity.
+impl<const N: usize> core::convert::TryFrom<u64> for FixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        try_format(value)
+    }
+}
+
+/// Formats a signed integer in decimal.
+///
+/// # Errors
+/// Returns `FixedStrError::Overflow` if the formatted number exceeds the capacity.
+impl<const N: usize> core::convert::TryFrom<i64> for FixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        try_format(value)
+    }
+}
+
+/// Formats a floating‑point number using its `Display` representation.
+///
+/// # Errors
+/// Returns `FixedStrError::Overflow` if the formatted number exceeds the capacity.
+impl<const N: usize> core::convert::TryFrom<f64> for FixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        try_format(value)
+    }
+}
+
+/// Encodes a single character.
+///
+/// The null character yields an empty string.
+///
+/// # Errors
+/// Returns `FixedStrError::Overflow` if the UTF‑8 encoding of the character exceeds the
+/// capacity.
+impl<const N: usize> core::convert::TryFrom<char> for FixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let mut buf = [0u8; 4];
+        let encoded = c.encode_utf8(&mut buf);
+        let data = copy_into_buffer(encoded.as_bytes().effective_bytes(), BufferCopyMode::Exact)?;
+        Ok(Self { data })
     }
 }
 
@@ -108,10 +219,14 @@ impl<const N: usize> From<FixedStrBuf<N>> for FixedStr<N> {
     }
 }
 
-/// Hashes the FixedStr based only on its effective bytes (up to the first null).
+/// Hashes the FixedStr exactly like its effective string.
+///
+/// This keeps the hash consistent with `Borrow<str>`, so a `HashMap<FixedStr<N>, V>` can be
+/// queried with a plain `&str`. The same holds for `hashbrown` and `indexmap`, whose
+/// `Equivalent` trait covers every `Borrow` implementation.
 impl<const N: usize> Hash for FixedStr<N> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.effective_bytes().hash(state);
+        self.as_str().hash(state);
     }
 }
 
@@ -142,100 +257,174 @@ impl<const N: usize> PartialOrd for FixedStr<N> {
     }
 }
 
-/// Compares a FixedStr with a &str by comparing their effective bytes.
-impl<const N: usize> PartialEq<&str> for FixedStr<N> {
-    fn eq(&self, other: &&str) -> bool {
-        self.effective_bytes() == other.effective_bytes()
-    }
-}
+//******************************************************************************
+//  Byte Deref Implementations
+//******************************************************************************
 
-/// Compares a &str with a FixedStr.
-impl<const N: usize> PartialEq<FixedStr<N>> for &str {
-    fn eq(&self, other: &FixedStr<N>) -> bool {
-        self.effective_bytes() == other.effective_bytes()
-    }
-}
+/// Deref to the underlying byte array (requires the `deref_bytes` feature).
+#[cfg(feature = "deref_bytes")]
+mod deref_ext {
+    use super::*;
 
-/// Compares a FixedStr with a byte slice.
-impl<const N: usize> PartialEq<[u8]> for FixedStr<N> {
-    fn eq(&self, other: &[u8]) -> bool {
-        self.effective_bytes() == other.effective_bytes()
+    /// Deref returns a reference to the underlying byte array.
+    impl<const N: usize> core::ops::Deref for FixedStr<N> {
+        type Target = [u8];
+        fn deref(&self) -> &Self::Target {
+            &self.data
+        }
     }
-}
 
-/// Compares a byte slice with a FixedStr.
-impl<const N: usize> PartialEq<FixedStr<N>> for [u8] {
-    fn eq(&self, other: &FixedStr<N>) -> bool {
-        self.effective_bytes() == other.effective_bytes()
+    /// Mutable Deref returns a mutable reference to the underlying byte array.
+    impl<const N: usize> core::ops::DerefMut for FixedStr<N> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.data
+        }
     }
 }
 
-/// Compares a FixedStr with a reference to a byte slice.
-impl<const N: usize> PartialEq<&[u8]> for FixedStr<N> {
-    fn eq(&self, other: &&[u8]) -> bool {
-        self.effective_bytes() == other.effective_bytes()
-    }
-}
+//******************************************************************************
+//  Lossy Conversions
+//******************************************************************************
 
-/// Compares a reference to a byte slice with a FixedStr.
-impl<const N: usize> PartialEq<FixedStr<N>> for &[u8] {
-    fn eq(&self, other: &FixedStr<N>) -> bool {
-        self.effective_bytes() == other.effective_bytes()
+/// Truncating `From` conversions (requires the `convert_lossy` feature).
+#[cfg(feature = "convert_lossy")]
+mod lossy_ext {
+    use super::*;
+
+    /// Constructs a FixedStr from a &str using the standard constructor.
+    ///
+    /// **Warning:** If the input contains a null byte or invalid UTF‑8, the string is truncated.
+    impl<const N: usize> From<&str> for FixedStr<N> {
+        #[cfg_attr(feature = "debug_lossy", track_caller)]
+        fn from(s: &str) -> Self {
+            Self::new(s)
+        }
     }
-}
 
-/// Compares a FixedStr with a fixed-size byte array.
-impl<const N: usize> PartialEq<[u8; N]> for FixedStr<N> {
-    fn eq(&self, other: &[u8; N]) -> bool {
-        self.effective_bytes() == other.effective_bytes()
+    /// Constructs a FixedStr from a byte array using [`FixedStr::from_bytes`].
+    ///
+    /// **Warning:** If the array contains a null byte or invalid UTF‑8, the string is truncated.
+    impl<const N: usize> From<[u8; N]> for FixedStr<N> {
+        #[cfg_attr(feature = "debug_lossy", track_caller)]
+        fn from(bytes: [u8; N]) -> Self {
+            Self::from_bytes(bytes)
+        }
     }
-}
 
-/// Compares a fixed-size byte array with a FixedStr.
-impl<const N: usize> PartialEq<FixedStr<N>> for [u8; N] {
-    fn eq(&self, other: &FixedStr<N>) -> bool {
-        self.effective_bytes() == other.effective_bytes()
+    /// Constructs a FixedStr from a String using the standard constructor.
+    ///
+    /// **Warning:** If the input contains a null byte or invalid UTF‑8, the string is truncated.
+    #[cfg(feature = "alloc")]
+    impl<const N: usize> From<String> for FixedStr<N> {
+        #[cfg_attr(feature = "debug_lossy", track_caller)]
+        fn from(s: String) -> Self {
+            Self::new(&s)
+        }
     }
 }
 
 //******************************************************************************
-//  std Implementations
+//  Foreign Comparisons
 //******************************************************************************
 
-/// Implementations for the Standard Library.
-#[cfg(feature = "std")]
-pub mod std_ext {
+/// Comparisons with `str`, byte slices and arrays (requires the `cmp_foreign` feature).
+#[cfg(feature = "cmp_foreign")]
+mod cmp_ext {
     use super::*;
 
+    /// Compares a FixedStr with a &str by comparing their effective bytes.
+    impl<const N: usize> PartialEq<&str> for FixedStr<N> {
+        fn eq(&self, other: &&str) -> bool {
+            self.effective_bytes() == other.effective_bytes()
+        }
+    }
+
+    /// Compares a &str with a FixedStr.
+    impl<const N: usize> PartialEq<FixedStr<N>> for &str {
+        fn eq(&self, other: &FixedStr<N>) -> bool {
+            self.effective_bytes() == other.effective_bytes()
+        }
+    }
+
+    /// Compares a FixedStr with a byte slice.
+    impl<const N: usize> PartialEq<[u8]> for FixedStr<N> {
+        fn eq(&self, other: &[u8]) -> bool {
+            self.effective_bytes() == other.effective_bytes()
+        }
+    }
+
+    /// Compares a byte slice with a FixedStr.
+    impl<const N: usize> PartialEq<FixedStr<N>> for [u8] {
+        fn eq(&self, other: &FixedStr<N>) -> bool {
+            self.effective_bytes() == other.effective_bytes()
+        }
+    }
+
+    /// Compares a FixedStr with a reference to a byte slice.
+    impl<const N: usize> PartialEq<&[u8]> for FixedStr<N> {
+        fn eq(&self, other: &&[u8]) -> bool {
+            self.effective_bytes() == other.effective_bytes()
+        }
+    }
+
+    /// Compares a reference to a byte slice with a FixedStr.
+    impl<const N: usize> PartialEq<FixedStr<N>> for &[u8] {
+        fn eq(&self, other: &FixedStr<N>) -> bool {
+            self.effective_bytes() == other.effective_bytes()
+        }
+    }
+
+    /// Compares a FixedStr with a fixed-size byte array.
+    impl<const N: usize> PartialEq<[u8; N]> for FixedStr<N> {
+        fn eq(&self, other: &[u8; N]) -> bool {
+            self.effective_bytes() == other.effective_bytes()
+        }
+    }
+
+    /// Compares a fixed-size byte array with a FixedStr.
+    impl<const N: usize> PartialEq<FixedStr<N>> for [u8; N] {
+        fn eq(&self, other: &FixedStr<N>) -> bool {
+            self.effective_bytes() == other.effective_bytes()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
     impl<const N: usize> PartialEq<Vec<u8>> for FixedStr<N> {
         fn eq(&self, other: &Vec<u8>) -> bool {
             self.effective_bytes() == other.effective_bytes()
         }
     }
 
+    #[cfg(feature = "alloc")]
     impl<const N: usize> PartialEq<FixedStr<N>> for Vec<u8> {
         fn eq(&self, other: &FixedStr<N>) -> bool {
             self.effective_bytes() == other.effective_bytes()
         }
     }
 
+    #[cfg(feature = "alloc")]
     impl<const N: usize> PartialEq<String> for FixedStr<N> {
         fn eq(&self, other: &String) -> bool {
             self.effective_bytes() == other.effective_bytes()
         }
     }
 
+    #[cfg(feature = "alloc")]
     impl<const N: usize> PartialEq<FixedStr<N>> for String {
         fn eq(&self, other: &FixedStr<N>) -> bool {
             self.effective_bytes() == other.effective_bytes()
         }
     }
+}
 
-    impl<const N: usize> From<String> for FixedStr<N> {
-        fn from(s: String) -> Self {
-            Self::new(&s)
-        }
-    }
+//******************************************************************************
+//  alloc Implementations
+//******************************************************************************
+
+/// Implementations for `String` and `Vec<u8>` (requires the `alloc` feature).
+#[cfg(feature = "alloc")]
+pub mod std_ext {
+    use super::*;
 
     impl<const N: usize> From<FixedStr<N>> for String {
         fn from(fs: FixedStr<N>) -> Self {
@@ -250,6 +439,31 @@ pub mod std_ext {
     }
 }
 
+//******************************************************************************
+//  std Implementations
+//******************************************************************************
+
+/// Path and OS string views of the effective string (requires the `std` feature).
+#[cfg(feature = "std")]
+mod path_ext {
+    use super::*;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    /// Allows a fixed file‑name field to be passed to `File::open` and other path APIs.
+    impl<const N: usize> AsRef<Path> for FixedStr<N> {
+        fn as_ref(&self) -> &Path {
+            Path::new(self.as_str())
+        }
+    }
+
+    impl<const N: usize> AsRef<OsStr> for FixedStr<N> {
+        fn as_ref(&self) -> &OsStr {
+            OsStr::new(self.as_str())
+        }
+    }
+}
+
 //******************************************************************************
 //  Tests
 //******************************************************************************
@@ -286,6 +500,21 @@ mod impl_tests {
         assert_eq!(fixed.as_str(), "Rusta");
     }
 
+    #[test]
+    fn test_try_from_numbers() {
+        assert_eq!(FixedStr::<8>::try_from(12345u64).unwrap().as_str(), "12345");
+        assert_eq!(FixedStr::<8>::try_from(-42i64).unwrap().as_str(), "-42");
+        assert_eq!(FixedStr::<8>::try_from(1.5f64).unwrap().as_str(), "1.5");
+        assert_eq!(
+            FixedStr::<4>::try_from(123456u64),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 6
+            })
+        );
+        assert!(FixedStr::<2>::try_from(-10i64).is_err());
+    }
+
     #[test]
     fn test_ordering() {
         // Test ordering between FixedStr values.
@@ -296,7 +525,7 @@ mod impl_tests {
         assert_eq!(a, c);
     }
 
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", feature = "convert_lossy"))]
     #[test]
     fn test_from_string_and_into_string() {
         // Test conversion from String to FixedStr and back.
@@ -307,6 +536,40 @@ mod impl_tests {
         assert_eq!(s2, "Hello");
     }
 
+    #[test]
+    fn test_sentinel_distinct_from_empty() {
+        let sentinel = FixedStr::<4>::sentinel();
+        let empty = FixedStr::<4>::default();
+        assert!(sentinel.is_sentinel());
+        assert!(!empty.is_sentinel());
+        assert!(!sentinel.is_empty());
+        assert_ne!(sentinel, empty);
+        assert_ne!(sentinel.effective_bytes(), b"");
+        assert!(sentinel > FixedStr::<4>::new("zzzz"));
+        assert_eq!(sentinel, FixedStr::<4>::SENTINEL);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_as_path() {
+        use std::ffi::OsStr;
+        use std::path::Path;
+
+        let name = FixedStr::<16>::new("logs/app.log");
+        let path: &Path = name.as_ref();
+        assert_eq!(path.file_name(), Some(OsStr::new("app.log")));
+        assert_eq!(AsRef::<OsStr>::as_ref(&name), "logs/app.log");
+        assert!(Path::new("logs")
+            .join(FixedStr::<8>::new("a.txt"))
+            .ends_with("a.txt"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sentinel_debug() {
+        assert_eq!(format!("{:?}", FixedStr::<4>::SENTINEL), "<sentinel>");
+    }
+
     #[test]
     fn test_as_mut_bytes() {
         // Test that modifying the mutable bytes directly affects the effective string.
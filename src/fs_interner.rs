@@ -0,0 +1,167 @@
+// fixed_str/src/fs_interner.rs
+
+use super::*;
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// A symbol returned by [`FixedStrInterner::intern`].
+///
+/// Symbols are small integers assigned in interning order, starting at zero. They are only
+/// meaningful for the interner that created them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the position of the symbol in interning order.
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Deduplicates `FixedStr<N>` values and assigns each distinct value a [`Symbol`].
+///
+/// Values are compared by their effective bytes, as with `Ord`; bytes after the first null
+/// byte are ignored, and the stored copy has them zeroed.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, FixedStrInterner};
+///
+/// let mut idents = FixedStrInterner::<8>::new();
+/// let a = idents.intern(FixedStr::new("player"));
+/// let b = idents.intern(FixedStr::new("enemy"));
+/// assert_eq!(idents.intern(FixedStr::new("player")), a);
+/// assert_ne!(a, b);
+/// assert_eq!(idents.resolve(b).as_str(), "enemy");
+/// assert_eq!(idents.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FixedStrInterner<const N: usize> {
+    /// The interned values, indexed by symbol.
+    values: Vec<FixedStr<N>>,
+    /// The symbol of each interned value.
+    symbols: BTreeMap<FixedStr<N>, Symbol>,
+}
+
+impl<const N: usize> FixedStrInterner<N> {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            symbols: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the symbol of `value`, interning it first if it has not been seen.
+    ///
+    /// # Panics
+    /// Panics if more than `u32::MAX` values are interned.
+    pub fn intern(&mut self, value: FixedStr<N>) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(&value) {
+            return symbol;
+        }
+        let symbol = Symbol(u32::try_from(self.values.len()).expect("Too many interned values"));
+        let mut value = value;
+        let len = value.len();
+        zero_from(&mut value.data, len);
+        self.values.push(value);
+        self.symbols.insert(value, symbol);
+        symbol
+    }
+
+    /// Returns the symbol of `value` if it has been interned, without interning it.
+    pub fn get(&self, value: &FixedStr<N>) -> Option<Symbol> {
+        self.symbols.get(value).copied()
+    }
+
+    /// Returns the value of `symbol`.
+    ///
+    /// # Panics
+    /// Panics if `symbol` was not created by this interner.
+    pub fn resolve(&self, symbol: Symbol) -> &FixedStr<N> {
+        &self.values[symbol.index()]
+    }
+
+    /// Returns the value of `symbol`, or `None` if it was not created by this interner.
+    pub fn try_resolve(&self, symbol: Symbol) -> Option<&FixedStr<N>> {
+        self.values.get(symbol.index())
+    }
+
+    /// Returns the number of distinct interned values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no values have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns an iterator over the symbols and values in interning order.
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &FixedStr<N>)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (Symbol(index as u32), value))
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> Extend<FixedStr<N>> for FixedStrInterner<N> {
+    fn extend<I: IntoIterator<Item = FixedStr<N>>>(&mut self, iter: I) {
+        for value in iter {
+            self.intern(value);
+        }
+    }
+}
+
+impl<const N: usize> FromIterator<FixedStr<N>> for FixedStrInterner<N> {
+    fn from_iter<I: IntoIterator<Item = FixedStr<N>>>(iter: I) -> Self {
+        let mut interner = Self::new();
+        interner.extend(iter);
+        interner
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod interner_tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_deduplicates() {
+        let mut interner = FixedStrInterner::<4>::new();
+        assert!(interner.is_empty());
+        let a = interner.intern(FixedStr::new("ab"));
+        let b = interner.intern(FixedStr::new("cd"));
+        assert_eq!((a.index(), b.index()), (0, 1));
+        // Bytes after the first null byte do not make a new value.
+        let padded = FixedStr::from_bytes(*b"ab\0x");
+        assert_eq!(interner.intern(padded), a);
+        assert_eq!(interner.resolve(a).as_bytes(), b"ab\0\0");
+        assert_eq!(interner.get(&FixedStr::new("cd")), Some(b));
+        assert_eq!(interner.get(&FixedStr::new("ef")), None);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn test_iter_and_collect() {
+        let interner: FixedStrInterner<4> =
+            ["x", "y", "x"].iter().copied().map(FixedStr::new).collect();
+        let values: Vec<_> = interner
+            .iter()
+            .map(|(s, v)| (s.index(), v.as_str()))
+            .collect();
+        assert_eq!(values, [(0, "x"), (1, "y")]);
+        assert_eq!(
+            interner.try_resolve(Symbol(1)).map(|v| v.as_str()),
+            Some("y")
+        );
+        assert_eq!(interner.try_resolve(Symbol(2)), None);
+    }
+}
@@ -0,0 +1,173 @@
+// fixed_str/src/fs_latched.rs
+
+use super::*;
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicU8, Ordering as AtomicOrdering};
+
+const EMPTY: u8 = 0;
+const WRITING: u8 = 1;
+const SET: u8 = 2;
+
+/// A write‑once `FixedStr` with interior mutability, usable in statics.
+///
+/// The value starts empty and accepts exactly one successful [`set`](LatchedFixedStr::set);
+/// later writes fail with `FixedStrError::AlreadySet`. This suits identifiers such as device
+/// serial numbers that are initialized once at boot and read everywhere afterwards.
+/// It requires no allocation and works on `no_std` targets with atomic compare‑and‑swap.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStrError, LatchedFixedStr};
+///
+/// static SERIAL: LatchedFixedStr<12> = LatchedFixedStr::new();
+///
+/// assert!(SERIAL.get().is_none());
+/// SERIAL.set("SN-0042").unwrap();
+/// assert_eq!(SERIAL.set("SN-9999"), Err(FixedStrError::AlreadySet));
+/// assert_eq!(SERIAL.get().unwrap().as_str(), "SN-0042");
+/// ```
+pub struct LatchedFixedStr<const N: usize> {
+    state: AtomicU8,
+    value: UnsafeCell<FixedStr<N>>,
+}
+
+// SAFETY: The value is written only by the single caller that moves `state` from `EMPTY`
+// to `WRITING`, and read only after `state` is `SET` (release/acquire ordered).
+unsafe impl<const N: usize> Sync for LatchedFixedStr<N> {}
+
+impl<const N: usize> LatchedFixedStr<N> {
+    /// Creates a new, empty `LatchedFixedStr`.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub const fn new() -> Self {
+        require_capacity::<N>();
+        Self {
+            state: AtomicU8::new(EMPTY),
+            value: UnsafeCell::new(FixedStr { data: [0; N] }),
+        }
+    }
+
+    /// Sets the value if it has not been set before.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::Overflow` if the input exceeds the capacity. The value can
+    ///   still be set afterwards.
+    /// - Returns `FixedStrError::AlreadySet` if another call has already set (or is setting)
+    ///   the value.
+    ///
+    /// **Warning:** If the input contains a null byte (`\0`), the string terminates at that point.
+    pub fn set(&self, input: &str) -> Result<(), FixedStrError> {
+        let data = copy_into_buffer(input.effective_bytes(), BufferCopyMode::Exact)?;
+        self.state
+            .compare_exchange(
+                EMPTY,
+                WRITING,
+                AtomicOrdering::Acquire,
+                AtomicOrdering::Relaxed,
+            )
+            .map_err(|_| FixedStrError::AlreadySet)?;
+        // SAFETY: Moving `state` to `WRITING` grants exclusive access to the value.
+        unsafe { *self.value.get() = FixedStr { data } };
+        self.state.store(SET, AtomicOrdering::Release);
+        Ok(())
+    }
+
+    /// Returns the value, or `None` if it has not been set yet.
+    pub fn get(&self) -> Option<&FixedStr<N>> {
+        if self.is_set() {
+            // SAFETY: Once `SET`, the value is never written again.
+            Some(unsafe { &*self.value.get() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the value has been set.
+    pub fn is_set(&self) -> bool {
+        self.state.load(AtomicOrdering::Acquire) == SET
+    }
+
+    /// Returns the value, or `None` if it has not been set.
+    pub fn into_inner(self) -> Option<FixedStr<N>> {
+        if self.is_set() {
+            Some(self.value.into_inner())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the maximum capacity of the `LatchedFixedStr`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> Default for LatchedFixedStr<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for LatchedFixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("LatchedFixedStr").field(value).finish(),
+            None => write!(f, "LatchedFixedStr(<unset>)"),
+        }
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod latched_tests {
+    use super::*;
+
+    #[test]
+    fn test_latched_set_once() {
+        let latched = LatchedFixedStr::<4>::new();
+        assert!(!latched.is_set());
+        assert_eq!(
+            latched.set("too long"),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 8
+            })
+        );
+        // A failed write does not consume the latch.
+        assert!(latched.get().is_none());
+        assert_eq!(latched.set("abc"), Ok(()));
+        assert_eq!(latched.set("xyz"), Err(FixedStrError::AlreadySet));
+        assert_eq!(latched.get(), Some(&FixedStr::new("abc")));
+        assert_eq!(latched.into_inner(), Some(FixedStr::new("abc")));
+        assert_eq!(LatchedFixedStr::<4>::default().into_inner(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_latched_concurrent() {
+        static LATCH: LatchedFixedStr<8> = LatchedFixedStr::new();
+        let names = ["one", "two", "three", "four"];
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = names
+                .iter()
+                .map(|name| scope.spawn(move || LATCH.set(name)))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        let winner = names[results.iter().position(Result::is_ok).unwrap()];
+        assert_eq!(LATCH.get().unwrap().as_str(), winner);
+        assert_eq!(
+            format!("{:?}", LATCH),
+            format!("LatchedFixedStr({:?})", winner)
+        );
+    }
+}
@@ -0,0 +1,349 @@
+// fixed_str/src/fs_len.rs
+
+use super::*;
+
+/// A fixed‑capacity UTF‑8 string that stores its length explicitly.
+///
+/// [`FixedStr`] ends at the first null byte, so it cannot hold `\0` and `len()` scans the
+/// buffer on every call. `FixedStrLen<N>` keeps the length next to the `N`‑byte buffer, like
+/// [`FixedStrBuf`] does, but behaves as a finished string: null bytes are ordinary content
+/// and `len()` is O(1). Unused capacity is always zero.
+///
+/// # Examples
+/// ```
+/// use fixed_str::FixedStrLen;
+///
+/// let record = FixedStrLen::<8>::new("ab\0cd");
+/// assert_eq!(record.len(), 5);
+/// assert_eq!(record.as_str(), "ab\0cd");
+/// assert_eq!(record.as_raw_bytes(), b"ab\0cd\0\0\0");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FixedStrLen<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedStrLen<N> {
+    /// Creates a new `FixedStrLen` from the given input string.
+    ///
+    /// If the input is longer than `N` bytes, it is truncated at the last valid UTF‑8
+    /// boundary. Null bytes are kept.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn new(input: &str) -> Self {
+        require_capacity::<N>();
+        let mut len = input.len().min(N);
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+        #[cfg(feature = "debug_lossy")]
+        crate::lossy_hook::report_if_lossy(N, input.len(), len);
+        Self::from_content(&input.as_bytes()[..len])
+    }
+
+    /// Copies the content into a zeroed buffer; `content` must be at most `N` bytes.
+    fn from_content(content: &[u8]) -> Self {
+        let mut data = [0u8; N];
+        data[..content.len()].copy_from_slice(content);
+        Self {
+            data,
+            len: content.len(),
+        }
+    }
+
+    /// Returns the maximum capacity of the `FixedStrLen`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the length of the content in bytes, without scanning the buffer.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the content as a string slice, including any null bytes.
+    pub fn as_str(&self) -> &str {
+        // Every constructor checks that the content is valid UTF‑8.
+        str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+
+    /// Returns the content bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Returns the full buffer, including the zeroed unused capacity.
+    pub const fn as_raw_bytes(&self) -> &[u8; N] {
+        &self.data
+    }
+
+    /// Replaces the content without truncation.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the input exceeds `N` bytes. The content is
+    /// left unchanged.
+    pub fn set(&mut self, input: &str) -> Result<(), FixedStrError> {
+        *self = Self::try_from(input)?;
+        Ok(())
+    }
+
+    /// Replaces the content, truncating it as [`FixedStrLen::new`] does.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn set_lossy(&mut self, input: &str) {
+        *self = Self::new(input);
+    }
+
+    /// Clears the string, setting all bytes to zero.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Shortens the string to `new_len` bytes, zeroing the bytes after it.
+    ///
+    /// If `new_len` is not on a character boundary, the string is cut at the previous one.
+    /// If `new_len` is greater than or equal to the current length, this method does nothing.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len {
+            return;
+        }
+        let mut len = new_len;
+        while !self.as_str().is_char_boundary(len) {
+            len -= 1;
+        }
+        self.data[len..].fill(0);
+        self.len = len;
+    }
+
+    /// Converts the content into a null‑padded `FixedStr` of the same capacity.
+    ///
+    /// **Note:** If the content contains a null byte (`\0`), the `FixedStr` ends there.
+    pub fn to_fixed_str(&self) -> FixedStr<N> {
+        FixedStr { data: self.data }
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> Default for FixedStrLen<N> {
+    fn default() -> Self {
+        require_capacity::<N>();
+        Self {
+            data: [0u8; N],
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedStrLen<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedStrLen<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> NoAllocDisplay for FixedStrLen<N> {}
+
+impl<const N: usize> AsRef<str> for FixedStrLen<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FixedStrLen<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> Borrow<str> for FixedStrLen<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Hashes like `str`, consistent with `Borrow<str>`.
+impl<const N: usize> Hash for FixedStrLen<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+/// Returns the whole content; null bytes are content here, not a terminator.
+impl<const N: usize> EffectiveBytes for FixedStrLen<N> {
+    fn effective_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> Ord for FixedStrLen<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl<const N: usize> PartialOrd for FixedStrLen<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Converts a string without truncation.
+///
+/// Returns `FixedStrError::Overflow` if the input exceeds `N` bytes.
+impl<const N: usize> core::convert::TryFrom<&str> for FixedStrLen<N> {
+    type Error = FixedStrError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        require_capacity::<N>();
+        if s.len() > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: s.len(),
+            });
+        }
+        Ok(Self::from_content(s.as_bytes()))
+    }
+}
+
+/// Converts bytes without truncation, keeping null bytes.
+///
+/// # Errors
+/// - Returns `FixedStrError::Overflow` if the input exceeds `N` bytes.
+/// - Returns `FixedStrError::InvalidUtf8` if the input is not valid UTF‑8.
+impl<const N: usize> core::convert::TryFrom<&[u8]> for FixedStrLen<N> {
+    type Error = FixedStrError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from(str::from_utf8(bytes)?)
+    }
+}
+
+/// Takes the effective string of a `FixedStr`.
+impl<const N: usize> From<FixedStr<N>> for FixedStrLen<N> {
+    fn from(fixed: FixedStr<N>) -> Self {
+        Self::from_content(fixed.as_str().as_bytes())
+    }
+}
+
+impl<const N: usize> From<FixedStrLen<N>> for FixedStr<N> {
+    fn from(fixed: FixedStrLen<N>) -> Self {
+        fixed.to_fixed_str()
+    }
+}
+
+impl<const N: usize> FixedStrMeta for FixedStrLen<N> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::FixedStrLen;
+}
+
+/// Deref to the content bytes (requires the `deref_bytes` feature).
+///
+/// Unlike `FixedStr`, there is no `DerefMut`, as writing through it could break the
+/// length or UTF‑8 invariants.
+#[cfg(feature = "deref_bytes")]
+impl<const N: usize> core::ops::Deref for FixedStrLen<N> {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "cmp_foreign")]
+mod cmp_ext {
+    use crate::*;
+
+    impl<const N: usize> PartialEq<str> for FixedStrLen<N> {
+        fn eq(&self, other: &str) -> bool {
+            self.as_str() == other
+        }
+    }
+
+    impl<const N: usize> PartialEq<&str> for FixedStrLen<N> {
+        fn eq(&self, other: &&str) -> bool {
+            self.as_str() == *other
+        }
+    }
+
+    impl<const N: usize> PartialEq<FixedStrLen<N>> for &str {
+        fn eq(&self, other: &FixedStrLen<N>) -> bool {
+            *self == other.as_str()
+        }
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod len_tests {
+    use super::*;
+
+    #[test]
+    fn test_interior_nul() {
+        let fixed = FixedStrLen::<6>::new("a\0b\0");
+        assert_eq!(fixed.len(), 4);
+        assert_eq!(fixed.as_bytes(), b"a\0b\0");
+        assert_eq!(fixed.to_fixed_str().as_str(), "a");
+        assert_eq!(
+            FixedStrLen::<4>::try_from(&b"\0\0"[..]).unwrap().as_str(),
+            "\0\0"
+        );
+        assert!(FixedStrLen::<4>::try_from(&b"\xFF"[..]).is_err());
+    }
+
+    #[test]
+    fn test_truncation() {
+        let mut fixed = FixedStrLen::<4>::new("aéé");
+        assert_eq!(fixed.as_str(), "aé");
+        assert!(fixed.set("abcde").is_err());
+        assert_eq!(fixed.as_str(), "aé");
+        fixed.truncate(2);
+        assert_eq!(fixed.as_raw_bytes(), b"a\0\0\0");
+        fixed.set_lossy("wxyz!");
+        assert_eq!(fixed.as_str(), "wxyz");
+        fixed.clear();
+        assert!(fixed.is_empty());
+    }
+
+    #[test]
+    fn test_conversions_and_order() {
+        let fixed = FixedStr::<5>::new("abc");
+        let with_len = FixedStrLen::from(fixed);
+        assert_eq!(with_len.len(), 3);
+        assert_eq!(FixedStr::from(with_len), fixed);
+        // Content compares before length, as for `str`.
+        assert!(FixedStrLen::<4>::new("a\0") < FixedStrLen::new("a\u{1}"));
+        assert!(FixedStrLen::<4>::new("a") < FixedStrLen::new("a\0"));
+        assert_eq!(format!("[{:>4}]", FixedStrLen::<4>::new("ab")), "[  ab]");
+        assert_eq!(
+            <FixedStrLen<4> as FixedStrMeta>::KIND,
+            FixedStrKind::FixedStrLen
+        );
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_map_lookup_by_str() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(FixedStrLen::<8>::new("abc"), 1);
+        map.insert(FixedStrLen::<8>::new("a\0c"), 2);
+        assert_eq!(map.get("abc"), Some(&1));
+        assert_eq!(map.get("a\0c"), Some(&2));
+        assert_eq!(map.get("a"), None);
+        assert_eq!(FixedStrLen::<8>::new("a\0c").effective_bytes(), b"a\0c");
+    }
+}
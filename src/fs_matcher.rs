@@ -0,0 +1,213 @@
+// fixed_str/src/fs_matcher.rs
+
+use super::*;
+
+/// The state of a [`PrefixMatcher`] after feeding input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchState {
+    /// The input so far is a proper prefix of the target.
+    Partial,
+    /// The input so far is exactly the target.
+    Complete,
+    /// The input so far does not match the target.
+    Mismatch,
+}
+
+/// Matches a `FixedStr` against input that arrives in chunks.
+///
+/// Only the position in the target is kept, so a fixed token can be recognized in a
+/// streaming parser without buffering the whole token. The effective bytes of the target
+/// are matched; once the input diverges, the matcher stays in [`MatchState::Mismatch`]
+/// until it is [reset](PrefixMatcher::reset).
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, MatchState};
+///
+/// let target = FixedStr::<8>::new("GET");
+/// let mut matcher = target.prefix_matcher();
+/// assert_eq!(matcher.feed(b"G"), MatchState::Partial);
+/// assert_eq!(matcher.feed(b"ET"), MatchState::Complete);
+/// assert_eq!(matcher.feed(b" "), MatchState::Mismatch);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefixMatcher<'a, const N: usize> {
+    target: &'a FixedStr<N>,
+    matched: usize,
+    failed: bool,
+}
+
+impl<'a, const N: usize> PrefixMatcher<'a, N> {
+    /// Creates a matcher for the effective bytes of `target`.
+    pub fn new(target: &'a FixedStr<N>) -> Self {
+        Self {
+            target,
+            matched: 0,
+            failed: false,
+        }
+    }
+
+    /// Feeds the next chunk of input and returns the resulting state.
+    pub fn feed(&mut self, chunk: &[u8]) -> MatchState {
+        if !self.failed && !chunk.is_empty() {
+            let rest = &self.target.effective_bytes()[self.matched..];
+            if chunk.len() <= rest.len() && rest[..chunk.len()] == *chunk {
+                self.matched += chunk.len();
+            } else {
+                self.failed = true;
+            }
+        }
+        self.state()
+    }
+
+    /// Returns the current state without feeding input.
+    pub fn state(&self) -> MatchState {
+        if self.failed {
+            MatchState::Mismatch
+        } else if self.matched == self.target.len() {
+            MatchState::Complete
+        } else {
+            MatchState::Partial
+        }
+    }
+
+    /// Returns `true` if the input so far is a prefix of (or equal to) the target.
+    pub fn is_match(&self) -> bool {
+        !self.failed
+    }
+
+    /// Returns `true` if the input so far is exactly the target.
+    pub fn is_complete(&self) -> bool {
+        self.state() == MatchState::Complete
+    }
+
+    /// Returns the number of target bytes matched so far.
+    ///
+    /// After a mismatch, this is the length of the input that matched before the failing chunk.
+    pub fn matched_len(&self) -> usize {
+        self.matched
+    }
+
+    /// Returns the target this matcher compares against.
+    pub fn target(&self) -> &'a FixedStr<N> {
+        self.target
+    }
+
+    /// Discards all input fed so far.
+    pub fn reset(&mut self) {
+        self.matched = 0;
+        self.failed = false;
+    }
+}
+
+/// Matches `text` against a glob `pattern` where `*` matches any sequence of characters
+/// and `?` matches exactly one; every other character matches itself.
+///
+/// Runs without allocation by backtracking to the most recent `*` only, which keeps the
+/// worst case at `O(pattern.len() * text.len())`.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let (mut pattern, mut text) = (pattern, text);
+    // The pattern after the last `*`, and the text it is currently matched from.
+    let mut backtrack: Option<(&str, &str)> = None;
+    loop {
+        let mut p = pattern.chars();
+        match p.next() {
+            Some('*') => {
+                pattern = p.as_str();
+                backtrack = Some((pattern, text));
+                continue;
+            }
+            Some(expected) => {
+                let mut t = text.chars();
+                if let Some(c) = t.next() {
+                    if expected == '?' || expected == c {
+                        pattern = p.as_str();
+                        text = t.as_str();
+                        continue;
+                    }
+                }
+            }
+            None if text.is_empty() => return true,
+            None => {}
+        }
+        // Let the last `*` absorb one more character and retry.
+        let (after_star, from) = match backtrack {
+            Some(state) => state,
+            None => return false,
+        };
+        let mut t = from.chars();
+        if t.next().is_none() {
+            return false;
+        }
+        backtrack = Some((after_star, t.as_str()));
+        pattern = after_star;
+        text = t.as_str();
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod matcher_tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_matcher_chunks() {
+        let target = FixedStr::<16>::new("Content-Length");
+        let mut matcher = PrefixMatcher::new(&target);
+        assert_eq!(matcher.state(), MatchState::Partial);
+        assert_eq!(matcher.feed(b"Cont"), MatchState::Partial);
+        assert_eq!(matcher.feed(b""), MatchState::Partial);
+        assert_eq!(matcher.feed(b"ent-"), MatchState::Partial);
+        assert_eq!(matcher.matched_len(), 8);
+        assert_eq!(matcher.feed(b"Length"), MatchState::Complete);
+        assert!(matcher.is_complete());
+    }
+
+    #[test]
+    fn test_prefix_matcher_mismatch() {
+        let target = FixedStr::<8>::new("abc");
+        let mut matcher = target.prefix_matcher();
+        assert_eq!(matcher.feed(b"ax"), MatchState::Mismatch);
+        // Stays failed, even if later input would match.
+        assert_eq!(matcher.feed(b"bc"), MatchState::Mismatch);
+        assert!(!matcher.is_match());
+
+        matcher.reset();
+        assert_eq!(matcher.feed(b"abc"), MatchState::Complete);
+        // Input longer than the target no longer matches.
+        assert_eq!(matcher.feed(b"d"), MatchState::Mismatch);
+        assert_eq!(matcher.matched_len(), 3);
+
+        // A chunk running past the end does not match either.
+        matcher.reset();
+        assert_eq!(matcher.feed(b"abcd"), MatchState::Mismatch);
+    }
+
+    #[test]
+    fn test_prefix_matcher_empty_target() {
+        let target = FixedStr::<4>::default();
+        let mut matcher = target.prefix_matcher();
+        assert_eq!(matcher.state(), MatchState::Complete);
+        assert_eq!(matcher.feed(b"\0"), MatchState::Mismatch);
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("", ""));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("?", ""));
+        assert!(glob_match("a*b?c", "axxbyc"));
+        assert!(glob_match("*.rs", "lib.rs"));
+        assert!(!glob_match("*.rs", "lib.rsx"));
+        // The last `*` is retried after a partial match of the rest.
+        assert!(glob_match("*ab", "aab"));
+        assert!(glob_match("a*b*c", "abbbc"));
+        assert!(!glob_match("a*b*c", "abbb"));
+        // `?` matches one character, not one byte.
+        assert!(glob_match("caf?", "café"));
+        assert!(!glob_match("caf??", "café"));
+    }
+}
@@ -0,0 +1,106 @@
+// fixed_str/src/fs_meta.rs
+
+use super::*;
+
+/// Identifies which fixed string type a [`FixedStrMeta`] implementation belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FixedStrKind {
+    /// A [`FixedStr`] value.
+    FixedStr,
+    /// A [`FixedStrBuf`] builder.
+    FixedStrBuf,
+    /// A [`SecretFixedStr`] wrapper.
+    SecretFixedStr,
+    /// A `LatchedFixedStr` write‑once value (requires byte‑wide atomic compare‑and‑swap).
+    LatchedFixedStr,
+    /// A [`FixedCStr`] NUL‑terminated string.
+    FixedCStr,
+    /// A [`PaddedStr`] (such as [`SpacePadded`]) with a custom pad byte.
+    PaddedStr,
+    /// A [`FixedUtf16Str`] UTF‑16 string.
+    FixedUtf16Str,
+    /// A [`FixedStrLen`] length‑prefixed string.
+    FixedStrLen,
+    /// A `SmallFixedStr` inline string with heap fallback (requires the `alloc` feature).
+    SmallFixedStr,
+    /// A [`ValidatedFixedStr`] checked by a type‑level validator.
+    ValidatedFixedStr,
+    /// An [`AsciiFixedStr`] ASCII‑only string.
+    AsciiFixedStr,
+    /// A `SymbolStr` interned process‑wide (requires the `std` feature).
+    SymbolStr,
+}
+
+/// Compile‑time metadata describing the layout of a fixed string type.
+///
+/// This allows generic record or schema code to introspect field properties
+/// without macros.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, FixedStrKind, FixedStrMeta};
+///
+/// fn describe<T: FixedStrMeta>() -> (usize, u8, FixedStrKind) {
+///     (T::CAPACITY, T::PAD_BYTE, T::KIND)
+/// }
+///
+/// assert_eq!(describe::<FixedStr<16>>(), (16, 0, FixedStrKind::FixedStr));
+/// ```
+pub trait FixedStrMeta {
+    /// The capacity of the type in bytes.
+    const CAPACITY: usize;
+    /// The byte used to fill unused capacity.
+    const PAD_BYTE: u8;
+    /// The kind of fixed string type.
+    const KIND: FixedStrKind;
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> FixedStrMeta for FixedStr<N> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::FixedStr;
+}
+
+impl<const N: usize> FixedStrMeta for FixedStrBuf<N> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::FixedStrBuf;
+}
+
+impl<const N: usize> FixedStrMeta for SecretFixedStr<N> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::SecretFixedStr;
+}
+
+#[cfg(target_has_atomic = "8")]
+impl<const N: usize> FixedStrMeta for LatchedFixedStr<N> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::LatchedFixedStr;
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod meta_tests {
+    use super::*;
+
+    #[test]
+    fn test_meta_constants() {
+        assert_eq!(<FixedStr<8> as FixedStrMeta>::CAPACITY, 8);
+        assert_eq!(<FixedStr<8> as FixedStrMeta>::PAD_BYTE, 0);
+        assert_eq!(<FixedStrBuf<4> as FixedStrMeta>::CAPACITY, 4);
+        assert_eq!(
+            <FixedStrBuf<4> as FixedStrMeta>::KIND,
+            FixedStrKind::FixedStrBuf
+        );
+    }
+}
@@ -0,0 +1,248 @@
+// fixed_str/src/fs_ord.rs
+
+use super::*;
+
+/// A `FixedStr` wrapper with a strict total order over the whole value.
+///
+/// `FixedStr` orders and hashes by its effective bytes only, so values that differ in their
+/// padding are not distinguished by `Ord`, and values of different capacity cannot be
+/// compared at all. `OrdFull` compares the effective bytes first, then the
+/// remaining buffer bytes, then the capacity `N`. Two values are equal only if their
+/// capacity and all bytes match, which gives a deterministic order even across
+/// mixed‑capacity values.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, OrdFull};
+///
+/// let short = OrdFull(FixedStr::<4>::new("ab"));
+/// let long = OrdFull(FixedStr::<8>::new("ab"));
+/// assert!(short < long);
+/// assert_ne!(short, long);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct OrdFull<const N: usize>(pub FixedStr<N>);
+
+impl<const N: usize> OrdFull<N> {
+    /// The capacity used as the last ordering key.
+    pub const CAPACITY: usize = N;
+
+    /// Compares with a value of any capacity, following the order described on [`OrdFull`].
+    pub fn cmp_full<const M: usize>(&self, other: &OrdFull<M>) -> Ordering {
+        let (a, b) = (&self.0, &other.0);
+        a.effective_bytes()
+            .cmp(b.effective_bytes())
+            .then_with(|| a.data[a.len()..].cmp(&b.data[b.len()..]))
+            .then_with(|| Self::CAPACITY.cmp(&OrdFull::<M>::CAPACITY))
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> FixedStr<N> {
+        self.0
+    }
+}
+
+/// A wrapper that orders strings naturally, comparing runs of digits by their value.
+///
+/// Byte order puts `"file10"` before `"file2"`; `NaturalOrd` compares `10` and `2` as
+/// numbers instead, so it sorts filenames and labels the way people expect. Runs of ASCII
+/// digits of any length are compared by value, and all other bytes byte‑wise. Numbers that
+/// differ only in leading zeros are ordered by the bytes as a last resort, so only identical
+/// strings compare equal.
+///
+/// It wraps any `T: AsRef<str>`, such as [`FixedStr`] or `&str`; see also
+/// [`FixedStr::natural_cmp`].
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, NaturalOrd};
+///
+/// let mut files = ["file10", "file2", "file1"].map(|s| NaturalOrd(FixedStr::<8>::new(s)));
+/// files.sort();
+/// assert_eq!(files.map(|file| file.0.len()), [5, 5, 6]);
+/// assert_eq!(files[1].0.as_str(), "file2");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct NaturalOrd<T>(pub T);
+
+impl<T> NaturalOrd<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: AsRef<str>> NaturalOrd<T> {
+    /// Compares with a value of any wrapped type, following the order described on
+    /// [`NaturalOrd`].
+    pub fn cmp_natural<U: AsRef<str>>(&self, other: &NaturalOrd<U>) -> Ordering {
+        natural_cmp(self.0.as_ref().as_bytes(), other.0.as_ref().as_bytes())
+    }
+}
+
+/// Compares two byte strings in natural order, as described on [`NaturalOrd`].
+pub(crate) fn natural_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+            let (x, next_i) = digit_run(a, i);
+            let (y, next_j) = digit_run(b, j);
+            let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(y));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            i = next_i;
+            j = next_j;
+        } else if a[i] != b[j] {
+            return a[i].cmp(&b[j]);
+        } else {
+            i += 1;
+            j += 1;
+        }
+    }
+    (a.len() - i).cmp(&(b.len() - j)).then_with(|| a.cmp(b))
+}
+
+/// Returns the run of digits starting at `start` without leading zeros, and the end of the run.
+fn digit_run(bytes: &[u8], start: usize) -> (&[u8], usize) {
+    let end = bytes[start..]
+        .iter()
+        .position(|b| !b.is_ascii_digit())
+        .map_or(bytes.len(), |len| start + len);
+    let run = &bytes[start..end];
+    let zeros = run.iter().take_while(|&&b| b == b'0').count();
+    (&run[zeros..], end)
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> From<FixedStr<N>> for OrdFull<N> {
+    fn from(fixed: FixedStr<N>) -> Self {
+        Self(fixed)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<OrdFull<M>> for OrdFull<N> {
+    fn eq(&self, other: &OrdFull<M>) -> bool {
+        self.cmp_full(other) == Ordering::Equal
+    }
+}
+
+impl<const N: usize> Eq for OrdFull<N> {}
+
+impl<const N: usize, const M: usize> PartialOrd<OrdFull<M>> for OrdFull<N> {
+    fn partial_cmp(&self, other: &OrdFull<M>) -> Option<Ordering> {
+        Some(self.cmp_full(other))
+    }
+}
+
+impl<const N: usize> Ord for OrdFull<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_full(other)
+    }
+}
+
+/// Hashes the whole buffer, consistent with the full equality.
+impl<const N: usize> Hash for OrdFull<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.data.hash(state);
+    }
+}
+
+impl<T> From<T> for NaturalOrd<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: AsRef<str>, U: AsRef<str>> PartialEq<NaturalOrd<U>> for NaturalOrd<T> {
+    fn eq(&self, other: &NaturalOrd<U>) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+impl<T: AsRef<str>> Eq for NaturalOrd<T> {}
+
+impl<T: AsRef<str>, U: AsRef<str>> PartialOrd<NaturalOrd<U>> for NaturalOrd<T> {
+    fn partial_cmp(&self, other: &NaturalOrd<U>) -> Option<Ordering> {
+        Some(self.cmp_natural(other))
+    }
+}
+
+impl<T: AsRef<str>> Ord for NaturalOrd<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_natural(other)
+    }
+}
+
+/// Hashes the string content, consistent with equality.
+impl<T: AsRef<str>> Hash for NaturalOrd<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ref().hash(state);
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod ord_tests {
+    use super::*;
+
+    #[test]
+    fn test_ord_full_padding() {
+        let clean = OrdFull(FixedStr::<4>::new("ab"));
+        let dirty = OrdFull(FixedStr::<4>::from_bytes_unsafe(*b"ab\0x"));
+        // Equal in the FixedStr order, ordered by padding as OrdFull.
+        assert_eq!(clean.0.cmp(&dirty.0), Ordering::Equal);
+        assert!(clean < dirty);
+        assert_eq!(clean.cmp(&clean), Ordering::Equal);
+        // The effective bytes take precedence over the padding.
+        assert!(dirty < OrdFull(FixedStr::<4>::new("abc")));
+    }
+
+    #[test]
+    fn test_ord_full_capacity() {
+        let a4 = OrdFull(FixedStr::<4>::new("a"));
+        let a2 = OrdFull(FixedStr::<2>::new("a"));
+        let b2 = OrdFull(FixedStr::<2>::new("b"));
+        assert!(a2 < a4);
+        assert!(a4 > a2);
+        assert!(a4 < b2);
+        assert_eq!(a2.cmp_full(&a4), Ordering::Less);
+        assert_eq!(OrdFull::<4>::CAPACITY, 4);
+        assert_eq!(a4.into_inner().as_str(), "a");
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        let cmp = |a: &str, b: &str| natural_cmp(a.as_bytes(), b.as_bytes());
+        assert_eq!(cmp("file2", "file10"), Ordering::Less);
+        assert_eq!(cmp("a10b2", "a10b10"), Ordering::Less);
+        assert_eq!(cmp("v1.9", "v1.10"), Ordering::Less);
+        assert_eq!(cmp("x", "x1"), Ordering::Less);
+        assert_eq!(cmp("file", "File"), Ordering::Greater);
+        // Leading zeros only break ties.
+        assert_eq!(cmp("007", "7"), Ordering::Less);
+        assert_eq!(cmp("007", "8"), Ordering::Less);
+        assert_eq!(cmp("12345678901234567890", "9"), Ordering::Greater);
+        assert_eq!(cmp("a1", "a1"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_natural_ord() {
+        let mut labels = ["pump10", "pump9", "pump09"].map(NaturalOrd);
+        labels.sort();
+        assert_eq!(
+            labels.map(NaturalOrd::into_inner),
+            ["pump09", "pump9", "pump10"]
+        );
+        assert_ne!(NaturalOrd("9"), NaturalOrd("09"));
+        assert_eq!(NaturalOrd(FixedStr::<4>::new("7")), NaturalOrd("7"));
+    }
+}
@@ -0,0 +1,263 @@
+// fixed_str/src/fs_padded.rs
+
+use super::*;
+
+/// A string padded with trailing spaces (`0x20`), as used by legacy record formats.
+pub type SpacePadded<const N: usize> = PaddedStr<N, b' '>;
+
+/// A string padded with `0xFF`, the state of erased flash memory.
+pub type FlashPadded<const N: usize> = PaddedStr<N, 0xFF>;
+
+/// A fixed‑capacity UTF‑8 string padded with trailing `PAD` bytes.
+///
+/// Storage conventions differ in the byte used for unused capacity: legacy record formats
+/// (tar headers, FITS, mainframe exports, FIX) use spaces, erased flash reads as `0xFF`.
+/// `PaddedStr<N, PAD>` stores exactly that layout: the content is followed by `PAD` bytes up
+/// to `N` bytes, and its effective length ignores them. [`SpacePadded`] and [`FlashPadded`]
+/// name the common choices.
+///
+/// Unlike [`FixedStr`], the content does not end at the first null byte: only trailing `PAD`
+/// bytes are padding, and other null bytes are kept.
+///
+/// **Note:** Trailing `PAD` bytes in the input cannot be told apart from padding and are dropped.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FlashPadded, SpacePadded};
+///
+/// let field = SpacePadded::<8>::new("ustar");
+/// assert_eq!(field.as_padded_bytes(), b"ustar   ");
+/// assert_eq!(field.as_str(), "ustar");
+///
+/// let read = SpacePadded::from_padded_bytes(*b"FITS    ").unwrap();
+/// assert_eq!(read.len(), 4);
+///
+/// let flash = FlashPadded::<4>::new("id");
+/// assert_eq!(flash.as_padded_bytes(), b"id\xFF\xFF");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct PaddedStr<const N: usize, const PAD: u8> {
+    data: [u8; N],
+}
+
+impl<const N: usize, const PAD: u8> PaddedStr<N, PAD> {
+    /// Creates a new `PaddedStr` from the given input string.
+    ///
+    /// If the input is longer than `N` bytes, it is truncated at the last valid UTF‑8
+    /// boundary, as with [`FixedStr::new`].
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn new(input: &str) -> Self {
+        require_capacity::<N>();
+        let mut len = input.len().min(N);
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+        #[cfg(feature = "debug_lossy")]
+        crate::lossy_hook::report_if_lossy(N, input.len(), len);
+        Self::from_content(&input.as_bytes()[..len])
+    }
+
+    /// Creates a `PaddedStr` from a full field as read from a record.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::InvalidUtf8` if the content (without the trailing padding)
+    /// is not valid UTF‑8.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn from_padded_bytes(bytes: [u8; N]) -> Result<Self, FixedStrError> {
+        require_capacity::<N>();
+        let result = Self { data: bytes };
+        str::from_utf8(result.as_bytes())?;
+        Ok(result)
+    }
+
+    /// Pads the content with `PAD`; `content` must be at most `N` bytes.
+    fn from_content(content: &[u8]) -> Self {
+        let mut data = [PAD; N];
+        data[..content.len()].copy_from_slice(content);
+        Self { data }
+    }
+
+    /// Returns the maximum capacity of the `PaddedStr`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the length of the content, excluding the trailing padding.
+    pub fn len(&self) -> usize {
+        last_non_pad(&self.data, PAD).map_or(0, |last| last + 1)
+    }
+
+    /// Returns `true` if the field contains only padding.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the content as a string slice, excluding the trailing padding.
+    pub fn as_str(&self) -> &str {
+        // Every constructor checks that the content is valid UTF‑8.
+        str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+
+    /// Returns the content bytes, excluding the trailing padding.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len()]
+    }
+
+    /// Returns the full field, including the padding, for writing a record.
+    pub const fn as_padded_bytes(&self) -> &[u8; N] {
+        &self.data
+    }
+
+    /// Converts the content into a null‑padded `FixedStr` of the same capacity.
+    ///
+    /// **Note:** If the content contains a null byte (`\0`), the `FixedStr` ends there.
+    pub fn to_fixed_str(&self) -> FixedStr<N> {
+        let mut data = [0u8; N];
+        data[..self.len()].copy_from_slice(self.as_bytes());
+        FixedStr { data }
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize, const PAD: u8> Default for PaddedStr<N, PAD> {
+    fn default() -> Self {
+        require_capacity::<N>();
+        Self { data: [PAD; N] }
+    }
+}
+
+impl<const N: usize, const PAD: u8> fmt::Debug for PaddedStr<N, PAD> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl<const N: usize, const PAD: u8> fmt::Display for PaddedStr<N, PAD> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<const N: usize, const PAD: u8> NoAllocDisplay for PaddedStr<N, PAD> {}
+
+/// Orders by content, so that padding never sorts after content bytes above `PAD` or
+/// before content bytes below it.
+impl<const N: usize, const PAD: u8> Ord for PaddedStr<N, PAD> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl<const N: usize, const PAD: u8> PartialOrd for PaddedStr<N, PAD> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Converts a string without truncation.
+///
+/// Returns `FixedStrError::Overflow` if the input exceeds `N` bytes.
+impl<const N: usize, const PAD: u8> core::convert::TryFrom<&str> for PaddedStr<N, PAD> {
+    type Error = FixedStrError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        require_capacity::<N>();
+        if s.len() > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: s.len(),
+            });
+        }
+        Ok(Self::from_content(s.as_bytes()))
+    }
+}
+
+/// Replaces the null padding of a `FixedStr` with `PAD`.
+impl<const N: usize, const PAD: u8> From<FixedStr<N>> for PaddedStr<N, PAD> {
+    fn from(fixed: FixedStr<N>) -> Self {
+        Self::from_content(fixed.as_str().as_bytes())
+    }
+}
+
+impl<const N: usize, const PAD: u8> From<PaddedStr<N, PAD>> for FixedStr<N> {
+    fn from(padded: PaddedStr<N, PAD>) -> Self {
+        padded.to_fixed_str()
+    }
+}
+
+impl<const N: usize, const PAD: u8> FixedStrMeta for PaddedStr<N, PAD> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = PAD;
+    const KIND: FixedStrKind = FixedStrKind::PaddedStr;
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod padded_tests {
+    use super::*;
+
+    #[test]
+    fn test_space_padding() {
+        let field = SpacePadded::<6>::new("ab  ");
+        assert_eq!(field.as_padded_bytes(), b"ab    ");
+        assert_eq!(field.len(), 2);
+        assert_eq!(field, SpacePadded::new("ab"));
+        assert!(SpacePadded::<3>::default().is_empty());
+        // Truncation keeps whole characters.
+        assert_eq!(
+            SpacePadded::<3>::new("aéb").as_padded_bytes(),
+            "aé".as_bytes()
+        );
+        assert_eq!(SpacePadded::<3>::new("aaé").as_padded_bytes(), b"aa ");
+    }
+
+    #[test]
+    fn test_from_padded_bytes() {
+        let read = SpacePadded::from_padded_bytes(*b"a\0b ").unwrap();
+        assert_eq!(read.as_bytes(), b"a\0b");
+        assert_eq!(read.to_fixed_str().as_str(), "a");
+        assert_eq!(
+            SpacePadded::from_padded_bytes([0xFF, b' ']),
+            Err(FixedStrError::InvalidUtf8 { valid_up_to: 0 })
+        );
+    }
+
+    #[test]
+    fn test_conversions_and_order() {
+        let fixed = FixedStr::<5>::new("abc");
+        let padded = SpacePadded::from(fixed);
+        assert_eq!(padded.as_padded_bytes(), b"abc  ");
+        assert_eq!(FixedStr::from(padded), fixed);
+        assert_eq!(
+            SpacePadded::<2>::try_from("abc"),
+            Err(FixedStrError::Overflow {
+                available: 2,
+                found: 3
+            })
+        );
+        let low = SpacePadded::<4>::new("ab\t");
+        assert!(SpacePadded::<4>::new("ab") < low);
+    }
+
+    #[test]
+    fn test_other_pad_bytes() {
+        let flash = FlashPadded::<4>::from_padded_bytes([b'o', b'k', 0xFF, 0xFF]).unwrap();
+        assert_eq!(flash.as_str(), "ok");
+        assert!(FlashPadded::<4>::default().is_empty());
+        // With a zero pad byte, only trailing null bytes are padding.
+        let zero = PaddedStr::<4, 0>::from_padded_bytes(*b"a\0b\0").unwrap();
+        assert_eq!(zero.as_bytes(), b"a\0b");
+        assert_eq!(<FlashPadded<4> as FixedStrMeta>::PAD_BYTE, 0xFF);
+    }
+}
@@ -0,0 +1,100 @@
+// fixed_str/src/fs_secret.rs
+
+use super::*;
+
+/// A `FixedStr` holding sensitive content, such as a password or an access token.
+///
+/// Both `Debug` and `Display` print `[REDACTED]`, so the value cannot leak into logs
+/// by accident. The content is only reachable through [`SecretFixedStr::expose`].
+///
+/// Unlike `FixedStr`, this type is not `Copy`. With the `zeroize` feature enabled,
+/// the buffer is securely wiped when the value is dropped.
+///
+/// # Examples
+/// ```
+/// use fixed_str::SecretFixedStr;
+///
+/// let secret = SecretFixedStr::<16>::new("hunter2");
+/// assert_eq!(format!("{:?}", secret), "[REDACTED]");
+/// assert_eq!(secret.expose().as_str(), "hunter2");
+/// ```
+#[derive(Clone, Default)]
+#[repr(transparent)]
+pub struct SecretFixedStr<const N: usize>(pub(super) FixedStr<N>);
+
+impl<const N: usize> SecretFixedStr<N> {
+    /// Creates a new `SecretFixedStr` from the given input string.
+    ///
+    /// The input is truncated at the last valid UTF‑8 boundary, as with [`FixedStr::new`].
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn new(input: &str) -> Self {
+        Self(FixedStr::new(input))
+    }
+
+    /// Returns the maximum capacity of the `SecretFixedStr`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns a reference to the secret content.
+    pub fn expose(&self) -> &FixedStr<N> {
+        &self.0
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> fmt::Debug for SecretFixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl<const N: usize> fmt::Display for SecretFixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("[REDACTED]")
+    }
+}
+
+impl<const N: usize> From<FixedStr<N>> for SecretFixedStr<N> {
+    fn from(fixed: FixedStr<N>) -> Self {
+        Self(fixed)
+    }
+}
+
+#[cfg(feature = "convert_lossy")]
+impl<const N: usize> From<&str> for SecretFixedStr<N> {
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(all(test, feature = "std"))]
+mod secret_tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_is_redacted() {
+        let secret = SecretFixedStr::<8>::new("password");
+        assert_eq!(format!("{:?}", secret), "[REDACTED]");
+        assert_eq!(format!("{}", secret), "[REDACTED]");
+        assert_eq!(secret.expose(), &FixedStr::<8>::new("password"));
+    }
+
+    #[test]
+    fn test_secret_from_fixed_str() {
+        let secret: SecretFixedStr<8> = FixedStr::new("abc").into();
+        assert_eq!(secret.expose().as_str(), "abc");
+        assert_eq!(secret.capacity(), 8);
+    }
+}
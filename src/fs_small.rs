@@ -0,0 +1,336 @@
+// fixed_str/src/fs_small.rs
+
+use super::*;
+
+/// A string stored inline in a `FixedStr<N>` while it fits, and on the heap otherwise
+/// (requires the `alloc` feature).
+///
+/// [`FixedStr`] truncates input that exceeds its capacity. `SmallFixedStr<N>` never does:
+/// content that is longer than `N` bytes, or that contains a null byte, spills into a
+/// `String`. Short values keep the predictable inline layout, and the rare long value is
+/// kept intact instead of being cut.
+///
+/// # Examples
+/// ```
+/// use fixed_str::SmallFixedStr;
+///
+/// let mut name = SmallFixedStr::<8>::new("sensor");
+/// assert!(name.is_inline());
+/// name.push_str("-north-42");
+/// assert!(!name.is_inline());
+/// assert_eq!(name.as_str(), "sensor-north-42");
+/// ```
+#[derive(Clone)]
+pub struct SmallFixedStr<const N: usize> {
+    repr: Repr<N>,
+}
+
+/// The storage of a [`SmallFixedStr`].
+#[derive(Clone)]
+enum Repr<const N: usize> {
+    Inline(FixedStr<N>),
+    Heap(String),
+}
+
+impl<const N: usize> SmallFixedStr<N> {
+    /// Creates a new `SmallFixedStr`, storing the input inline if it fits.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn new(input: &str) -> Self {
+        require_capacity::<N>();
+        let repr = match Self::inline(input) {
+            Some(fixed) => Repr::Inline(fixed),
+            None => Repr::Heap(String::from(input)),
+        };
+        Self { repr }
+    }
+
+    /// Returns the input as a `FixedStr<N>` if it fits without truncation.
+    fn inline(input: &str) -> Option<FixedStr<N>> {
+        if find_first_null(input.as_bytes()) != input.len() {
+            return None;
+        }
+        copy_into_buffer(input.as_bytes(), BufferCopyMode::Exact)
+            .ok()
+            .map(|data| FixedStr { data })
+    }
+
+    /// Returns the inline capacity `N`.
+    pub const fn inline_capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the content is stored inline.
+    pub const fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline(_))
+    }
+
+    /// Returns the length of the content in bytes.
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns `true` if the content is empty.
+    pub fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+
+    /// Returns the content as a string slice.
+    pub fn as_str(&self) -> &str {
+        match &self.repr {
+            Repr::Inline(fixed) => fixed.as_str(),
+            Repr::Heap(heap) => heap,
+        }
+    }
+
+    /// Returns the content as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    /// Appends a string slice, spilling to the heap if the result no longer fits inline.
+    pub fn push_str(&mut self, s: &str) {
+        match &mut self.repr {
+            Repr::Inline(fixed) => {
+                let len = fixed.len();
+                if s.len() <= N - len && find_first_null(s.as_bytes()) == s.len() {
+                    write_at(&mut fixed.data, len, s.as_bytes());
+                    return;
+                }
+                let mut heap = String::with_capacity(len + s.len());
+                heap.push_str(fixed.as_str());
+                heap.push_str(s);
+                self.repr = Repr::Heap(heap);
+            }
+            Repr::Heap(heap) => heap.push_str(s),
+        }
+    }
+
+    /// Clears the content and returns to inline storage.
+    pub fn clear(&mut self) {
+        self.repr = Repr::Inline(FixedStr { data: [0; N] });
+    }
+
+    /// Converts the content into a `String`, reusing the heap allocation if there is one.
+    pub fn into_string(self) -> String {
+        match self.repr {
+            Repr::Inline(fixed) => String::from(fixed.as_str()),
+            Repr::Heap(heap) => heap,
+        }
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> Default for SmallFixedStr<N> {
+    fn default() -> Self {
+        require_capacity::<N>();
+        Self {
+            repr: Repr::Inline(FixedStr { data: [0; N] }),
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for SmallFixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for SmallFixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> NoAllocDisplay for SmallFixedStr<N> {}
+
+/// Returns the whole content; null bytes are content here, not a terminator.
+impl<const N: usize> EffectiveBytes for SmallFixedStr<N> {
+    fn effective_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> AsRef<str> for SmallFixedStr<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for SmallFixedStr<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> Borrow<str> for SmallFixedStr<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the content, regardless of whether it is stored inline or on the heap.
+impl<const N: usize> PartialEq for SmallFixedStr<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for SmallFixedStr<N> {}
+
+impl<const N: usize> Ord for SmallFixedStr<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> PartialOrd for SmallFixedStr<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hashes like `str`, consistent with `Borrow<str>`.
+impl<const N: usize> Hash for SmallFixedStr<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> From<&str> for SmallFixedStr<N> {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+/// Keeps the allocation of a `String` that does not fit inline.
+impl<const N: usize> From<String> for SmallFixedStr<N> {
+    fn from(s: String) -> Self {
+        require_capacity::<N>();
+        let repr = match Self::inline(&s) {
+            Some(fixed) => Repr::Inline(fixed),
+            None => Repr::Heap(s),
+        };
+        Self { repr }
+    }
+}
+
+impl<const N: usize> From<FixedStr<N>> for SmallFixedStr<N> {
+    fn from(fixed: FixedStr<N>) -> Self {
+        let mut data = [0u8; N];
+        write_at(&mut data, 0, fixed.effective_bytes());
+        Self {
+            repr: Repr::Inline(FixedStr { data }),
+        }
+    }
+}
+
+/// Converts the content without truncation.
+///
+/// Returns `FixedStrError::Overflow` if the content exceeds `N` bytes, or
+/// `FixedStrError::InteriorNul` if it contains a null byte.
+impl<const N: usize> core::convert::TryFrom<SmallFixedStr<N>> for FixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(small: SmallFixedStr<N>) -> Result<Self, Self::Error> {
+        let bytes = small.as_bytes();
+        let position = find_first_null(bytes);
+        if position != bytes.len() {
+            return Err(FixedStrError::InteriorNul { position });
+        }
+        let data = copy_into_buffer(bytes, BufferCopyMode::Exact)?;
+        Ok(FixedStr { data })
+    }
+}
+
+impl<const N: usize> FixedStrMeta for SmallFixedStr<N> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::SmallFixedStr;
+}
+
+#[cfg(feature = "cmp_foreign")]
+mod cmp_ext {
+    use crate::*;
+
+    impl<const N: usize> PartialEq<str> for SmallFixedStr<N> {
+        fn eq(&self, other: &str) -> bool {
+            self.as_str() == other
+        }
+    }
+
+    impl<const N: usize> PartialEq<&str> for SmallFixedStr<N> {
+        fn eq(&self, other: &&str) -> bool {
+            self.as_str() == *other
+        }
+    }
+
+    impl<const N: usize> PartialEq<SmallFixedStr<N>> for &str {
+        fn eq(&self, other: &SmallFixedStr<N>) -> bool {
+            *self == other.as_str()
+        }
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod small_tests {
+    use super::*;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_inline_and_spill() {
+        let mut small = SmallFixedStr::<4>::new("ab");
+        assert!(small.is_inline());
+        small.push_str("cd");
+        assert!(small.is_inline());
+        assert_eq!(small.as_str(), "abcd");
+        small.push_str("é");
+        assert!(!small.is_inline());
+        assert_eq!(small.as_str(), "abcdé");
+        assert_eq!(small, SmallFixedStr::new("abcdé"));
+        small.clear();
+        assert!(small.is_inline() && small.is_empty());
+    }
+
+    #[test]
+    fn test_null_bytes_spill() {
+        let mut small = SmallFixedStr::<8>::new("a\0b");
+        assert!(!small.is_inline());
+        assert_eq!(small.len(), 3);
+        assert_eq!(small.effective_bytes(), b"a\0b");
+        assert_eq!(
+            FixedStr::try_from(small.clone()),
+            Err(FixedStrError::InteriorNul { position: 1 })
+        );
+        assert!(!SmallFixedStr::<8>::from(String::from("long string")).is_inline());
+        assert!(SmallFixedStr::<8>::from(String::from("short")).is_inline());
+        small.push_str("c");
+        assert_eq!(small.into_string(), "a\0bc");
+    }
+
+    #[test]
+    fn test_conversions() {
+        let fixed = FixedStr::<4>::from_bytes(*b"ab\0x");
+        let small = SmallFixedStr::from(fixed);
+        assert!(small.is_inline());
+        assert_eq!(FixedStr::try_from(small).unwrap().as_bytes(), b"ab\0\0");
+        assert_eq!(
+            FixedStr::<4>::try_from(SmallFixedStr::new("abcde")),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 5
+            })
+        );
+        assert_eq!(
+            <SmallFixedStr<4> as FixedStrMeta>::KIND,
+            FixedStrKind::SmallFixedStr
+        );
+    }
+}
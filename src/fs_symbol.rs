@@ -0,0 +1,281 @@
+// fixed_str/src/fs_symbol.rs
+
+use super::*;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::sync::atomic::{AtomicPtr, Ordering as AtomicOrdering};
+use std::any::Any;
+use std::sync::Mutex;
+
+/// The process‑wide symbol table for one capacity `N`.
+struct SymbolTable<const N: usize> {
+    interner: FixedStrInterner<N>,
+    /// The leaked copy of each interned value, indexed by symbol.
+    values: Vec<&'static FixedStr<N>>,
+}
+
+/// The symbol tables of all capacities in use, one `SymbolTable<N>` per `N`.
+type SymbolTables = Mutex<Vec<Box<dyn Any + Send>>>;
+
+/// Returns the symbol tables, creating them on first use.
+fn symbol_tables() -> &'static SymbolTables {
+    static TABLES: AtomicPtr<SymbolTables> = AtomicPtr::new(core::ptr::null_mut());
+    let ptr = TABLES.load(AtomicOrdering::Acquire);
+    if !ptr.is_null() {
+        // SAFETY: The pointer was leaked from a `Box` below and is never freed.
+        return unsafe { &*ptr };
+    }
+    let new = Box::into_raw(Box::new(Mutex::new(Vec::new())));
+    match TABLES.compare_exchange(
+        core::ptr::null_mut(),
+        new,
+        AtomicOrdering::AcqRel,
+        AtomicOrdering::Acquire,
+    ) {
+        // SAFETY: `new` was just leaked and is now shared for the rest of the program.
+        Ok(_) => unsafe { &*new },
+        Err(existing) => {
+            // SAFETY: Another thread won the race; `new` was never shared.
+            drop(unsafe { Box::from_raw(new) });
+            // SAFETY: As above, `existing` is never freed.
+            unsafe { &*existing }
+        }
+    }
+}
+
+/// Interns `value` in the process‑wide table for `N`, returning its symbol and stored copy.
+fn intern_global<const N: usize>(value: FixedStr<N>) -> (Symbol, &'static FixedStr<N>) {
+    let mut tables = symbol_tables()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let index = match tables.iter().position(|table| table.is::<SymbolTable<N>>()) {
+        Some(index) => index,
+        None => {
+            tables.push(Box::new(SymbolTable::<N> {
+                interner: FixedStrInterner::new(),
+                values: Vec::new(),
+            }));
+            tables.len() - 1
+        }
+    };
+    let table = tables[index]
+        .downcast_mut::<SymbolTable<N>>()
+        .expect("Symbol table type was checked above");
+    let symbol = table.interner.intern(value);
+    if symbol.index() == table.values.len() {
+        let stored: &'static FixedStr<N> = Box::leak(Box::new(*table.interner.resolve(symbol)));
+        table.values.push(stored);
+    }
+    (symbol, table.values[symbol.index()])
+}
+
+/// A validated `FixedStr` interned in a process‑wide table, for cheap `Copy` map keys.
+///
+/// Each distinct value is validated by `V` and stored once for the rest of the program; a
+/// `SymbolStr` only holds its [`Symbol`] and a reference to that copy. Equality and hashing
+/// compare symbols, so keys stay cheap to copy and compare however large `N` is. Ordering
+/// and formatting use the string content.
+///
+/// With the `serde` feature, a `SymbolStr` serializes as a string and deserializes through
+/// the interner, rejecting overlong content and content refused by `V`. This suits
+/// configuration files that repeat the same names thousands of times.
+///
+/// Interned values are never freed, so only use `SymbolStr` for a bounded set of names.
+/// Requires the `std` feature.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{PrintableAscii, SymbolStr};
+///
+/// type Name = SymbolStr<16, PrintableAscii>;
+///
+/// let a = Name::new("pump").unwrap();
+/// let b = Name::new("pump").unwrap();
+/// assert_eq!(a, b);
+/// assert_eq!(a.symbol(), b.symbol());
+/// assert_eq!(a.as_str(), "pump");
+/// assert!(Name::new("pump\n").is_err());
+/// ```
+pub struct SymbolStr<const N: usize, V> {
+    symbol: Symbol,
+    value: &'static FixedStr<N>,
+    validator: PhantomData<fn() -> V>,
+}
+
+impl<const N: usize, V: Validator + Default> SymbolStr<N, V> {
+    /// Validates and interns `input`, without truncation.
+    ///
+    /// # Errors
+    /// - Returns `ValidationError::Invalid` if the input exceeds the capacity.
+    /// - Returns `ValidationError::Rule` if the validator rejects the content.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn new(input: &str) -> Result<Self, ValidationError<V::Error>> {
+        ValidatedFixedStr::new(input).map(Self::from_validated)
+    }
+}
+
+impl<const N: usize, V: Validator> SymbolStr<N, V> {
+    /// Interns an already validated string.
+    pub fn from_validated(validated: ValidatedFixedStr<N, V>) -> Self {
+        let (symbol, value) = intern_global(validated.into_inner());
+        Self {
+            symbol,
+            value,
+            validator: PhantomData,
+        }
+    }
+
+    /// Returns the symbol of the value in the process‑wide table for capacity `N`.
+    pub const fn symbol(&self) -> Symbol {
+        self.symbol
+    }
+
+    /// Returns the interned `FixedStr`.
+    pub const fn as_fixed_str(&self) -> &'static FixedStr<N> {
+        self.value
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize, V> Clone for SymbolStr<N, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, V> Copy for SymbolStr<N, V> {}
+
+impl<const N: usize, V> Deref for SymbolStr<N, V> {
+    type Target = FixedStr<N>;
+    fn deref(&self) -> &FixedStr<N> {
+        self.value
+    }
+}
+
+impl<const N: usize, V> AsRef<FixedStr<N>> for SymbolStr<N, V> {
+    fn as_ref(&self) -> &FixedStr<N> {
+        self.value
+    }
+}
+
+impl<const N: usize, V> AsRef<str> for SymbolStr<N, V> {
+    fn as_ref(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl<const N: usize, V> fmt::Debug for SymbolStr<N, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.value, f)
+    }
+}
+
+impl<const N: usize, V> fmt::Display for SymbolStr<N, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.value, f)
+    }
+}
+
+impl<const N: usize, V> NoAllocDisplay for SymbolStr<N, V> {}
+
+impl<const N: usize, V> PartialEq for SymbolStr<N, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.symbol == other.symbol
+    }
+}
+
+impl<const N: usize, V> Eq for SymbolStr<N, V> {}
+
+impl<const N: usize, V> Ord for SymbolStr<N, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.symbol == other.symbol {
+            Ordering::Equal
+        } else {
+            self.value.cmp(other.value)
+        }
+    }
+}
+
+impl<const N: usize, V> PartialOrd for SymbolStr<N, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, V> Hash for SymbolStr<N, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.symbol.hash(state);
+    }
+}
+
+impl<const N: usize, V: Validator + Default> core::convert::TryFrom<&str> for SymbolStr<N, V> {
+    type Error = ValidationError<V::Error>;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize, V> From<SymbolStr<N, V>> for FixedStr<N> {
+    fn from(symbol: SymbolStr<N, V>) -> Self {
+        *symbol.value
+    }
+}
+
+impl<const N: usize, V> FixedStrMeta for SymbolStr<N, V> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::SymbolStr;
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod symbol_tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_interning_shares_storage() {
+        let a = SymbolStr::<8, AsciiOnly>::new("alpha").unwrap();
+        let b = SymbolStr::<8, AsciiOnly>::new("beta").unwrap();
+        let again = SymbolStr::<8, AsciiOnly>::new("alpha").unwrap();
+        assert_eq!(a, again);
+        assert_ne!(a, b);
+        assert!(core::ptr::eq(a.as_fixed_str(), again.as_fixed_str()));
+        assert!(a < b);
+        assert_eq!(FixedStr::from(b).as_str(), "beta");
+        let set: HashSet<_> = [a, b, again].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_capacities_use_separate_tables() {
+        let short = SymbolStr::<4, AsciiOnly>::new("abc").unwrap();
+        let long = SymbolStr::<12, AsciiOnly>::new("abc").unwrap();
+        assert_eq!(short.as_str(), long.as_str());
+        assert_eq!(short.as_fixed_str().as_bytes().len(), 4);
+        assert_eq!(long.as_fixed_str().as_bytes().len(), 12);
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        assert_eq!(
+            SymbolStr::<8, AsciiAlphanumeric>::new("a-b"),
+            Err(ValidationError::Rule(RejectedChar {
+                position: 1,
+                ch: '-'
+            }))
+        );
+        assert!(matches!(
+            SymbolStr::<2, AsciiOnly>::new("abc"),
+            Err(ValidationError::Invalid(FixedStrError::Overflow { .. }))
+        ));
+    }
+}
@@ -0,0 +1,164 @@
+// fixed_str/src/fs_table.rs
+
+use super::*;
+use core::cmp::Ordering;
+
+/// A const‑initialized lookup table of `FixedStr<N>` keys.
+///
+/// The entries are sorted at compile time, so [`FixedTable::lookup`] is a binary search
+/// that returns the position of the key in the original entry list. Usually built with
+/// [`fixed_table!`](crate::fixed_table).
+///
+/// # Examples
+/// ```
+/// use fixed_str::{fixed_table, FixedStr, FixedTable};
+///
+/// const KEYWORDS: FixedTable<8, 3> = fixed_table!(8; "while", "if", "else");
+///
+/// assert_eq!(KEYWORDS.lookup(&FixedStr::new("else")), Some(2));
+/// assert_eq!(KEYWORDS.lookup(&FixedStr::new("loop")), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedTable<const N: usize, const M: usize> {
+    /// The entries in sorted order.
+    entries: [FixedStr<N>; M],
+    /// The original position of each sorted entry.
+    indices: [usize; M],
+}
+
+impl<const N: usize, const M: usize> FixedTable<N, M> {
+    /// Creates a table from the given entries, sorting them in a const context.
+    ///
+    /// Entries are ordered like `FixedStr<N>`, i.e. by their effective bytes.
+    ///
+    /// # Panics
+    /// Panics if an entry occurs more than once.
+    pub const fn new(entries: [FixedStr<N>; M]) -> Self {
+        let mut entries = entries;
+        let mut indices = [0usize; M];
+        let mut i = 0;
+        while i < M {
+            indices[i] = i;
+            i += 1;
+        }
+
+        // Insertion sort, as the table is built at compile time and usually small.
+        let mut i = 1;
+        while i < M {
+            let mut j = i;
+            while j > 0 {
+                match entries[j - 1].const_cmp(&entries[j]) {
+                    Ordering::Less => break,
+                    Ordering::Equal => panic!("Duplicate entry in fixed table"),
+                    Ordering::Greater => {
+                        let entry = entries[j - 1];
+                        entries[j - 1] = entries[j];
+                        entries[j] = entry;
+                        let index = indices[j - 1];
+                        indices[j - 1] = indices[j];
+                        indices[j] = index;
+                    }
+                }
+                j -= 1;
+            }
+            i += 1;
+        }
+
+        Self { entries, indices }
+    }
+
+    /// Returns the position of `key` in the original entry list, or `None` if it is not
+    /// in the table.
+    pub fn lookup(&self, key: &FixedStr<N>) -> Option<usize> {
+        let mut low = 0;
+        let mut high = M;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.entries[mid].const_cmp(key) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Some(self.indices[mid]),
+            }
+        }
+        None
+    }
+
+    /// Returns the entries in sorted order.
+    pub const fn entries(&self) -> &[FixedStr<N>; M] {
+        &self.entries
+    }
+
+    /// Returns the number of entries.
+    pub const fn len(&self) -> usize {
+        M
+    }
+
+    /// Returns `true` if the table has no entries.
+    pub const fn is_empty(&self) -> bool {
+        M == 0
+    }
+}
+
+/// Builds a [`FixedTable`] of `FixedStr<N>` keys from string literals at compile time.
+///
+/// The first argument is the capacity `N`, followed by the entries. Entries longer than `N`
+/// are truncated as with [`FixedStr::new_const`].
+///
+/// # Examples
+/// ```
+/// use fixed_str::{fixed_table, FixedStr, FixedTable};
+///
+/// static UNITS: FixedTable<4, 3> = fixed_table!(4; "ms", "s", "min");
+/// assert_eq!(UNITS.lookup(&FixedStr::new("ms")), Some(0));
+/// ```
+#[macro_export]
+macro_rules! fixed_table {
+    ($n:expr; $($entry:expr),* $(,)?) => {
+        $crate::FixedTable::<$n, { [$($entry),*].len() }>::new([
+            $($crate::FixedStr::<$n>::new_const($entry)),*
+        ])
+    };
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod table_tests {
+    use super::*;
+
+    const COMMANDS: FixedTable<6, 5> = fixed_table!(6; "QUIT", "HELO", "DATA", "RCPT", "MAIL");
+
+    #[test]
+    fn test_fixed_table_lookup() {
+        assert_eq!(COMMANDS.len(), 5);
+        assert_eq!(COMMANDS.lookup(&FixedStr::new("QUIT")), Some(0));
+        assert_eq!(COMMANDS.lookup(&FixedStr::new("HELO")), Some(1));
+        assert_eq!(COMMANDS.lookup(&FixedStr::new("MAIL")), Some(4));
+        assert_eq!(COMMANDS.lookup(&FixedStr::new("EHLO")), None);
+        assert_eq!(COMMANDS.lookup(&FixedStr::new("")), None);
+        assert_eq!(COMMANDS.entries()[0].as_str(), "DATA");
+        assert_eq!(COMMANDS.entries()[4].as_str(), "RCPT");
+    }
+
+    #[test]
+    fn test_fixed_table_prefixes() {
+        let table = fixed_table!(4; "abc", "ab", "a", "");
+        assert_eq!(table.lookup(&FixedStr::new("")), Some(3));
+        assert_eq!(table.lookup(&FixedStr::new("a")), Some(2));
+        assert_eq!(table.lookup(&FixedStr::new("ab")), Some(1));
+        assert_eq!(table.lookup(&FixedStr::new("abc")), Some(0));
+        assert_eq!(table.lookup(&FixedStr::new("abcd")), None);
+
+        let empty: FixedTable<4, 0> = FixedTable::new([]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.lookup(&FixedStr::new("a")), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fixed_table_duplicate() {
+        let _ = fixed_table!(4; "a", "b", "a");
+    }
+}
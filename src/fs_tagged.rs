@@ -0,0 +1,211 @@
+// fixed_str/src/fs_tagged.rs
+
+use super::*;
+
+/// The version of the tagged encoding written by [`FixedStr::encode_tagged`].
+pub const TAGGED_VERSION: u8 = 1;
+
+/// The length of the tagged header: version (1 byte), capacity (`u32` LE) and flags (1 byte).
+pub const TAGGED_HEADER_LEN: usize = 6;
+
+/// The flags understood by this version of the tagged encoding (none so far).
+const TAGGED_KNOWN_FLAGS: u8 = 0;
+
+impl<const N: usize> FixedStr<N> {
+    /// Encodes the value in the self‑describing tagged form and returns the number of
+    /// bytes written.
+    ///
+    /// The output is a [`TAGGED_HEADER_LEN`]‑byte header (version, capacity `N` as
+    /// little‑endian `u32`, flags) followed by the full `N`‑byte buffer. Readers can
+    /// detect a different capacity instead of silently mis‑slicing the payload.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if `out` is shorter than `TAGGED_HEADER_LEN + N`
+    /// bytes, or if `N` does not fit into a `u32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let mut out = [0u8; 10];
+    /// let written = FixedStr::<4>::new("ab").encode_tagged(&mut out).unwrap();
+    /// assert_eq!(&out[..written], b"\x01\x04\0\0\0\0ab\0\0");
+    ///
+    /// // A build with a different capacity does not mis-read the field...
+    /// assert!(FixedStr::<8>::decode_tagged(&out).is_err());
+    /// // ...but can migrate it explicitly.
+    /// let (migrated, _) = FixedStr::<8>::decode_tagged_migrating(&out).unwrap();
+    /// assert_eq!(migrated.as_str(), "ab");
+    /// ```
+    pub fn encode_tagged(&self, out: &mut [u8]) -> Result<usize, FixedStrError> {
+        let total = TAGGED_HEADER_LEN + N;
+        let capacity = u32::try_from(N).map_err(|_| FixedStrError::Overflow {
+            available: u32::MAX as usize,
+            found: N,
+        })?;
+        if out.len() < total {
+            return Err(FixedStrError::Overflow {
+                available: out.len(),
+                found: total,
+            });
+        }
+        out[0] = TAGGED_VERSION;
+        out[1..5].copy_from_slice(&capacity.to_le_bytes());
+        out[5] = 0;
+        out[TAGGED_HEADER_LEN..total].copy_from_slice(&self.data);
+        Ok(total)
+    }
+
+    /// Decodes a value written by [`FixedStr::encode_tagged`] with the same capacity `N`.
+    ///
+    /// Returns the value and the number of bytes consumed. The buffer is restored as‑is.
+    ///
+    /// # Errors
+    /// - `TaggedError::CapacityMismatch` if the value was written with a different capacity;
+    ///   use [`FixedStr::decode_tagged_migrating`] to convert it.
+    /// - `TaggedError::Invalid` holding `FixedStrError::InvalidUtf8` if the effective bytes
+    ///   are not valid UTF‑8.
+    /// - See [`TaggedError`] for malformed input.
+    pub fn decode_tagged(input: &[u8]) -> Result<(Self, usize), TaggedError> {
+        let (capacity, payload) = split_tagged(input)?;
+        if capacity != N {
+            return Err(TaggedError::CapacityMismatch {
+                expected: N,
+                found: capacity,
+            });
+        }
+        let mut data = [0u8; N];
+        data.copy_from_slice(payload);
+        let fixed = Self { data };
+        fixed.try_as_str()?;
+        Ok((fixed, TAGGED_HEADER_LEN + capacity))
+    }
+
+    /// Decodes a value written by [`FixedStr::encode_tagged`] with any capacity.
+    ///
+    /// The effective bytes of the payload are copied into a zero‑padded `FixedStr<N>`.
+    /// Returns the value and the number of bytes consumed.
+    ///
+    /// # Errors
+    /// - `TaggedError::Invalid` holding `FixedStrError::Overflow` if the effective string
+    ///   does not fit into `N` bytes, or `FixedStrError::InvalidUtf8` if it is not valid UTF‑8.
+    /// - See [`TaggedError`] for malformed input.
+    pub fn decode_tagged_migrating(input: &[u8]) -> Result<(Self, usize), TaggedError> {
+        let (capacity, payload) = split_tagged(input)?;
+        let effective = &payload[..find_first_null(payload)];
+        let data = copy_into_buffer(effective, BufferCopyMode::Exact)?;
+        let fixed = Self { data };
+        fixed.try_as_str()?;
+        Ok((fixed, TAGGED_HEADER_LEN + capacity))
+    }
+}
+
+/// Checks the tagged header and returns the stored capacity and its payload.
+fn split_tagged(input: &[u8]) -> Result<(usize, &[u8]), TaggedError> {
+    if input.len() < TAGGED_HEADER_LEN {
+        return Err(TaggedError::UnexpectedEnd {
+            needed: TAGGED_HEADER_LEN,
+            found: input.len(),
+        });
+    }
+    if input[0] != TAGGED_VERSION {
+        return Err(TaggedError::UnsupportedVersion(input[0]));
+    }
+    if input[5] & !TAGGED_KNOWN_FLAGS != 0 {
+        return Err(TaggedError::UnsupportedFlags(input[5]));
+    }
+    let capacity = u32::from_le_bytes([input[1], input[2], input[3], input[4]]) as usize;
+    let payload = &input[TAGGED_HEADER_LEN..];
+    if payload.len() < capacity {
+        return Err(TaggedError::UnexpectedEnd {
+            needed: TAGGED_HEADER_LEN.saturating_add(capacity),
+            found: input.len(),
+        });
+    }
+    Ok((capacity, &payload[..capacity]))
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod tagged_tests {
+    use super::*;
+
+    #[test]
+    fn test_tagged_roundtrip() {
+        let fixed = FixedStr::<6>::new("héllo");
+        let mut out = [0xAAu8; 16];
+        assert_eq!(fixed.encode_tagged(&mut out), Ok(12));
+        assert_eq!(&out[..6], b"\x01\x06\0\0\0\0");
+        assert_eq!(FixedStr::<6>::decode_tagged(&out), Ok((fixed, 12)));
+
+        let mut short = [0u8; 11];
+        assert_eq!(
+            fixed.encode_tagged(&mut short),
+            Err(FixedStrError::Overflow {
+                available: 11,
+                found: 12
+            })
+        );
+    }
+
+    #[test]
+    fn test_tagged_capacity_mismatch() {
+        let mut out = [0u8; 14];
+        FixedStr::<8>::new("abc").encode_tagged(&mut out).unwrap();
+        assert_eq!(
+            FixedStr::<4>::decode_tagged(&out),
+            Err(TaggedError::CapacityMismatch {
+                expected: 4,
+                found: 8
+            })
+        );
+        let (migrated, used) = FixedStr::<4>::decode_tagged_migrating(&out).unwrap();
+        assert_eq!((migrated, used), (FixedStr::new("abc"), 14));
+
+        FixedStr::<8>::new("abcdef")
+            .encode_tagged(&mut out)
+            .unwrap();
+        assert_eq!(
+            FixedStr::<4>::decode_tagged_migrating(&out),
+            Err(TaggedError::Invalid(FixedStrError::Overflow {
+                available: 4,
+                found: 6
+            }))
+        );
+    }
+
+    #[test]
+    fn test_tagged_malformed() {
+        assert_eq!(
+            FixedStr::<4>::decode_tagged(b"\x01\x04\0"),
+            Err(TaggedError::UnexpectedEnd {
+                needed: 6,
+                found: 3
+            })
+        );
+        assert_eq!(
+            FixedStr::<4>::decode_tagged(b"\x01\x04\0\0\0\0ab"),
+            Err(TaggedError::UnexpectedEnd {
+                needed: 10,
+                found: 8
+            })
+        );
+        assert_eq!(
+            FixedStr::<4>::decode_tagged(b"\x02\x04\0\0\0\0abcd"),
+            Err(TaggedError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            FixedStr::<4>::decode_tagged(b"\x01\x04\0\0\0\x80abcd"),
+            Err(TaggedError::UnsupportedFlags(0x80))
+        );
+        assert_eq!(
+            FixedStr::<4>::decode_tagged(b"\x01\x04\0\0\0\0a\xFFcd"),
+            Err(TaggedError::Invalid(FixedStrError::InvalidUtf8 {
+                valid_up_to: 1
+            }))
+        );
+    }
+}
@@ -0,0 +1,270 @@
+// fixed_str/src/fs_utf16.rs
+
+use super::*;
+
+/// A fixed‑capacity UTF‑16 string of `N` code units.
+///
+/// The sibling of [`FixedStr`] for Windows registry and file formats and UEFI: the string
+/// is stored in a `[u16; N]` array, ends at the first null unit and is zero‑padded.
+/// Truncation never splits a surrogate pair.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, FixedUtf16Str};
+///
+/// let name = FixedUtf16Str::<3>::new("a😊b");
+/// // "😊" is a surrogate pair, so "b" does not fit.
+/// assert_eq!(name.as_units(), &[0x61, 0xD83D, 0xDE0A]);
+/// assert_eq!(name.to_fixed_str::<8>(), FixedStr::<8>::new("a😊"));
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct FixedUtf16Str<const N: usize> {
+    data: [u16; N],
+}
+
+impl<const N: usize> FixedUtf16Str<N> {
+    /// Creates a new `FixedUtf16Str` from the given input string.
+    ///
+    /// If the input needs more than `N` code units, it is truncated after the last complete
+    /// character that fits. If the input contains a null character, the string ends there.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn new(input: &str) -> Self {
+        require_capacity::<N>();
+        let mut data = [0u16; N];
+        let mut len = 0;
+        for c in input.chars().take_while(|&c| c != '\0') {
+            if len + c.len_utf16() > N {
+                break;
+            }
+            len += c.encode_utf16(&mut data[len..]).len();
+        }
+        let result = Self { data };
+        #[cfg(feature = "debug_lossy")]
+        crate::lossy_hook::report_if_lossy(
+            N,
+            find_first_null(input.as_bytes()),
+            result.chars().map(char::len_utf8).sum(),
+        );
+        result
+    }
+
+    /// Creates a `FixedUtf16Str` from a full array of code units.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::InvalidUtf16` if the units up to the first null unit contain
+    /// an unpaired surrogate.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn from_units(units: [u16; N]) -> Result<Self, FixedStrError> {
+        require_capacity::<N>();
+        let result = Self { data: units };
+        if char::decode_utf16(result.as_units().iter().copied()).any(|c| c.is_err()) {
+            return Err(FixedStrError::InvalidUtf16);
+        }
+        Ok(result)
+    }
+
+    /// Creates a `FixedUtf16Str` from the effective string of a `FixedStr`, truncating as
+    /// [`FixedUtf16Str::new`] does.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn from_fixed_str<const M: usize>(fixed: &FixedStr<M>) -> Self {
+        Self::new(fixed.as_str())
+    }
+
+    /// Returns the maximum capacity in code units.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of code units up to the first null unit.
+    pub fn len(&self) -> usize {
+        self.data.iter().position(|&u| u == 0).unwrap_or(N)
+    }
+
+    /// Returns `true` if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data[0] == 0
+    }
+
+    /// Returns the code units up to the first null unit.
+    pub fn as_units(&self) -> &[u16] {
+        &self.data[..self.len()]
+    }
+
+    /// Returns the full array of code units, including the padding.
+    pub const fn as_raw_units(&self) -> &[u16; N] {
+        &self.data
+    }
+
+    /// Returns an iterator over the characters of the string.
+    ///
+    /// Unpaired surrogates can only be present if the value was built from raw units; they
+    /// are yielded as `U+FFFD`.
+    pub fn chars(&self) -> impl Iterator<Item = char> + Clone + '_ {
+        char::decode_utf16(self.as_units().iter().copied())
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Converts the string to UTF‑8, truncating at the last complete character that fits
+    /// into `M` bytes.
+    ///
+    /// # Panics
+    /// Panics if `M == 0`. Zero‑length strings are not supported.
+    pub fn to_fixed_str<const M: usize>(&self) -> FixedStr<M> {
+        let mut buf = FixedStrBuf::<M>::new();
+        for c in self.chars() {
+            if buf.try_push_char(c).is_err() {
+                break;
+            }
+        }
+        buf.finalize()
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> Default for FixedUtf16Str<N> {
+    fn default() -> Self {
+        require_capacity::<N>();
+        Self { data: [0u16; N] }
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedUtf16Str<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+        for c in self.chars() {
+            write!(f, "{}", c.escape_debug())?;
+        }
+        write!(f, "\"")
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedUtf16Str<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fs_display::pad_chars(f, self.chars())
+    }
+}
+
+impl<const N: usize> NoAllocDisplay for FixedUtf16Str<N> {}
+
+impl<const N: usize> PartialEq for FixedUtf16Str<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_units() == other.as_units()
+    }
+}
+
+impl<const N: usize> Eq for FixedUtf16Str<N> {}
+
+impl<const N: usize> Hash for FixedUtf16Str<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_units().hash(state);
+    }
+}
+
+/// Orders by code units, which differs from the order of the UTF‑8 strings for characters
+/// above `U+FFFF`.
+impl<const N: usize> Ord for FixedUtf16Str<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_units().cmp(other.as_units())
+    }
+}
+
+impl<const N: usize> PartialOrd for FixedUtf16Str<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Converts a string without truncation.
+///
+/// Returns `FixedStrError::Overflow` (counted in code units) if the input needs more than
+/// `N` units. As with [`FixedStr::set`], the string ends at the first null character.
+impl<const N: usize> core::convert::TryFrom<&str> for FixedUtf16Str<N> {
+    type Error = FixedStrError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let effective = &s[..find_first_null(s.as_bytes())];
+        let needed = effective.encode_utf16().count();
+        if needed > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: needed,
+            });
+        }
+        Ok(Self::new(effective))
+    }
+}
+
+impl<const N: usize> FixedStrMeta for FixedUtf16Str<N> {
+    const CAPACITY: usize = N * 2;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::FixedUtf16Str;
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod utf16_tests {
+    use super::*;
+
+    #[test]
+    fn test_surrogate_aware_truncation() {
+        let full = FixedUtf16Str::<3>::new("a😊");
+        assert_eq!(full.len(), 3);
+        let cut = FixedUtf16Str::<2>::new("a😊");
+        assert_eq!(cut.as_units(), &[u16::from(b'a')]);
+        assert_eq!(cut.as_raw_units(), &[u16::from(b'a'), 0]);
+        assert_eq!(FixedUtf16Str::<4>::new("ab\0cd").len(), 2);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let fixed = FixedStr::<16>::new("héllo 😊");
+        let wide = FixedUtf16Str::<8>::from_fixed_str(&fixed);
+        assert_eq!(wide.len(), 8);
+        assert_eq!(wide.to_fixed_str::<16>(), fixed);
+        // "😊" needs 4 UTF‑8 bytes, so it does not fit into 9.
+        assert_eq!(wide.to_fixed_str::<9>(), FixedStr::<9>::new("héllo "));
+        assert_eq!(
+            FixedUtf16Str::<2>::try_from("abc"),
+            Err(FixedStrError::Overflow {
+                available: 2,
+                found: 3
+            })
+        );
+        assert_eq!(FixedUtf16Str::<2>::try_from("😊").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_from_units() {
+        let units = [0x61, 0, 0xD800, 0];
+        let wide = FixedUtf16Str::from_units(units).unwrap();
+        assert_eq!(wide, FixedUtf16Str::new("a"));
+        assert_eq!(
+            FixedUtf16Str::from_units([0x61, 0xD800]),
+            Err(FixedStrError::InvalidUtf16)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatting() {
+        let wide = FixedUtf16Str::<8>::new("a\"😊");
+        assert_eq!(format!("{}", wide), "a\"😊");
+        assert_eq!(format!("{:?}", wide), "\"a\\\"😊\"");
+        assert_eq!(format!("[{:>5}]", wide), "[  a\"😊]");
+        assert_eq!(format!("[{:*^6.2}]", wide), "[**a\"**]");
+    }
+}
@@ -0,0 +1,223 @@
+// fixed_str/src/fs_validated.rs
+
+use super::*;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+/// A `FixedStr` whose content is known to satisfy the [`Validator`] `V`.
+///
+/// The validator is part of the type, so an invariant such as "printable ASCII label" is
+/// checked once on construction and then carried by the value. Content is only reachable
+/// read‑only (through `Deref` to [`FixedStr`]), so it cannot be changed to violate the rule.
+///
+/// Type‑level validators are unit structs implementing `Validator + Default`, such as
+/// [`AsciiOnly`] or [`PrintableAscii`]. Validators with state, including closures, can be
+/// passed to [`ValidatedFixedStr::new_with`].
+///
+/// # Examples
+/// ```
+/// use fixed_str::{PrintableAscii, RejectedChar, ValidatedFixedStr, ValidationError};
+///
+/// type Label = ValidatedFixedStr<16, PrintableAscii>;
+///
+/// let label = Label::new("Pump 2").unwrap();
+/// assert_eq!(label.as_str(), "Pump 2");
+///
+/// let err = Label::new("Pump\t2").unwrap_err();
+/// assert_eq!(err, ValidationError::Rule(RejectedChar { position: 4, ch: '\t' }));
+/// ```
+#[repr(transparent)]
+pub struct ValidatedFixedStr<const N: usize, V>(FixedStr<N>, PhantomData<fn() -> V>);
+
+impl<const N: usize, V: Validator + Default> ValidatedFixedStr<N, V> {
+    /// Creates a new `ValidatedFixedStr`, without truncation.
+    ///
+    /// # Errors
+    /// - Returns `ValidationError::Invalid` if the input exceeds the capacity.
+    /// - Returns `ValidationError::Rule` if the validator rejects the content.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn new(input: &str) -> Result<Self, ValidationError<V::Error>> {
+        Self::new_with(input, &V::default())
+    }
+
+    /// Validates an existing `FixedStr`.
+    ///
+    /// # Errors
+    /// - Returns `ValidationError::Invalid` if the content is not valid UTF‑8.
+    /// - Returns `ValidationError::Rule` if the validator rejects the content.
+    pub fn from_fixed(fixed: FixedStr<N>) -> Result<Self, ValidationError<V::Error>> {
+        V::default()
+            .validate(fixed.try_as_str()?)
+            .map_err(ValidationError::Rule)?;
+        Ok(Self(fixed, PhantomData))
+    }
+}
+
+impl<const N: usize, V: Validator> ValidatedFixedStr<N, V> {
+    /// Creates a new `ValidatedFixedStr`, checking the content with the given validator.
+    ///
+    /// # Errors
+    /// - Returns `ValidationError::Invalid` if the input exceeds the capacity.
+    /// - Returns `ValidationError::Rule` if the validator rejects the content.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    pub fn new_with(input: &str, validator: &V) -> Result<Self, ValidationError<V::Error>> {
+        FixedStr::new_validated(input, validator).map(|fixed| Self(fixed, PhantomData))
+    }
+
+    /// Returns the validated `FixedStr`.
+    pub const fn as_fixed_str(&self) -> &FixedStr<N> {
+        &self.0
+    }
+
+    /// Returns the validated `FixedStr`, dropping the guarantee from the type.
+    pub const fn into_inner(self) -> FixedStr<N> {
+        self.0
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize, V> Clone for ValidatedFixedStr<N, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, V> Copy for ValidatedFixedStr<N, V> {}
+
+impl<const N: usize, V> Deref for ValidatedFixedStr<N, V> {
+    type Target = FixedStr<N>;
+    fn deref(&self) -> &FixedStr<N> {
+        &self.0
+    }
+}
+
+impl<const N: usize, V> AsRef<FixedStr<N>> for ValidatedFixedStr<N, V> {
+    fn as_ref(&self) -> &FixedStr<N> {
+        &self.0
+    }
+}
+
+impl<const N: usize, V> AsRef<str> for ValidatedFixedStr<N, V> {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<const N: usize, V> Borrow<str> for ValidatedFixedStr<N, V> {
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<const N: usize, V> fmt::Debug for ValidatedFixedStr<N, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize, V> fmt::Display for ValidatedFixedStr<N, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize, V> NoAllocDisplay for ValidatedFixedStr<N, V> {}
+
+impl<const N: usize, V> PartialEq for ValidatedFixedStr<N, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<const N: usize, V> Eq for ValidatedFixedStr<N, V> {}
+
+impl<const N: usize, V> Ord for ValidatedFixedStr<N, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<const N: usize, V> PartialOrd for ValidatedFixedStr<N, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, V> Hash for ValidatedFixedStr<N, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<const N: usize, V: Validator + Default> core::convert::TryFrom<&str>
+    for ValidatedFixedStr<N, V>
+{
+    type Error = ValidationError<V::Error>;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize, V> From<ValidatedFixedStr<N, V>> for FixedStr<N> {
+    fn from(validated: ValidatedFixedStr<N, V>) -> Self {
+        validated.0
+    }
+}
+
+impl<const N: usize, V> FixedStrMeta for ValidatedFixedStr<N, V> {
+    const CAPACITY: usize = N;
+    const PAD_BYTE: u8 = 0;
+    const KIND: FixedStrKind = FixedStrKind::ValidatedFixedStr;
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod validated_tests {
+    use super::*;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_type_level_validator() {
+        let code = ValidatedFixedStr::<4, AsciiAlphanumeric>::new("AB12").unwrap();
+        assert_eq!(code.len(), 4);
+        assert_eq!(FixedStr::from(code).as_str(), "AB12");
+        assert_eq!(
+            ValidatedFixedStr::<4, AsciiAlphanumeric>::try_from("AB-1"),
+            Err(ValidationError::Rule(RejectedChar {
+                position: 2,
+                ch: '-'
+            }))
+        );
+        assert!(matches!(
+            ValidatedFixedStr::<2, AsciiOnly>::new("abc"),
+            Err(ValidationError::Invalid(FixedStrError::Overflow { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_from_fixed_and_closure() {
+        let fixed = FixedStr::<8>::new("naïve");
+        assert!(ValidatedFixedStr::<8, NoControl>::from_fixed(fixed).is_ok());
+        assert!(ValidatedFixedStr::<8, AsciiOnly>::from_fixed(fixed).is_err());
+        let invalid = FixedStr::<2>::from_bytes_unsafe([0xFF, 0]);
+        assert_eq!(
+            ValidatedFixedStr::<2, AsciiOnly>::from_fixed(invalid),
+            Err(ValidationError::Invalid(FixedStrError::InvalidUtf8 {
+                valid_up_to: 0
+            }))
+        );
+        let even = |s: &str| if s.len() % 2 == 0 { Ok(()) } else { Err(()) };
+        assert!(ValidatedFixedStr::<4, _>::new_with("ab", &even).is_ok());
+        assert!(ValidatedFixedStr::<4, _>::new_with("abc", &even).is_err());
+    }
+}
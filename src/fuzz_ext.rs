@@ -0,0 +1,211 @@
+// fixed_str/src/fuzz_ext.rs
+
+//******************************************************************************
+//  Arbitrary
+//******************************************************************************
+
+#[cfg(feature = "arbitrary")]
+pub use arbitrary_ext::ArbitraryRaw;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_ext {
+    use crate::*;
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    /// Generates values from arbitrary byte input without UTF‑8 validation.
+    ///
+    /// `FixedStr` and `FixedStrBuf` implement `Arbitrary` with valid UTF‑8 content only.
+    /// Wrap them in `ArbitraryRaw` to fuzz with any buffer content instead, e.g. to test
+    /// parsers against garbage read from disk.
+    ///
+    /// # Examples
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use fixed_str::{ArbitraryRaw, FixedStr};
+    ///
+    /// let mut u = Unstructured::new(b"a\xFFbc");
+    /// let ArbitraryRaw(raw) = ArbitraryRaw::<FixedStr<4>>::arbitrary(&mut u).unwrap();
+    /// assert_eq!(raw.as_bytes(), b"a\xFFbc");
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ArbitraryRaw<T>(pub T);
+
+    /// Generates a valid UTF‑8 string, truncated to `N` bytes at a character boundary.
+    impl<'a, const N: usize> Arbitrary<'a> for FixedStr<N> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(FixedStr::new(<&str>::arbitrary(u)?))
+        }
+    }
+
+    /// Generates a valid UTF‑8 builder, truncated to `N` bytes at a character boundary.
+    impl<'a, const N: usize> Arbitrary<'a> for FixedStrBuf<N> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            FixedStr::<N>::arbitrary(u).map(FixedStrBuf::from)
+        }
+    }
+
+    /// Generates any `N`‑byte buffer.
+    impl<'a, const N: usize> Arbitrary<'a> for ArbitraryRaw<FixedStr<N>> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self(FixedStr {
+                data: u.arbitrary()?,
+            }))
+        }
+
+        fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+            (N, Some(N))
+        }
+    }
+
+    /// Generates any `N`‑byte buffer, with the length set to its first null byte.
+    impl<'a, const N: usize> Arbitrary<'a> for ArbitraryRaw<FixedStrBuf<N>> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let buffer: [u8; N] = u.arbitrary()?;
+            let len = find_first_null(&buffer);
+            Ok(Self(FixedStrBuf::from_raw_parts(buffer, len)))
+        }
+
+        fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+            (N, Some(N))
+        }
+    }
+}
+
+//******************************************************************************
+//  proptest
+//******************************************************************************
+
+#[cfg(feature = "proptest")]
+pub use proptest_ext::{fixed_str_raw, fixed_str_regex};
+
+#[cfg(feature = "proptest")]
+mod proptest_ext {
+    use crate::*;
+    use proptest::prelude::*;
+    use proptest::string::{string_regex, Error};
+
+    /// Generates valid UTF‑8 strings, truncated to `N` bytes at a character boundary.
+    impl<const N: usize> Arbitrary for FixedStr<N> {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any::<String>().prop_map(|s| FixedStr::new(&s)).boxed()
+        }
+    }
+
+    /// Generates valid UTF‑8 builders, truncated to `N` bytes at a character boundary.
+    impl<const N: usize> Arbitrary for FixedStrBuf<N> {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any::<FixedStr<N>>().prop_map(FixedStrBuf::from).boxed()
+        }
+    }
+
+    /// Returns a strategy generating `FixedStr<N>` values that match the regular expression
+    /// `pattern` entirely.
+    ///
+    /// Matches longer than `N` bytes or containing a null byte are rejected rather than
+    /// truncated, so every value still matches the pattern.
+    ///
+    /// # Errors
+    /// Returns an error if `pattern` is not a valid regular expression.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{fixed_str_regex, FixedStr};
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(code in fixed_str_regex::<3>("[A-Z]{3}").unwrap())| {
+    ///     prop_assert_eq!(code.len(), 3);
+    ///     prop_assert!(code.as_str().bytes().all(|b| b.is_ascii_uppercase()));
+    /// });
+    /// ```
+    // Returns the error type of `proptest::string::string_regex` unchanged.
+    #[allow(clippy::result_large_err)]
+    pub fn fixed_str_regex<const N: usize>(
+        pattern: &str,
+    ) -> Result<impl Strategy<Value = FixedStr<N>>, Error> {
+        Ok(string_regex(pattern)?
+            .prop_filter("does not fit into a FixedStr", |s| {
+                s.len() <= N && !s.contains('\0')
+            })
+            .prop_map(|s| FixedStr::new(&s)))
+    }
+
+    /// Returns a strategy generating `FixedStr<N>` values with any buffer content,
+    /// including invalid UTF‑8.
+    pub fn fixed_str_raw<const N: usize>() -> impl Strategy<Value = FixedStr<N>> {
+        proptest::collection::vec(any::<u8>(), N).prop_map(|bytes| {
+            let mut data = [0u8; N];
+            data.copy_from_slice(&bytes);
+            FixedStr { data }
+        })
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod arbitrary_tests {
+    use crate::*;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_arbitrary_valid_utf8() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let fixed = FixedStr::<5>::arbitrary(&mut u).unwrap();
+            assert!(fixed.try_as_str().is_ok());
+            let buf = FixedStrBuf::<3>::arbitrary(&mut u).unwrap();
+            assert!(buf.len() <= 3);
+            assert!(core::str::from_utf8(buf.effective_bytes()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_raw() {
+        let mut u = Unstructured::new(b"\xFF\xFE\0xyz");
+        let ArbitraryRaw(fixed) = ArbitraryRaw::<FixedStr<3>>::arbitrary(&mut u).unwrap();
+        assert_eq!(fixed.as_bytes(), b"\xFF\xFE\0");
+        let ArbitraryRaw(buf) = ArbitraryRaw::<FixedStrBuf<3>>::arbitrary(&mut u).unwrap();
+        assert_eq!(buf.len(), 3);
+        assert_eq!(buf.effective_bytes(), b"xyz");
+    }
+}
+
+#[cfg(all(test, feature = "proptest"))]
+mod proptest_tests {
+    use crate::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_proptest_arbitrary(fixed in any::<FixedStr<6>>(), buf in any::<FixedStrBuf<4>>()) {
+            prop_assert!(fixed.try_as_str().is_ok());
+            prop_assert!(fixed.len() <= 6);
+            prop_assert!(core::str::from_utf8(buf.effective_bytes()).is_ok());
+        }
+
+        #[test]
+        fn test_proptest_regex(id in fixed_str_regex::<8>("id-[0-9]{1,5}").unwrap()) {
+            prop_assert!(id.as_str().starts_with("id-"));
+            prop_assert!(id.len() <= 8);
+        }
+
+        #[test]
+        fn test_proptest_raw(fixed in fixed_str_raw::<4>()) {
+            prop_assert_eq!(fixed.as_bytes().len(), 4);
+        }
+    }
+
+    #[test]
+    fn test_proptest_invalid_regex() {
+        assert!(fixed_str_regex::<4>("(").is_err());
+    }
+}
@@ -0,0 +1,227 @@
+// fixed_str/src/interop_ext.rs
+
+//******************************************************************************
+//  heapless
+//******************************************************************************
+
+#[cfg(feature = "heapless")]
+mod heapless_ext {
+    use crate::*;
+
+    /// Copies a `FixedStr` into a `heapless::String` of the same capacity.
+    ///
+    /// The effective string always fits, so nothing is lost.
+    ///
+    /// Returns `FixedStrError::InvalidUtf8` if the content is not valid UTF‑8.
+    impl<const N: usize> TryFrom<FixedStr<N>> for heapless::String<N> {
+        type Error = FixedStrError;
+        fn try_from(fs: FixedStr<N>) -> Result<Self, Self::Error> {
+            let mut out = heapless::String::new();
+            // Cannot fail: the effective string is at most `N` bytes.
+            let pushed = out.push_str(fs.try_as_str()?);
+            debug_assert!(pushed.is_ok());
+            Ok(out)
+        }
+    }
+
+    /// Copies the written content of a `FixedStrBuf` into a `heapless::String` of the same capacity.
+    ///
+    /// Returns `FixedStrError::InvalidUtf8` if the content is not valid UTF‑8.
+    impl<const N: usize> TryFrom<FixedStrBuf<N>> for heapless::String<N> {
+        type Error = FixedStrError;
+        fn try_from(buf: FixedStrBuf<N>) -> Result<Self, Self::Error> {
+            Self::try_from(buf.finalize_strict()?)
+        }
+    }
+
+    /// Converts a `heapless::String` without truncation.
+    ///
+    /// Returns `FixedStrError::Overflow` if the string exceeds `N` bytes. As with
+    /// [`FixedStr::set`], the content ends at the first null byte (`\0`), if any.
+    impl<const N: usize, const M: usize> TryFrom<heapless::String<M>> for FixedStr<N> {
+        type Error = FixedStrError;
+        fn try_from(s: heapless::String<M>) -> Result<Self, Self::Error> {
+            let data = copy_into_buffer(s.as_str().effective_bytes(), BufferCopyMode::Exact)?;
+            Ok(FixedStr { data })
+        }
+    }
+
+    /// Converts a `heapless::String` into a builder without truncation.
+    ///
+    /// Returns `FixedStrError::Overflow` if the string exceeds `N` bytes.
+    impl<const N: usize, const M: usize> TryFrom<heapless::String<M>> for FixedStrBuf<N> {
+        type Error = FixedStrError;
+        fn try_from(s: heapless::String<M>) -> Result<Self, Self::Error> {
+            let mut buf = FixedStrBuf::new();
+            buf.try_push_str(s.as_str())?;
+            Ok(buf)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "heapless"))]
+mod heapless_tests {
+    use crate::*;
+
+    #[test]
+    fn test_heapless_round_trip() {
+        let fixed = FixedStr::<8>::new("héllo");
+        let string = heapless::String::<8>::try_from(fixed).unwrap();
+        assert_eq!(string.as_str(), "héllo");
+        assert_eq!(FixedStr::<8>::try_from(string.clone()), Ok(fixed));
+        // A larger FixedStr accepts any string that fits.
+        assert_eq!(FixedStr::<16>::try_from(string).unwrap().as_str(), "héllo");
+
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.try_push_str("abc").unwrap();
+        let string = heapless::String::<8>::try_from(buf).unwrap();
+        assert_eq!(string.as_str(), "abc");
+        assert_eq!(
+            FixedStrBuf::<8>::try_from(string).unwrap().finalize(),
+            buf.finalize()
+        );
+    }
+
+    #[test]
+    fn test_heapless_overflow() {
+        let mut string = heapless::String::<8>::new();
+        string.push_str("too long").unwrap();
+        assert_eq!(
+            FixedStr::<4>::try_from(string.clone()),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 8
+            })
+        );
+        assert!(FixedStrBuf::<4>::try_from(string).is_err());
+    }
+
+    #[test]
+    fn test_heapless_rejects_invalid_utf8() {
+        let invalid = FixedStr::<4>::from_bytes_unsafe(*b"ab\xFF\0");
+        assert_eq!(
+            heapless::String::<4>::try_from(invalid),
+            Err(FixedStrError::InvalidUtf8 { valid_up_to: 2 })
+        );
+        let buf = FixedStrBuf::<4>::try_from(&b"ab\xFF"[..]).unwrap();
+        assert!(heapless::String::<4>::try_from(buf).is_err());
+    }
+}
+
+//******************************************************************************
+//  compact_str
+//******************************************************************************
+
+#[cfg(feature = "compact_str")]
+mod compact_str_ext {
+    use crate::*;
+    use compact_str::CompactString;
+
+    /// Copies the effective string into a `CompactString` (inline for up to 24 bytes).
+    impl<const N: usize> From<FixedStr<N>> for CompactString {
+        fn from(fs: FixedStr<N>) -> Self {
+            CompactString::from(fs.as_str())
+        }
+    }
+
+    impl<const N: usize> From<&FixedStr<N>> for CompactString {
+        fn from(fs: &FixedStr<N>) -> Self {
+            CompactString::from(fs.as_str())
+        }
+    }
+
+    /// Converts a `CompactString` without truncation.
+    ///
+    /// Returns `FixedStrError::Overflow` if the string exceeds `N` bytes.
+    impl<const N: usize> TryFrom<&CompactString> for FixedStr<N> {
+        type Error = FixedStrError;
+        fn try_from(s: &CompactString) -> Result<Self, Self::Error> {
+            let data = copy_into_buffer(s.as_str().effective_bytes(), BufferCopyMode::Exact)?;
+            Ok(FixedStr { data })
+        }
+    }
+
+    impl<const N: usize> TryFrom<CompactString> for FixedStr<N> {
+        type Error = FixedStrError;
+        fn try_from(s: CompactString) -> Result<Self, Self::Error> {
+            FixedStr::try_from(&s)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "compact_str"))]
+mod compact_str_tests {
+    use crate::*;
+    use compact_str::CompactString;
+
+    #[test]
+    fn test_compact_str_conversions() {
+        let fixed = FixedStr::<8>::new("héllo");
+        let compact = CompactString::from(&fixed);
+        assert_eq!(compact.as_str(), "héllo");
+        assert_eq!(FixedStr::<8>::try_from(compact), Ok(fixed));
+        assert!(FixedStr::<4>::try_from(CompactString::from("too long")).is_err());
+    }
+}
+
+//******************************************************************************
+//  smol_str
+//******************************************************************************
+
+#[cfg(feature = "smol_str")]
+mod smol_str_ext {
+    use crate::*;
+    use smol_str::SmolStr;
+
+    /// Copies the effective string into a `SmolStr` (inline for up to 23 bytes).
+    impl<const N: usize> From<FixedStr<N>> for SmolStr {
+        fn from(fs: FixedStr<N>) -> Self {
+            SmolStr::new(fs.as_str())
+        }
+    }
+
+    impl<const N: usize> From<&FixedStr<N>> for SmolStr {
+        fn from(fs: &FixedStr<N>) -> Self {
+            SmolStr::new(fs.as_str())
+        }
+    }
+
+    /// Converts a `SmolStr` without truncation.
+    ///
+    /// Returns `FixedStrError::Overflow` if the string exceeds `N` bytes.
+    impl<const N: usize> TryFrom<&SmolStr> for FixedStr<N> {
+        type Error = FixedStrError;
+        fn try_from(s: &SmolStr) -> Result<Self, Self::Error> {
+            let data = copy_into_buffer(s.as_str().effective_bytes(), BufferCopyMode::Exact)?;
+            Ok(FixedStr { data })
+        }
+    }
+
+    impl<const N: usize> TryFrom<SmolStr> for FixedStr<N> {
+        type Error = FixedStrError;
+        fn try_from(s: SmolStr) -> Result<Self, Self::Error> {
+            FixedStr::try_from(&s)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "smol_str"))]
+mod smol_str_tests {
+    use crate::*;
+    use smol_str::SmolStr;
+
+    #[test]
+    fn test_smol_str_conversions() {
+        let fixed = FixedStr::<8>::new("héllo");
+        let smol = SmolStr::from(fixed);
+        assert_eq!(smol.as_str(), "héllo");
+        assert_eq!(FixedStr::<8>::try_from(&smol), Ok(fixed));
+        assert_eq!(
+            FixedStr::<4>::try_from(SmolStr::new("too long")),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 8
+            })
+        );
+    }
+}
@@ -0,0 +1,82 @@
+// fixed_str/src/layout_ext.rs
+
+//******************************************************************************
+//  zerocopy
+//******************************************************************************
+
+// `FixedStr` derives `FromBytes`, `IntoBytes`, `Unaligned`, `KnownLayout` and `Immutable`
+// (see `fs_core.rs`), as zerocopy only supports derived implementations. Any byte pattern
+// is accepted, so values read this way may hold invalid UTF‑8; use `FixedStr::try_as_str`
+// to check them.
+
+//******************************************************************************
+//  bytemuck
+//******************************************************************************
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_ext {
+    use crate::*;
+    use bytemuck::{Pod, Zeroable};
+
+    /// The all‑zero `FixedStr` is the empty string.
+    // SAFETY: `FixedStr<N>` is a `repr(transparent)` wrapper around `[u8; N]`.
+    unsafe impl<const N: usize> Zeroable for FixedStr<N> {}
+
+    /// Any byte pattern is a valid `FixedStr`, which may hold invalid UTF‑8.
+    // SAFETY: `FixedStr<N>` is a `repr(transparent)` wrapper around `[u8; N]`, so it has
+    // no padding, no invalid bit patterns and is `Copy + 'static`.
+    unsafe impl<const N: usize> Pod for FixedStr<N> {}
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(all(test, feature = "bytemuck"))]
+mod bytemuck_tests {
+    use crate::*;
+
+    #[test]
+    fn test_bytemuck_cast_slice() {
+        let names = [FixedStr::<4>::new("ab"), FixedStr::new("cdef")];
+        let bytes: &[u8] = bytemuck::cast_slice(&names);
+        assert_eq!(bytes, b"ab\0\0cdef");
+
+        let back: &[FixedStr<4>] = bytemuck::cast_slice(b"wxyz12\0\0");
+        assert_eq!(back, [FixedStr::new("wxyz"), FixedStr::new("12")]);
+        assert_eq!(<FixedStr<8> as bytemuck::Zeroable>::zeroed(), "");
+    }
+}
+
+#[cfg(all(test, feature = "zerocopy"))]
+mod zerocopy_tests {
+    use crate::*;
+    use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+    #[derive(FromBytes, IntoBytes, Unaligned, KnownLayout, Immutable)]
+    #[repr(C)]
+    struct Packet {
+        kind: u8,
+        name: FixedStr<7>,
+    }
+
+    #[test]
+    fn test_zerocopy_packet() {
+        let bytes = *b"\x01abc\0\0\0\0";
+        let packet = Packet::ref_from_bytes(&bytes).unwrap();
+        assert_eq!(packet.kind, 1);
+        assert_eq!(packet.name, "abc");
+        assert_eq!(packet.as_bytes(), &bytes);
+    }
+
+    #[test]
+    fn test_zerocopy_slice() {
+        let bytes = b"ab\0\0cdef";
+        let names = <[FixedStr<4>]>::ref_from_bytes(bytes).unwrap();
+        assert_eq!(names, [FixedStr::new("ab"), FixedStr::new("cdef")]);
+
+        let fixed = FixedStr::<4>::read_from_bytes(b"\xFF\xFF\xFF\xFF").unwrap();
+        assert!(fixed.is_sentinel());
+        assert!(FixedStr::<4>::ref_from_bytes(b"abc").is_err());
+    }
+}
@@ -20,7 +20,7 @@
 //!
 //! Also included:
 //! - [`FixedStrBuf<N>`]: A builder for incrementally constructing `FixedStr` values with boundary-aware methods such as `try_push_str()` and `push_str_lossy()`.
-//! - Optional integrations for `serde`, `binrw`, and support for `no_std` environments.
+//! - Optional integrations for `serde`, `binrw`, `ufmt`, `zeroize`, and support for `no_std` environments.
 
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -33,32 +33,173 @@ use core::{
     str,
 };
 
-#[cfg(feature = "std")]
-use std::string::String;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-#[cfg(feature = "std")]
-use std::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(all(feature = "alloc", feature = "cmp_foreign"))]
+use alloc::vec::Vec;
 
 /// Exposes the effective (non‑zero) bytes of a `FixedStr`.
 pub mod effective_bytes;
+/// Provides optional integrations for alternative formatting frameworks (`ufmt`).
+pub mod fmt_ext;
+/// Provides `FixedStrArena` and `FixedStrArrayArena` for contiguous bulk storage.
+pub mod fs_arena;
+/// Provides the ASCII‑only `AsciiFixedStr`.
+pub mod fs_ascii;
 /// Provides the builder type `FixedStrBuf` for constructing fixed‑capacity strings.
 pub mod fs_buffer;
+/// Provides the non‑panicking `checked_*` constructors for `FixedStr`.
+pub mod fs_checked;
+/// Provides helpers that pack or split strings into fixed‑capacity chunks.
+pub mod fs_chunks;
+/// Provides single‑byte code page conversions (Latin‑1, CP437).
+pub mod fs_codepage;
+/// Provides the `Collated` wrapper for human‑sensible ordering (requires the `unicode_normalization` feature).
+#[cfg(feature = "unicode_normalization")]
+pub mod fs_collated;
+/// Provides `ColumnLayout` for splitting fixed‑width text lines into `FixedStr` fields.
+pub mod fs_columns;
 /// Contains the core implementation of the `FixedStr` type.
 pub mod fs_core;
+/// Provides the always NUL‑terminated `FixedCStr` for FFI.
+pub mod fs_cstr;
+/// Provides `impl_debug_fixed_fields!` for annotated struct `Debug` output (requires the `debug_fields` feature).
+#[cfg(feature = "debug_fields")]
+pub mod fs_debug;
+/// Provides zero‑allocation display adapters for `FixedStr`.
+pub mod fs_display;
 /// Defines custom error types for the `FixedStr` library.
 pub mod fs_error;
+/// Provides reversible escaping of newline and backslash (`escape_into`/`unescape_into`).
+pub mod fs_escape;
 /// Implements various trait implementations for `FixedStr`.
 pub mod fs_impl;
+/// Provides `FixedStrInterner` for deduplicating `FixedStr` values into symbols (requires the `alloc` feature).
+#[cfg(feature = "alloc")]
+pub mod fs_interner;
+/// Provides the write‑once `LatchedFixedStr` for statics.
+///
+/// Only available on targets with atomic compare‑and‑swap on bytes.
+#[cfg(target_has_atomic = "8")]
+pub mod fs_latched;
+/// Provides the length‑prefixed sibling type `FixedStrLen`.
+pub mod fs_len;
+/// Provides `PrefixMatcher` for matching a `FixedStr` against streamed input, and glob matching.
+pub mod fs_matcher;
+/// Provides the `FixedStrMeta` trait for compile‑time layout introspection.
+pub mod fs_meta;
+/// Provides the `OrdFull` wrapper for a strict total order including padding and capacity, and `NaturalOrd`.
+pub mod fs_ord;
+/// Provides `PaddedStr` with a configurable pad byte (`SpacePadded`, `FlashPadded`).
+pub mod fs_padded;
+/// Provides the `SecretFixedStr` wrapper for credentials with redacted formatting.
+pub mod fs_secret;
+/// Provides `SmallFixedStr`, which spills to the heap instead of truncating (requires the `alloc` feature).
+#[cfg(feature = "alloc")]
+pub mod fs_small;
+/// Provides `SymbolStr`, a validated `FixedStr` interned process‑wide (requires the `std` feature).
+#[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+pub mod fs_symbol;
+/// Provides `FixedTable` for const-initialized keyword lookup.
+pub mod fs_table;
+/// Provides the self‑describing tagged encoding (`encode_tagged`/`decode_tagged`).
+pub mod fs_tagged;
+/// Provides the UTF‑16 sibling type `FixedUtf16Str`.
+pub mod fs_utf16;
+/// Provides `ValidatedFixedStr`, a `FixedStr` carrying a type‑level validator.
+pub mod fs_validated;
+/// Provides optional integrations for fuzzing and property testing (`arbitrary`, `proptest`).
+pub mod fuzz_ext;
+/// Provides optional conversions to and from other small‑string types (`heapless`, `compact_str`, `smol_str`).
+pub mod interop_ext;
+/// Provides optional integrations for byte‑level casting (`zerocopy`, `bytemuck`).
+pub mod layout_ext;
+/// Provides a hook for observing lossy truncation (requires the `debug_lossy` feature).
+#[cfg(feature = "debug_lossy")]
+pub mod lossy_hook;
+/// Provides `FixedRecord` for parsing and emitting fixed‑width multi‑field records.
+pub mod record;
+/// Provides optional integrations for securely wiping memory (`zeroize`).
+pub mod secure_ext;
 /// Provides optional integrations for binary and serialization support (`binrw` and `serde`).
 pub mod serialize_ext;
+/// Provides optional integrations for SQL databases (`rusqlite`, `postgres-types`).
+pub mod sql_ext;
 /// Contains helper functions for byte copying, UTF‑8 boundary detection, and hex formatting.
 pub mod string_helpers;
+/// Provides `TruncationPolicy` and the optional crate‑wide policy hook (`truncation_policy` feature).
+pub mod truncation_policy;
+/// Provides optional Unicode normalization and Unicode‑aware truncation
+/// (`unicode-normalization`, `unicode-segmentation`, `unicode-width`).
+pub mod unicode_ext;
+/// Provides the `Validator` trait for checking domain rules at construction time.
+pub mod validator;
 
 pub use effective_bytes::{EffectiveBytes, EffectiveBytesIter};
-pub use fs_buffer::FixedStrBuf;
-pub use fs_core::FixedStr;
-pub use fs_error::FixedStrError;
+#[cfg(feature = "derive")]
+pub use fixed_str_derive::FixedRecord;
+#[cfg(feature = "alloc")]
+pub use fs_arena::FixedStrArena;
+pub use fs_arena::FixedStrArrayArena;
+pub use fs_ascii::AsciiFixedStr;
+pub use fs_buffer::{FixedStrBuf, PushOutcome};
+pub use fs_chunks::{collect_fixed_chunks, fill_slice_from_str, FixedChunks};
+#[cfg(feature = "unicode_normalization")]
+pub use fs_collated::Collated;
+#[cfg(feature = "std")]
+pub use fs_columns::ColumnLines;
+pub use fs_columns::{Column, ColumnLayout, Trim};
+pub use fs_core::{FixedStr, TruncationInfo};
+pub use fs_cstr::FixedCStr;
+pub use fs_display::{DisplayLowercase, DisplayUppercase, NoAllocDisplay};
+pub use fs_error::{FixedStrError, RecordError, RejectedChar, TaggedError, ValidationError};
+#[cfg(feature = "alloc")]
+pub use fs_interner::{FixedStrInterner, Symbol};
+#[cfg(target_has_atomic = "8")]
+pub use fs_latched::LatchedFixedStr;
+pub use fs_len::FixedStrLen;
+pub use fs_matcher::{MatchState, PrefixMatcher};
+pub use fs_meta::{FixedStrKind, FixedStrMeta};
+pub use fs_ord::{NaturalOrd, OrdFull};
+pub use fs_padded::{FlashPadded, PaddedStr, SpacePadded};
+pub use fs_secret::SecretFixedStr;
+#[cfg(feature = "alloc")]
+pub use fs_small::SmallFixedStr;
+#[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+pub use fs_symbol::SymbolStr;
+pub use fs_table::FixedTable;
+pub use fs_tagged::{TAGGED_HEADER_LEN, TAGGED_VERSION};
+pub use fs_utf16::FixedUtf16Str;
+pub use fs_validated::ValidatedFixedStr;
+#[cfg(feature = "arbitrary")]
+pub use fuzz_ext::ArbitraryRaw;
+#[cfg(feature = "proptest")]
+pub use fuzz_ext::{fixed_str_raw, fixed_str_regex};
+pub use record::{emit_record, parse_record, FieldSpec, FixedRecord, RecordField};
+#[cfg(target_has_atomic = "ptr")]
+pub use string_helpers::set_default_hex_style;
 pub use string_helpers::{
-    copy_into_buffer, dump_as_hex, fast_format_hex, find_first_null, find_valid_boundary,
-    find_valid_utf8_len, panic_on_zero, truncate_utf8_lossy, BufferCopyMode,
+    ascii_case_fold_table, convert_padding_table, copy_into_buffer, default_hex_style, dump_as_hex,
+    dump_as_hex_styled, fast_format_hex, fast_format_hex_styled, find_first_null,
+    find_first_null_const, find_valid_boundary, find_valid_utf8_len, hexdump, hexdump_to,
+    last_non_pad, panic_on_zero, parse_hex, rfind_byte, truncate_utf8_lossy, BufferCopyMode,
+    HexStyle,
+};
+#[cfg(all(
+    feature = "truncation_policy",
+    target_has_atomic = "8",
+    target_has_atomic = "ptr"
+))]
+pub use truncation_policy::set_truncation_policy;
+#[cfg(feature = "truncation_policy")]
+pub use truncation_policy::truncation_policy;
+pub use truncation_policy::{
+    EllipsisPolicy, ErrorPolicy, SilentPolicy, TruncationPolicy, TruncationPolicyParam,
 };
+pub use validator::{AsciiAlphanumeric, AsciiOnly, NoControl, PrintableAscii, Validator};
+
+use string_helpers::{copy_into_array, head, require_capacity, write_at, zero_from};
@@ -0,0 +1,124 @@
+// fixed_str/src/lossy_hook.rs
+
+use core::panic::Location;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// Describes a single lossy operation that dropped data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LossyEvent {
+    /// The capacity of the destination in bytes.
+    pub capacity: usize,
+    /// The effective length of the input in bytes.
+    pub input_len: usize,
+    /// The number of bytes that were kept.
+    pub kept: usize,
+    /// The location of the call into the lossy API.
+    pub location: &'static Location<'static>,
+}
+
+/// The installed hook, stored as a type‑erased `fn(&LossyEvent)` pointer.
+static HOOK: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Installs a hook that is invoked whenever a lossy API truncates data.
+///
+/// Lossy APIs include [`FixedStr::new`](crate::FixedStr::new), `from_slice`, `from_bytes`,
+/// `set_lossy`, the `From` conversions and [`FixedStrBuf::push_str_lossy`](crate::FixedStrBuf::push_str_lossy).
+/// Replaces any previously installed hook.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{lossy_hook, FixedStr};
+///
+/// fn report(event: &lossy_hook::LossyEvent) {
+///     eprintln!(
+///         "{}: kept {} of {} bytes (capacity {})",
+///         event.location, event.kept, event.input_len, event.capacity
+///     );
+/// }
+///
+/// lossy_hook::set_lossy_hook(report);
+/// let _ = FixedStr::<4>::new("truncated");
+/// ```
+pub fn set_lossy_hook(hook: fn(&LossyEvent)) {
+    HOOK.store(hook as *mut (), Ordering::Release);
+}
+
+/// Removes the installed hook, if any.
+pub fn clear_lossy_hook() {
+    HOOK.store(core::ptr::null_mut(), Ordering::Release);
+}
+
+/// Invokes the installed hook if `kept` is less than `input_len`.
+#[track_caller]
+pub(crate) fn report_if_lossy(capacity: usize, input_len: usize, kept: usize) {
+    if kept >= input_len {
+        return;
+    }
+    let ptr = HOOK.load(Ordering::Acquire);
+    if ptr.is_null() {
+        return;
+    }
+    // SAFETY: Every non-null value stored in `HOOK` was cast from a `fn(&LossyEvent)`.
+    let hook: fn(&LossyEvent) = unsafe { core::mem::transmute(ptr) };
+    hook(&LossyEvent {
+        capacity,
+        input_len,
+        kept,
+        location: Location::caller(),
+    });
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(all(test, feature = "std"))]
+mod lossy_hook_tests {
+    use super::*;
+    use crate::*;
+    use std::cell::RefCell;
+    use std::vec::Vec;
+
+    std::thread_local! {
+        static EVENTS: RefCell<Vec<LossyEvent>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(event: &LossyEvent) {
+        EVENTS.with(|events| events.borrow_mut().push(*event));
+    }
+
+    fn take_events() -> Vec<LossyEvent> {
+        EVENTS.with(|events| events.borrow_mut().drain(..).collect())
+    }
+
+    #[test]
+    fn test_lossy_hook_reports_truncation() {
+        set_lossy_hook(record);
+
+        let _ = FixedStr::<4>::new("fits");
+        let mut buf = FixedStrBuf::<4>::new();
+        let _ = buf.push_str_lossy("ok");
+        assert!(take_events().is_empty());
+
+        let line = line!() + 1;
+        let _ = FixedStr::<4>::new("too long");
+        let events = take_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].capacity, 4);
+        assert_eq!(events[0].input_len, 8);
+        assert_eq!(events[0].kept, 4);
+        assert_eq!(events[0].location.file(), file!());
+        assert_eq!(events[0].location.line(), line);
+
+        let _ = buf.push_str_lossy("abc");
+        let events = take_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!((events[0].input_len, events[0].kept), (3, 2));
+        assert_eq!(events[0].location.file(), file!());
+
+        // Overflowing appends hand back everything that does not fit, so nothing is lost.
+        let mut first = FixedStrBuf::<2>::new();
+        let _ = first.append_overflowing("xyzwv");
+        assert!(take_events().is_empty());
+    }
+}
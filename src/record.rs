@@ -0,0 +1,313 @@
+// fixed_str/src/record.rs
+
+use super::*;
+use core::marker::PhantomData;
+
+/// A type that can be stored as one field of a fixed‑width record.
+///
+/// The field occupies exactly `WIDTH` bytes. For fixed string types, unused capacity is
+/// filled with `PAD_BYTE`, so the padding policy of a field is that of its type: [`FixedStr`]
+/// pads with null bytes, [`SpacePadded`] with spaces. Integers are stored big‑endian.
+pub trait RecordField: Sized {
+    /// The width of the field in bytes.
+    const WIDTH: usize;
+    /// The byte used to fill unused capacity.
+    const PAD_BYTE: u8;
+
+    /// Parses the field from its bytes. Fixed string types treat shorter input as padded.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if `bytes` exceeds the width (or, for integers, has
+    /// a different length), or `FixedStrError::InvalidUtf8` if the content is not valid UTF‑8.
+    fn read_field(bytes: &[u8]) -> Result<Self, FixedStrError>;
+
+    /// Writes the field, including its padding, to the first `WIDTH` bytes of `out`.
+    ///
+    /// # Panics
+    /// Panics if `out` is shorter than the width.
+    fn write_field(&self, out: &mut [u8]);
+}
+
+impl<const N: usize> RecordField for FixedStr<N> {
+    const WIDTH: usize = N;
+    const PAD_BYTE: u8 = 0;
+
+    fn read_field(bytes: &[u8]) -> Result<Self, FixedStrError> {
+        Self::try_from(bytes)
+    }
+
+    fn write_field(&self, out: &mut [u8]) {
+        out[..N].copy_from_slice(&self.data);
+    }
+}
+
+impl<const N: usize, const PAD: u8> RecordField for PaddedStr<N, PAD> {
+    const WIDTH: usize = N;
+    const PAD_BYTE: u8 = PAD;
+
+    fn read_field(bytes: &[u8]) -> Result<Self, FixedStrError> {
+        if bytes.len() > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: bytes.len(),
+            });
+        }
+        let mut data = [PAD; N];
+        write_at(&mut data, 0, bytes);
+        Self::from_padded_bytes(data)
+    }
+
+    fn write_field(&self, out: &mut [u8]) {
+        out[..N].copy_from_slice(self.as_padded_bytes());
+    }
+}
+
+/// Implements `RecordField` for big‑endian integers.
+macro_rules! impl_int_field {
+    ($($int:ty),+) => {$(
+        impl RecordField for $int {
+            const WIDTH: usize = core::mem::size_of::<$int>();
+            const PAD_BYTE: u8 = 0;
+
+            fn read_field(bytes: &[u8]) -> Result<Self, FixedStrError> {
+                let bytes = bytes.try_into().map_err(|_| FixedStrError::Overflow {
+                    available: Self::WIDTH,
+                    found: bytes.len(),
+                })?;
+                Ok(<$int>::from_be_bytes(bytes))
+            }
+
+            fn write_field(&self, out: &mut [u8]) {
+                out[..Self::WIDTH].copy_from_slice(&self.to_be_bytes());
+            }
+        }
+    )+};
+}
+
+impl_int_field!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+/// Describes one field of a [`FixedRecord`] layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldSpec {
+    /// The byte offset of the field within the record.
+    pub offset: usize,
+    /// The width of the field in bytes.
+    pub width: usize,
+    /// The byte used to pad the field.
+    pub pad: u8,
+}
+
+/// A record made of consecutive fixed‑width fields.
+///
+/// Implemented for tuples of up to eight [`RecordField`] types, where the fields follow each
+/// other without gaps in tuple order, and derivable for structs with the `derive` feature.
+/// [`parse_record`] and [`emit_record`] convert between a record and a `[u8; TOTAL]` buffer
+/// with the width checked at compile time.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{emit_record, parse_record, FixedRecord, FixedStr, SpacePadded};
+///
+/// type Account = (SpacePadded<6>, FixedStr<4>, SpacePadded<3>);
+///
+/// let (name, id, currency): Account = parse_record(b"SMITH 0042EUR").unwrap();
+/// assert_eq!((name.as_str(), id.as_str(), currency.as_str()), ("SMITH", "0042", "EUR"));
+/// assert_eq!(Account::FIELDS[2].offset, 10);
+///
+/// let record: Account = (SpacePadded::new("DOE"), FixedStr::new("7"), SpacePadded::new("USD"));
+/// assert_eq!(&emit_record::<_, 13>(&record), b"DOE   7\0\0\0USD");
+/// ```
+pub trait FixedRecord: Sized {
+    /// The total width of the record in bytes.
+    const WIDTH: usize;
+    /// The layout of the fields, in order.
+    const FIELDS: &'static [FieldSpec];
+
+    /// Parses the record from a buffer of exactly `WIDTH` bytes.
+    ///
+    /// # Errors
+    /// Returns `RecordError::WrongLength` if the buffer has a different length, or
+    /// `RecordError::Field` for the first field that cannot be parsed.
+    fn parse_slice(bytes: &[u8]) -> Result<Self, RecordError>;
+
+    /// Writes the record to a buffer of exactly `WIDTH` bytes.
+    ///
+    /// # Errors
+    /// Returns `RecordError::WrongLength` if the buffer has a different length.
+    fn emit_into(&self, out: &mut [u8]) -> Result<(), RecordError>;
+}
+
+/// Parses a record from a buffer whose length is checked against the layout at compile time.
+///
+/// # Errors
+/// Returns `RecordError::Field` for the first field that cannot be parsed.
+///
+/// # Panics
+/// Fails to compile if `TOTAL` differs from `R::WIDTH`.
+pub fn parse_record<R: FixedRecord, const TOTAL: usize>(
+    bytes: &[u8; TOTAL],
+) -> Result<R, RecordError> {
+    #[allow(clippy::let_unit_value)]
+    let () = RecordWidth::<R, TOTAL>::MATCHES;
+    R::parse_slice(bytes)
+}
+
+/// Writes a record to a buffer whose length is checked against the layout at compile time.
+///
+/// # Panics
+/// Fails to compile if `TOTAL` differs from `R::WIDTH`.
+pub fn emit_record<R: FixedRecord, const TOTAL: usize>(record: &R) -> [u8; TOTAL] {
+    #[allow(clippy::let_unit_value)]
+    let () = RecordWidth::<R, TOTAL>::MATCHES;
+    let mut out = [0u8; TOTAL];
+    // The length matches, which is the only error `emit_into` reports.
+    let _ = record.emit_into(&mut out);
+    out
+}
+
+/// Compile‑time width check for [`parse_record`] and [`emit_record`].
+struct RecordWidth<R, const TOTAL: usize>(PhantomData<R>);
+
+impl<R: FixedRecord, const TOTAL: usize> RecordWidth<R, TOTAL> {
+    const MATCHES: () = assert!(
+        R::WIDTH == TOTAL,
+        "record buffer length does not match the record width"
+    );
+}
+
+/// Fills in the offsets of consecutive fields.
+const fn layout_fields<const K: usize>(fields: [FieldSpec; K]) -> [FieldSpec; K] {
+    let mut fields = fields;
+    let mut offset = 0;
+    let mut i = 0;
+    while i < K {
+        fields[i].offset = offset;
+        offset += fields[i].width;
+        i += 1;
+    }
+    fields
+}
+
+/// Implements `FixedRecord` for a tuple of record fields.
+macro_rules! impl_tuple_record {
+    ($($field:ident $index:tt),+) => {
+        impl<$($field: RecordField),+> FixedRecord for ($($field,)+) {
+            const WIDTH: usize = 0 $(+ $field::WIDTH)+;
+            const FIELDS: &'static [FieldSpec] = &layout_fields([$(FieldSpec {
+                offset: 0,
+                width: $field::WIDTH,
+                pad: $field::PAD_BYTE,
+            }),+]);
+
+            fn parse_slice(bytes: &[u8]) -> Result<Self, RecordError> {
+                check_width(Self::WIDTH, bytes.len())?;
+                Ok(($({
+                    let spec = Self::FIELDS[$index];
+                    let field = &bytes[spec.offset..spec.offset + spec.width];
+                    $field::read_field(field).map_err(|error| RecordError::Field {
+                        index: $index,
+                        offset: spec.offset,
+                        error,
+                    })?
+                },)+))
+            }
+
+            fn emit_into(&self, out: &mut [u8]) -> Result<(), RecordError> {
+                check_width(Self::WIDTH, out.len())?;
+                $(self.$index.write_field(&mut out[Self::FIELDS[$index].offset..]);)+
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Checks that a buffer has the width of the record.
+fn check_width(expected: usize, found: usize) -> Result<(), RecordError> {
+    if expected != found {
+        return Err(RecordError::WrongLength { expected, found });
+    }
+    Ok(())
+}
+
+impl_tuple_record!(A 0);
+impl_tuple_record!(A 0, B 1);
+impl_tuple_record!(A 0, B 1, C 2);
+impl_tuple_record!(A 0, B 1, C 2, D 3);
+impl_tuple_record!(A 0, B 1, C 2, D 3, E 4);
+impl_tuple_record!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_tuple_record!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_tuple_record!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod record_tests {
+    use super::*;
+
+    type Header = (FixedStr<4>, SpacePadded<3>);
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(Header::WIDTH, 7);
+        assert_eq!(
+            Header::FIELDS,
+            [
+                FieldSpec {
+                    offset: 0,
+                    width: 4,
+                    pad: 0,
+                },
+                FieldSpec {
+                    offset: 4,
+                    width: 3,
+                    pad: b' ',
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let (magic, version): Header = parse_record(b"FS\0\0v1 ").unwrap();
+        assert_eq!((magic.as_str(), version.as_str()), ("FS", "v1"));
+        assert_eq!(&emit_record::<_, 7>(&(magic, version)), b"FS\0\0v1 ");
+    }
+
+    #[test]
+    fn test_int_fields() {
+        type Entry = (u16, FixedStr<2>, i32);
+        let entry: Entry = parse_record(b"\x01\x02ok\xFF\xFF\xFF\xFE").unwrap();
+        assert_eq!((entry.0, entry.1.as_str(), entry.2), (0x0102, "ok", -2));
+        assert_eq!(&emit_record::<_, 8>(&entry), b"\x01\x02ok\xFF\xFF\xFF\xFE");
+        assert_eq!(
+            u16::read_field(b"\x01"),
+            Err(FixedStrError::Overflow {
+                available: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Header::parse_slice(b"FSv1"),
+            Err(RecordError::WrongLength {
+                expected: 7,
+                found: 4
+            })
+        );
+        assert_eq!(
+            parse_record::<Header, 7>(b"FSv1\xFF  "),
+            Err(RecordError::Field {
+                index: 1,
+                offset: 4,
+                error: FixedStrError::InvalidUtf8 { valid_up_to: 0 },
+            })
+        );
+        let header: Header = (FixedStr::new("FS"), SpacePadded::new("v1"));
+        assert!(header.emit_into(&mut [0u8; 8]).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+// fixed_str/src/secure_ext.rs
+
+//******************************************************************************
+//  Zeroize
+//******************************************************************************
+
+// `FixedStr` and `FixedStrBuf` are `Copy`, so they cannot wipe themselves on drop and do not
+// implement `ZeroizeOnDrop`. Wrap them in `zeroize::Zeroizing` to wipe a value when it goes
+// out of scope, keeping in mind that any copies made before that are not affected.
+#[cfg(feature = "zeroize")]
+mod zeroize_ext {
+    use crate::*;
+    use zeroize::{Zeroize, ZeroizeOnDrop};
+
+    /// Securely wipes the entire buffer of a `FixedStr`, including the padding.
+    impl<const N: usize> Zeroize for FixedStr<N> {
+        fn zeroize(&mut self) {
+            self.data.zeroize();
+        }
+    }
+
+    /// Securely wipes the entire buffer of a `FixedStrBuf` and resets it to an empty builder.
+    impl<const N: usize> Zeroize for FixedStrBuf<N> {
+        fn zeroize(&mut self) {
+            self.buffer.zeroize();
+            self.len.zeroize();
+            self.utf8 = true;
+        }
+    }
+
+    /// Securely wipes the secret content of a `SecretFixedStr`.
+    impl<const N: usize> Zeroize for SecretFixedStr<N> {
+        fn zeroize(&mut self) {
+            self.0.zeroize();
+        }
+    }
+
+    /// Wipes the secret content when a `SecretFixedStr` is dropped.
+    impl<const N: usize> Drop for SecretFixedStr<N> {
+        fn drop(&mut self) {
+            self.zeroize();
+        }
+    }
+
+    impl<const N: usize> ZeroizeOnDrop for SecretFixedStr<N> {}
+}
+
+// --- Tests for zeroize integration ---
+#[cfg(all(test, feature = "zeroize"))]
+mod zeroize_tests {
+    use crate::*;
+    use zeroize::{Zeroize, Zeroizing};
+
+    #[test]
+    fn test_zeroize_fixed_str() {
+        let mut fixed = FixedStr::<8>::from_bytes_unsafe(*b"secret\0X");
+        fixed.zeroize();
+        assert_eq!(fixed.as_bytes(), &[0u8; 8]);
+    }
+
+    #[test]
+    fn test_zeroize_buffer() {
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.try_push_str("token").unwrap();
+        buf.zeroize();
+        assert!(buf.is_empty());
+        assert_eq!(&buf[..], &[0u8; 8]);
+    }
+
+    #[test]
+    fn test_zeroize_buffer_resets_utf8_flag() {
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.push_bytes_unchecked(&[0xFF, b'a']).unwrap();
+        assert!(!buf.is_utf8_guaranteed());
+        buf.zeroize();
+        assert!(buf.is_utf8_guaranteed());
+    }
+
+    #[test]
+    fn test_zeroize_secret() {
+        let mut secret = SecretFixedStr::<8>::new("hunter2");
+        secret.zeroize();
+        assert!(secret.expose().is_empty());
+    }
+
+    #[test]
+    fn test_zeroizing_wrapper() {
+        let secret = Zeroizing::new(FixedStr::<8>::new("hunter2"));
+        assert_eq!(*secret, "hunter2");
+    }
+}
@@ -4,43 +4,214 @@
 //  BinRW Serialization
 //******************************************************************************
 
+#[cfg(feature = "binrw")]
+pub use binrw_ext::FixedStrBinArgs;
+
 #[cfg(feature = "binrw")]
 mod binrw_ext {
     use crate::*;
     use binrw::io::{Read, Seek, Write};
-    use binrw::{BinRead, BinWrite};
+    use binrw::{BinRead, BinWrite, NamedArgs};
+
+    /// Arguments controlling how `FixedStr` is read and written with binrw.
+    ///
+    /// Many on‑disk formats (tar, FAT, ISO 9660) pad fixed fields with spaces or `0xFF`
+    /// instead of NULs. Pass these with `#[br(args { pad: b' ', trim: true })]` and
+    /// `#[bw(args { pad: b' ' })]`.
+    #[derive(NamedArgs, Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct FixedStrBinArgs {
+        /// The byte used to fill the unused part of the field on write (default `0x00`).
+        #[named_args(default = 0)]
+        pub pad: u8,
+        /// Whether trailing `pad` bytes are replaced by NULs on read (default `false`).
+        #[named_args(default = false)]
+        pub trim: bool,
+        /// Whether the field is a variable‑length, NUL‑terminated string of at most `N`
+        /// bytes rather than a fixed `N`‑byte field (default `false`).
+        #[named_args(default = false)]
+        pub null_terminated: bool,
+        /// Whether reading fails with a `binrw::Error::Custom` holding
+        /// `FixedStrError::InvalidUtf8` if the effective bytes are not valid UTF‑8
+        /// (default `false`).
+        #[named_args(default = false)]
+        pub validate: bool,
+    }
 
     /// Implements binary reading for `FixedStr` using the binrw crate.
+    ///
+    /// Reads exactly `N` bytes. If `trim` is set, trailing `pad` bytes are stripped so
+    /// that they are not part of the effective string.
+    ///
+    /// If `null_terminated` is set, bytes are read up to and including the first NUL
+    /// instead, stopping after `N` bytes if no NUL is found.
+    ///
+    /// If `validate` is set, invalid UTF‑8 is rejected instead of being stored as‑is.
     impl<const N: usize> BinRead for FixedStr<N> {
-        type Args<'a> = ();
+        type Args<'a> = FixedStrBinArgs;
 
         fn read_options<R: Read + Seek>(
             reader: &mut R,
             _endian: binrw::Endian,
-            _args: Self::Args<'_>,
+            args: Self::Args<'_>,
         ) -> binrw::BinResult<Self> {
+            let pos = reader.stream_position()?;
             let mut buf = [0u8; N];
-            reader.read_exact(&mut buf)?;
-            Ok(Self { data: buf })
+            if args.null_terminated {
+                for i in 0..N {
+                    reader.read_exact(&mut buf[i..i + 1])?;
+                    if buf[i] == 0 {
+                        break;
+                    }
+                }
+            } else {
+                reader.read_exact(&mut buf)?;
+            }
+            if args.trim {
+                let end = buf
+                    .iter()
+                    .rposition(|&b| b != args.pad)
+                    .map_or(0, |i| i + 1);
+                buf[end..].fill(0);
+            }
+            let fixed = Self { data: buf };
+            if args.validate {
+                if let Err(err) = fixed.try_as_str() {
+                    return Err(binrw::Error::Custom {
+                        pos,
+                        err: alloc::boxed::Box::new(err),
+                    });
+                }
+            }
+            Ok(fixed)
         }
     }
 
     /// Implements binary writing for `FixedStr` using the binrw crate.
+    ///
+    /// With the default pad byte `0x00`, the buffer is written as‑is. Otherwise the
+    /// effective bytes are written, followed by `pad` bytes up to `N`.
+    ///
+    /// If `null_terminated` is set, only the effective bytes are written, followed by a
+    /// NUL unless the string fills all `N` bytes.
     impl<const N: usize> BinWrite for FixedStr<N> {
-        type Args<'a> = ();
+        type Args<'a> = FixedStrBinArgs;
 
         fn write_options<W: Write + Seek>(
             &self,
             writer: &mut W,
             _endian: binrw::Endian,
-            _args: Self::Args<'_>,
+            args: Self::Args<'_>,
         ) -> binrw::BinResult<()> {
-            writer.write_all(&self.data)?;
+            if args.null_terminated {
+                let len = self.len();
+                writer.write_all(&self.data[..len])?;
+                if len < N {
+                    writer.write_all(&[0])?;
+                }
+            } else if args.pad == 0 {
+                writer.write_all(&self.data)?;
+            } else {
+                let mut buf = [args.pad; N];
+                let len = self.len();
+                buf[..len].copy_from_slice(&self.data[..len]);
+                writer.write_all(&buf)?;
+            }
             Ok(())
         }
     }
 }
 
+/// Provides binrw helpers for reading and writing `Option<FixedStr<N>>`.
+///
+/// Optional fields are stored as blank slots: `None` is encoded as a field where every
+/// byte is the sentinel, either all `0x00` (`*_zeroed`) or all `0xFF` (`*_erased`).
+/// Use them with `#[br(parse_with = ...)]` and `#[bw(write_with = ...)]`.
+///
+/// # Examples
+/// ```
+/// use binrw::{binrw, BinRead};
+/// use fixed_str::{serialize_ext::binrw_option, FixedStr};
+///
+/// #[binrw]
+/// struct Record {
+///     #[br(parse_with = binrw_option::read_zeroed)]
+///     #[bw(write_with = binrw_option::write_zeroed)]
+///     label: Option<FixedStr<4>>,
+/// }
+///
+/// let record = Record::read_le(&mut binrw::io::Cursor::new([0u8; 4])).unwrap();
+/// assert!(record.label.is_none());
+/// ```
+#[cfg(feature = "binrw")]
+pub mod binrw_option {
+    use crate::FixedStr;
+    use binrw::io::{Read, Seek, Write};
+    use binrw::{BinResult, Endian};
+
+    fn read_with_sentinel<R: Read + Seek, const N: usize>(
+        reader: &mut R,
+        sentinel: u8,
+    ) -> BinResult<Option<FixedStr<N>>> {
+        let mut buf = [0u8; N];
+        reader.read_exact(&mut buf)?;
+        if buf.iter().all(|&b| b == sentinel) {
+            Ok(None)
+        } else {
+            Ok(Some(FixedStr { data: buf }))
+        }
+    }
+
+    fn write_with_sentinel<W: Write + Seek, const N: usize>(
+        value: &Option<FixedStr<N>>,
+        writer: &mut W,
+        sentinel: u8,
+    ) -> BinResult<()> {
+        match value {
+            Some(fixed) => writer.write_all(&fixed.data)?,
+            None => writer.write_all(&[sentinel; N])?,
+        }
+        Ok(())
+    }
+
+    /// Reads an `Option<FixedStr<N>>`, treating an all‑zero field as `None`.
+    pub fn read_zeroed<R: Read + Seek, const N: usize>(
+        reader: &mut R,
+        _endian: Endian,
+        _args: (),
+    ) -> BinResult<Option<FixedStr<N>>> {
+        read_with_sentinel(reader, 0x00)
+    }
+
+    /// Writes an `Option<FixedStr<N>>`, encoding `None` as an all‑zero field.
+    pub fn write_zeroed<W: Write + Seek, const N: usize>(
+        value: &Option<FixedStr<N>>,
+        writer: &mut W,
+        _endian: Endian,
+        _args: (),
+    ) -> BinResult<()> {
+        write_with_sentinel(value, writer, 0x00)
+    }
+
+    /// Reads an `Option<FixedStr<N>>`, treating an all‑`0xFF` (erased) field as `None`.
+    pub fn read_erased<R: Read + Seek, const N: usize>(
+        reader: &mut R,
+        _endian: Endian,
+        _args: (),
+    ) -> BinResult<Option<FixedStr<N>>> {
+        read_with_sentinel(reader, 0xFF)
+    }
+
+    /// Writes an `Option<FixedStr<N>>`, encoding `None` as an all‑`0xFF` (erased) field.
+    pub fn write_erased<W: Write + Seek, const N: usize>(
+        value: &Option<FixedStr<N>>,
+        writer: &mut W,
+        _endian: Endian,
+        _args: (),
+    ) -> BinResult<()> {
+        write_with_sentinel(value, writer, 0xFF)
+    }
+}
+
 // --- Tests for binrw integration ---
 #[cfg(all(test, feature = "binrw", feature = "std"))]
 mod binrw_tests {
@@ -55,13 +226,135 @@ mod binrw_tests {
         // Use a Cursor for both writing and reading.
         let mut cursor = Cursor::new(Vec::new());
         original
-            .write_options(&mut cursor, Endian::Little, ())
+            .write_options(&mut cursor, Endian::Little, Default::default())
             .expect("writing failed");
         cursor.set_position(0);
         let read: FixedStr<5> =
-            FixedStr::read_options(&mut cursor, Endian::Little, ()).expect("reading failed");
+            FixedStr::read_options(&mut cursor, Endian::Little, Default::default())
+                .expect("reading failed");
         assert_eq!(original, read);
     }
+
+    #[test]
+    fn test_binrw_space_padding() {
+        use binrw::{binrw, BinRead, BinWrite};
+        use std::io::Cursor;
+
+        #[binrw]
+        #[derive(Debug, PartialEq)]
+        struct Header {
+            #[br(args { pad: b' ', trim: true })]
+            #[bw(args { pad: b' ' })]
+            name: FixedStr<8>,
+        }
+
+        let header = Header {
+            name: FixedStr::new("README"),
+        };
+        let mut cursor = Cursor::new(Vec::new());
+        header.write_le(&mut cursor).unwrap();
+        assert_eq!(cursor.get_ref(), b"README  ");
+
+        cursor.set_position(0);
+        let read = Header::read_le(&mut cursor).unwrap();
+        assert_eq!(read, header);
+        assert_eq!(read.name.len(), 6);
+    }
+
+    #[test]
+    fn test_binrw_null_terminated() {
+        use binrw::{binrw, BinRead, BinWrite};
+        use std::io::Cursor;
+
+        #[binrw]
+        #[derive(Debug, PartialEq)]
+        struct Entry {
+            #[brw(args { null_terminated: true })]
+            name: FixedStr<4>,
+            #[brw(args { null_terminated: true })]
+            full: FixedStr<4>,
+            tag: u8,
+        }
+
+        let entry = Entry {
+            name: FixedStr::new("ab"),
+            full: FixedStr::new("cdef"),
+            tag: 7,
+        };
+        let mut cursor = Cursor::new(Vec::new());
+        entry.write_le(&mut cursor).unwrap();
+        assert_eq!(cursor.get_ref(), b"ab\0cdef\x07");
+
+        cursor.set_position(0);
+        assert_eq!(Entry::read_le(&mut cursor).unwrap(), entry);
+    }
+
+    #[test]
+    fn test_binrw_validate() {
+        use binrw::BinReaderExt;
+        use std::io::Cursor;
+
+        let mut cursor = Cursor::new(b"xx\xFFab\0");
+        cursor.set_position(2);
+        let err = cursor
+            .read_le_args::<FixedStr<4>>(
+                crate::serialize_ext::FixedStrBinArgs::builder()
+                    .validate(true)
+                    .finalize(),
+            )
+            .unwrap_err();
+        match err {
+            binrw::Error::Custom { pos, err } => {
+                assert_eq!(pos, 2);
+                assert_eq!(
+                    err.downcast_ref(),
+                    Some(&FixedStrError::InvalidUtf8 { valid_up_to: 0 })
+                );
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        // Without validation the bytes are stored as-is.
+        cursor.set_position(2);
+        let fixed: FixedStr<4> = cursor.read_le().unwrap();
+        assert!(fixed.try_as_str().is_err());
+
+        // Bytes after the first NUL are not validated.
+        let mut cursor = Cursor::new(b"ok\0\xFF");
+        let args = crate::serialize_ext::FixedStrBinArgs::builder()
+            .validate(true)
+            .finalize();
+        assert_eq!(cursor.read_le_args::<FixedStr<4>>(args).unwrap(), "ok");
+    }
+
+    #[test]
+    fn test_binrw_option_sentinels() {
+        use binrw::Endian;
+        use serialize_ext::binrw_option::*;
+        use std::io::Cursor;
+
+        let mut cursor = Cursor::new(Vec::new());
+        write_erased::<_, 4>(&None, &mut cursor, Endian::Little, ()).unwrap();
+        write_zeroed(
+            &Some(FixedStr::<4>::new("ab")),
+            &mut cursor,
+            Endian::Little,
+            (),
+        )
+        .unwrap();
+        assert_eq!(cursor.get_ref(), b"\xFF\xFF\xFF\xFFab\0\0");
+
+        cursor.set_position(0);
+        let erased = read_erased::<_, 4>(&mut cursor, Endian::Little, ()).unwrap();
+        let zeroed = read_zeroed::<_, 4>(&mut cursor, Endian::Little, ()).unwrap();
+        assert_eq!(erased, None);
+        assert_eq!(zeroed, Some(FixedStr::new("ab")));
+
+        // An all-zero field is a value (the empty string) for the erased convention.
+        let mut cursor = Cursor::new([0u8; 4]);
+        let empty = read_erased::<_, 4>(&mut cursor, Endian::Little, ()).unwrap();
+        assert_eq!(empty, Some(FixedStr::default()));
+    }
 }
 
 //******************************************************************************
@@ -77,14 +370,21 @@ mod serde_ext {
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
     /// Implements Serde serialization for `FixedStr`.
+    ///
+    /// Human‑readable formats (JSON, TOML, ...) receive the effective string. Binary formats
+    /// receive the full `N`‑byte buffer as a fixed‑size tuple (see
+    /// [`serde_as_padded_array`](crate::serialize_ext::serde_as_padded_array)).
     impl<const N: usize> Serialize for FixedStr<N> {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
+            if !serializer.is_human_readable() {
+                return serialize_ext::serde_as_padded_array::serialize(self, serializer);
+            }
             match self.try_as_str() {
                 Ok(s) => serializer.serialize_str(s),
-                Err(_) => Err(S::Error::custom(FixedStrError::InvalidUtf8)),
+                Err(e) => Err(S::Error::custom(e)),
             }
         }
     }
@@ -108,21 +408,80 @@ mod serde_ext {
     }
 
     /// Implements Serde deserialization for `FixedStr`.
+    ///
+    /// Mirrors the serialization: a string for human‑readable formats, a fixed‑size tuple
+    /// of `N` bytes otherwise. In both cases the content is truncated at the last valid
+    /// UTF‑8 boundary, as with [`FixedStr::new`] and [`FixedStr::from_bytes`].
     impl<'de, const N: usize> Deserialize<'de> for FixedStr<N> {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: Deserializer<'de>,
         {
-            deserializer.deserialize_str(FixedStrVisitor::<N>)
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(FixedStrVisitor::<N>)
+            } else {
+                serialize_ext::serde_as_padded_array::deserialize(deserializer)
+                    .map(|raw: FixedStr<N>| FixedStr::from_bytes(raw.data))
+            }
         }
     }
 }
 
 /// Provides alternative (byte-based) serialization for `FixedStr` via Serde.
+///
+/// Deserialization accepts borrowed or owned byte buffers as well as sequences of bytes
+/// and strings, so it also works with formats like JSON or MessagePack.
 #[cfg(feature = "serde")]
 pub mod serde_as_bytes {
-    use crate::FixedStr;
-    use serde::{Deserialize, Deserializer, Serializer};
+    use crate::{FixedStr, FixedStrError};
+    use core::fmt;
+    use serde::de::{Error as DeError, SeqAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    /// A visitor for deserializing a `FixedStr` from bytes.
+    struct BytesVisitor<const N: usize>;
+
+    impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+        type Value = FixedStr<N>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "at most {} bytes", N)
+        }
+
+        /// Handles borrowed and owned byte buffers (the latter are forwarded here by Serde).
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: DeError,
+        {
+            FixedStr::<N>::try_from(v).map_err(E::custom)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: DeError,
+        {
+            self.visit_bytes(v.as_bytes())
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut buf = [0u8; N];
+            let mut len = 0;
+            while let Some(byte) = seq.next_element::<u8>()? {
+                if len == N {
+                    return Err(A::Error::custom(FixedStrError::Overflow {
+                        available: N,
+                        found: N + 1 + seq.size_hint().unwrap_or(0),
+                    }));
+                }
+                buf[len] = byte;
+                len += 1;
+            }
+            self.visit_bytes(&buf[..len])
+        }
+    }
 
     /// Serializes a `FixedStr<N>` as raw bytes.
     pub fn serialize<S, const N: usize>(
@@ -140,8 +499,240 @@ pub mod serde_as_bytes {
     where
         D: Deserializer<'de>,
     {
-        let bytes: &[u8] = Deserialize::deserialize(deserializer)?;
-        FixedStr::<N>::try_from(bytes).map_err(serde::de::Error::custom)
+        deserializer.deserialize_bytes(BytesVisitor::<N>)
+    }
+}
+
+/// Provides raw byte serialization for `FixedStr` via Serde, without validation.
+///
+/// Deserialization requests bytes with `deserialize_bytes`, so formats that can lend their
+/// input (e.g. bincode or postcard reading from a slice) hand over borrowed data that is
+/// copied straight into the `[u8; N]` buffer. Unlike [`serde_as_bytes`], no UTF‑8
+/// validation or null scan is performed, which suits high‑throughput binary pipelines.
+///
+/// # Warning
+/// Use with care—like [`FixedStr::from_bytes_unsafe`], this may produce values that may
+/// cause conversions to panic or comparisons to fail.
+#[cfg(feature = "serde")]
+pub mod serde_as_raw_bytes {
+    use crate::FixedStr;
+    use core::fmt;
+    use serde::de::{Error as DeError, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    /// A visitor copying bytes directly into the buffer of a `FixedStr`.
+    struct RawBytesVisitor<const N: usize>;
+
+    impl<const N: usize> Visitor<'_> for RawBytesVisitor<N> {
+        type Value = FixedStr<N>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "at most {} bytes", N)
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: DeError,
+        {
+            if v.len() > N {
+                return Err(E::invalid_length(v.len(), &self));
+            }
+            let mut data = [0u8; N];
+            data[..v.len()].copy_from_slice(v);
+            Ok(FixedStr { data })
+        }
+    }
+
+    /// Serializes the full buffer of a `FixedStr<N>` as raw bytes.
+    pub fn serialize<S, const N: usize>(
+        value: &FixedStr<N>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(value.as_bytes())
+    }
+
+    /// Deserializes a `FixedStr<N>` from raw bytes, storing them as‑is.
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<FixedStr<N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(RawBytesVisitor::<N>)
+    }
+}
+
+/// Provides fixed‑size array serialization for `FixedStr` via Serde.
+///
+/// The full `N`‑byte buffer, including padding, is serialized as a tuple of `N` bytes.
+/// Formats such as bincode or postcard encode this without a length prefix, so the value
+/// always occupies exactly `N` bytes on the wire.
+///
+/// **Note:** Like the `binrw` integration, deserialization stores the bytes as‑is
+/// without validating UTF‑8.
+#[cfg(feature = "serde")]
+pub mod serde_as_padded_array {
+    use crate::FixedStr;
+    use core::fmt;
+    use serde::de::{Error as DeError, SeqAccess, Visitor};
+    use serde::ser::SerializeTuple;
+    use serde::{Deserializer, Serializer};
+
+    /// A visitor for deserializing a `FixedStr` from a tuple of `N` bytes.
+    struct PaddedArrayVisitor<const N: usize>;
+
+    impl<'de, const N: usize> Visitor<'de> for PaddedArrayVisitor<N> {
+        type Value = FixedStr<N>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "an array of {} bytes", N)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut data = [0u8; N];
+            for (i, byte) in data.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+            }
+            Ok(FixedStr { data })
+        }
+    }
+
+    /// Serializes the full buffer of a `FixedStr<N>` as a tuple of `N` bytes.
+    pub fn serialize<S, const N: usize>(
+        value: &FixedStr<N>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for byte in value.as_bytes() {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+
+    /// Deserializes a `FixedStr<N>` from a tuple of exactly `N` bytes.
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<FixedStr<N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(N, PaddedArrayVisitor::<N>)
+    }
+}
+
+/// Provides strict string serialization for `FixedStr` via Serde.
+///
+/// Always uses the string representation, and deserialization fails with an
+/// `invalid length` error instead of truncating input that exceeds the capacity.
+#[cfg(feature = "serde")]
+pub mod serde_strict {
+    use crate::FixedStr;
+    use core::fmt;
+    use serde::de::{Error as DeError, Visitor};
+    use serde::ser::Error as SerError;
+    use serde::{Deserializer, Serializer};
+
+    /// A visitor for strictly deserializing a `FixedStr`.
+    struct StrictVisitor<const N: usize>;
+
+    impl<const N: usize> Visitor<'_> for StrictVisitor<N> {
+        type Value = FixedStr<N>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a string of at most {} bytes", N)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: DeError,
+        {
+            FixedStr::try_from(value.as_bytes()).map_err(|_| E::invalid_length(value.len(), &self))
+        }
+    }
+
+    /// Serializes a `FixedStr<N>` as a string, regardless of the format.
+    pub fn serialize<S, const N: usize>(
+        value: &FixedStr<N>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let s = value.try_as_str().map_err(S::Error::custom)?;
+        serializer.serialize_str(s)
+    }
+
+    /// Deserializes a `FixedStr<N>` from a string, failing if it exceeds the capacity.
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<FixedStr<N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrictVisitor::<N>)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std", target_has_atomic = "ptr"))]
+mod serde_symbol_ext {
+    use crate::*;
+    use core::marker::PhantomData;
+    use serde::de::{Error as DeError, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a `SymbolStr` as a string, regardless of the format.
+    impl<const N: usize, V> Serialize for SymbolStr<N, V> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            // Interned values were validated as UTF‑8 on construction.
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    /// A visitor that validates and interns a string.
+    struct SymbolVisitor<const N: usize, V>(PhantomData<fn() -> V>);
+
+    impl<const N: usize, V> Visitor<'_> for SymbolVisitor<N, V>
+    where
+        V: Validator + Default,
+        V::Error: fmt::Display,
+    {
+        type Value = SymbolStr<N, V>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a valid string of at most {} bytes", N)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: DeError,
+        {
+            SymbolStr::new(value).map_err(|err| match err {
+                ValidationError::Invalid(_) => E::invalid_length(value.len(), &self),
+                ValidationError::Rule(rule) => E::custom(rule),
+            })
+        }
+    }
+
+    /// Deserializes a string, validating it with `V` and interning it.
+    impl<'de, const N: usize, V> Deserialize<'de> for SymbolStr<N, V>
+    where
+        V: Validator + Default,
+        V::Error: fmt::Display,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(SymbolVisitor::<N, V>(PhantomData))
+        }
     }
 }
 
@@ -150,7 +741,29 @@ pub mod serde_as_bytes {
 mod serde_tests {
     use crate::*;
     use serde::{Deserialize, Serialize};
-    use serde_test::{assert_tokens, Token};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    #[test]
+    fn test_serde_adaptive_readable() {
+        let fixed = FixedStr::<4>::new("Hi");
+        assert_tokens(&fixed.readable(), &[Token::Str("Hi")]);
+    }
+
+    #[test]
+    fn test_serde_adaptive_compact() {
+        let fixed = FixedStr::<4>::new("Hi");
+        assert_tokens(
+            &fixed.compact(),
+            &[
+                Token::Tuple { len: 4 },
+                Token::U8(b'H'),
+                Token::U8(b'i'),
+                Token::U8(0),
+                Token::U8(0),
+                Token::TupleEnd,
+            ],
+        );
+    }
 
     /// A test structure to verify byte-based serialization of FixedStr.
     #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -179,4 +792,421 @@ mod serde_tests {
             ],
         );
     }
+
+    #[test]
+    fn test_serde_as_bytes_owned_and_seq() {
+        use serde_test::assert_de_tokens;
+        let expected = ByteWrapper {
+            inner: FixedStr::new("Hi"),
+        };
+        let header = [
+            Token::Struct {
+                name: "ByteWrapper",
+                len: 1,
+            },
+            Token::Str("inner"),
+        ];
+        let bodies: [&[Token]; 3] = [
+            &[Token::ByteBuf(b"Hi")],
+            &[Token::Str("Hi")],
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U8(b'H'),
+                Token::U8(b'i'),
+                Token::SeqEnd,
+            ],
+        ];
+        for body in bodies {
+            let mut tokens = header.to_vec();
+            tokens.extend_from_slice(body);
+            tokens.push(Token::StructEnd);
+            assert_de_tokens(&expected, &tokens);
+        }
+    }
+
+    #[test]
+    fn test_serde_as_bytes_seq_overflow() {
+        let mut tokens = vec![
+            Token::Struct {
+                name: "ByteWrapper",
+                len: 1,
+            },
+            Token::Str("inner"),
+            Token::Seq { len: Some(6) },
+        ];
+        tokens.extend(b"Hello!".iter().map(|&b| Token::U8(b)));
+        assert_de_tokens_error::<ByteWrapper>(
+            &tokens,
+            "Overflow: tried to add 6 bytes with only 5 bytes available",
+        );
+    }
+
+    /// A test structure to verify raw byte serialization of FixedStr.
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct RawWrapper {
+        #[serde(with = "serialize_ext::serde_as_raw_bytes")]
+        inner: FixedStr<4>,
+    }
+
+    #[test]
+    fn test_serde_as_raw_bytes() {
+        let wrapper = RawWrapper {
+            inner: FixedStr::from_bytes_unsafe([b'a', 0xFF, 0, 0]),
+        };
+        assert_tokens(
+            &wrapper,
+            &[
+                Token::Struct {
+                    name: "RawWrapper",
+                    len: 1,
+                },
+                Token::Str("inner"),
+                Token::BorrowedBytes(&[b'a', 0xFF, 0, 0]),
+                Token::StructEnd,
+            ],
+        );
+        assert_de_tokens_error::<RawWrapper>(
+            &[
+                Token::Struct {
+                    name: "RawWrapper",
+                    len: 1,
+                },
+                Token::Str("inner"),
+                Token::BorrowedBytes(b"Hello"),
+            ],
+            "invalid length 5, expected at most 4 bytes",
+        );
+    }
+
+    /// A test structure to verify padded array serialization of FixedStr.
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct PaddedWrapper {
+        #[serde(with = "serialize_ext::serde_as_padded_array")]
+        inner: FixedStr<4>,
+    }
+
+    #[test]
+    fn test_serde_as_padded_array() {
+        let wrapper = PaddedWrapper {
+            inner: FixedStr::new("Hi"),
+        };
+        assert_tokens(
+            &wrapper,
+            &[
+                Token::Struct {
+                    name: "PaddedWrapper",
+                    len: 1,
+                },
+                Token::Str("inner"),
+                Token::Tuple { len: 4 },
+                Token::U8(b'H'),
+                Token::U8(b'i'),
+                Token::U8(0),
+                Token::U8(0),
+                Token::TupleEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// A test structure to verify strict deserialization of FixedStr.
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct StrictWrapper {
+        #[serde(with = "serialize_ext::serde_strict")]
+        inner: FixedStr<5>,
+    }
+
+    #[test]
+    fn test_serde_strict_roundtrip() {
+        let wrapper = StrictWrapper {
+            inner: FixedStr::new("Hello"),
+        };
+        assert_tokens(
+            &wrapper,
+            &[
+                Token::Struct {
+                    name: "StrictWrapper",
+                    len: 1,
+                },
+                Token::Str("inner"),
+                Token::Str("Hello"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_serde_strict_overflow() {
+        assert_de_tokens_error::<StrictWrapper>(
+            &[
+                Token::Struct {
+                    name: "StrictWrapper",
+                    len: 1,
+                },
+                Token::Str("inner"),
+                Token::Str("Hello!"),
+            ],
+            "invalid length 6, expected a string of at most 5 bytes",
+        );
+    }
+
+    #[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+    #[test]
+    fn test_serde_symbol_str() {
+        type Name = SymbolStr<8, PrintableAscii>;
+        let name = Name::new("pump").unwrap();
+        assert_tokens(&name, &[Token::Str("pump")]);
+        assert_de_tokens_error::<Name>(
+            &[Token::Str("pump\t")],
+            "Rejected character '\\t' at byte 4",
+        );
+        assert_de_tokens_error::<Name>(
+            &[Token::Str("turbopump")],
+            "invalid length 9, expected a valid string of at most 8 bytes",
+        );
+    }
+}
+
+//******************************************************************************
+//  bincode Serialization
+//******************************************************************************
+
+#[cfg(feature = "bincode")]
+pub use bincode_ext::BincodePadded;
+
+#[cfg(feature = "bincode")]
+mod bincode_ext {
+    use crate::*;
+    use bincode::de::read::Reader;
+    use bincode::de::{BorrowDecoder, Decoder};
+    use bincode::enc::Encoder;
+    use bincode::error::{DecodeError, EncodeError};
+    use bincode::{BorrowDecode, Decode, Encode};
+
+    /// A `FixedStr` that bincode encodes as its full `N`‑byte array, without a length prefix.
+    ///
+    /// A plain `FixedStr` encodes only its effective string. Wrap fields in `BincodePadded`
+    /// where a format needs fixed‑size records; the choice is made per field, so it does not
+    /// change the encoding seen by other crates.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::serialize_ext::BincodePadded;
+    /// use fixed_str::FixedStr;
+    ///
+    /// let config = bincode::config::standard();
+    /// let padded = BincodePadded(FixedStr::<4>::new("ab"));
+    /// let mut buf = [0u8; 8];
+    /// let len = bincode::encode_into_slice(padded, &mut buf, config).unwrap();
+    /// assert_eq!(&buf[..len], b"ab\0\0");
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct BincodePadded<const N: usize>(pub FixedStr<N>);
+
+    impl<const N: usize> From<FixedStr<N>> for BincodePadded<N> {
+        fn from(fixed: FixedStr<N>) -> Self {
+            Self(fixed)
+        }
+    }
+
+    impl<const N: usize> From<BincodePadded<N>> for FixedStr<N> {
+        fn from(padded: BincodePadded<N>) -> Self {
+            padded.0
+        }
+    }
+
+    /// Rejects content that is not valid UTF‑8.
+    fn check_utf8<const N: usize>(fixed: &FixedStr<N>) -> Result<(), DecodeError> {
+        str::from_utf8(fixed.effective_bytes()).map_err(|inner| DecodeError::Utf8 { inner })?;
+        Ok(())
+    }
+
+    /// Encodes the effective string like a `str`: a length prefix followed by the bytes.
+    impl<const N: usize> Encode for FixedStr<N> {
+        fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+            self.effective_bytes().encode(encoder)
+        }
+    }
+
+    /// Decodes the form written by `Encode`.
+    ///
+    /// Rejects content that is longer than `N` bytes, contains a null byte (which would end
+    /// the string early and drop the bytes after it), or is not valid UTF‑8.
+    impl<Context, const N: usize> Decode<Context> for FixedStr<N> {
+        fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+            let len = u64::decode(decoder)?;
+            let len = usize::try_from(len).map_err(|_| DecodeError::OutsideUsizeRange(len))?;
+            if len > N {
+                return Err(DecodeError::Other("FixedStr exceeds its capacity"));
+            }
+            decoder.claim_bytes_read(len)?;
+            let mut data = [0u8; N];
+            decoder.reader().read(&mut data[..len])?;
+            if data[..len].contains(&0) {
+                return Err(DecodeError::Other("FixedStr contains a null byte"));
+            }
+            let fixed = Self { data };
+            check_utf8(&fixed)?;
+            Ok(fixed)
+        }
+    }
+
+    impl<'de, Context, const N: usize> BorrowDecode<'de, Context> for FixedStr<N> {
+        fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+            decoder: &mut D,
+        ) -> Result<Self, DecodeError> {
+            Decode::decode(decoder)
+        }
+    }
+
+    /// Encodes the full `N`‑byte array, padding included.
+    impl<const N: usize> Encode for BincodePadded<N> {
+        fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+            self.0.data.encode(encoder)
+        }
+    }
+
+    /// Decodes the full `N`‑byte array, rejecting content that is not valid UTF‑8.
+    impl<Context, const N: usize> Decode<Context> for BincodePadded<N> {
+        fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+            let fixed = FixedStr {
+                data: <[u8; N]>::decode(decoder)?,
+            };
+            check_utf8(&fixed)?;
+            Ok(Self(fixed))
+        }
+    }
+
+    impl<'de, Context, const N: usize> BorrowDecode<'de, Context> for BincodePadded<N> {
+        fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+            decoder: &mut D,
+        ) -> Result<Self, DecodeError> {
+            Decode::decode(decoder)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "bincode"))]
+mod bincode_tests {
+    use crate::*;
+    use serialize_ext::BincodePadded;
+
+    #[derive(bincode::Encode, bincode::Decode, Debug, PartialEq)]
+    struct Record {
+        name: FixedStr<6>,
+        code: BincodePadded<4>,
+        id: u8,
+    }
+
+    #[test]
+    fn test_bincode_roundtrip() {
+        let config = bincode::config::standard();
+        let record = Record {
+            name: FixedStr::new("abc"),
+            code: BincodePadded(FixedStr::new("xy")),
+            id: 7,
+        };
+        let mut buf = [0u8; 16];
+        let len = bincode::encode_into_slice(&record, &mut buf, config).unwrap();
+        assert_eq!(&buf[..len], b"\x03abcxy\0\0\x07");
+
+        let (decoded, used): (Record, usize) =
+            bincode::decode_from_slice(&buf[..len], config).unwrap();
+        assert_eq!((decoded, used), (record, len));
+    }
+
+    #[test]
+    fn test_bincode_decode_errors() {
+        let config = bincode::config::standard();
+        let too_long = bincode::decode_from_slice::<FixedStr<2>, _>(b"\x03abc", config);
+        assert!(too_long.is_err());
+        let invalid = bincode::decode_from_slice::<FixedStr<4>, _>(b"\x02a\xFF", config);
+        assert!(matches!(
+            invalid,
+            Err(bincode::error::DecodeError::Utf8 { .. })
+        ));
+        let interior_nul = bincode::decode_from_slice::<FixedStr<4>, _>(b"\x03a\0b", config);
+        assert!(matches!(
+            interior_nul,
+            Err(bincode::error::DecodeError::Other(_))
+        ));
+        let invalid = bincode::decode_from_slice::<BincodePadded<2>, _>(b"\xFF\0", config);
+        assert!(matches!(
+            invalid,
+            Err(bincode::error::DecodeError::Utf8 { .. })
+        ));
+    }
+}
+
+//******************************************************************************
+//  SCALE Codec
+//******************************************************************************
+
+#[cfg(feature = "scale_codec")]
+mod scale_ext {
+    use crate::*;
+    use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+
+    /// Encodes the full `N`‑byte buffer, without a length prefix.
+    impl<const N: usize> Encode for FixedStr<N> {
+        fn size_hint(&self) -> usize {
+            N
+        }
+
+        fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+            dest.write(&self.data);
+        }
+    }
+
+    impl<const N: usize> EncodeLike for FixedStr<N> {}
+
+    /// Decodes exactly `N` bytes, rejecting content that is not valid UTF‑8.
+    impl<const N: usize> Decode for FixedStr<N> {
+        fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+            let mut data = [0u8; N];
+            input.read(&mut data)?;
+            let fixed = Self { data };
+            fixed
+                .try_as_str()
+                .map_err(|_| Error::from("FixedStr contains invalid UTF-8"))?;
+            Ok(fixed)
+        }
+    }
+
+    impl<const N: usize> MaxEncodedLen for FixedStr<N> {
+        fn max_encoded_len() -> usize {
+            N
+        }
+    }
+}
+
+#[cfg(all(test, feature = "scale_codec"))]
+mod scale_tests {
+    use crate::*;
+    use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+    #[derive(Encode, Decode, MaxEncodedLen, Debug, PartialEq)]
+    #[codec(crate = parity_scale_codec)]
+    struct Asset {
+        symbol: FixedStr<4>,
+        decimals: u8,
+    }
+
+    #[test]
+    fn test_scale_roundtrip() {
+        let asset = Asset {
+            symbol: FixedStr::new("DOT"),
+            decimals: 10,
+        };
+        let encoded = asset.encode();
+        assert_eq!(encoded, b"DOT\0\x0A");
+        assert_eq!(Asset::max_encoded_len(), 5);
+        assert_eq!(Asset::decode(&mut &encoded[..]), Ok(asset));
+    }
+
+    #[test]
+    fn test_scale_decode_errors() {
+        assert!(FixedStr::<4>::decode(&mut &b"abc"[..]).is_err());
+        assert!(FixedStr::<4>::decode(&mut &b"a\xFFbc"[..]).is_err());
+    }
 }
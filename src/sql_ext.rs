@@ -0,0 +1,175 @@
+// fixed_str/src/sql_ext.rs
+
+//******************************************************************************
+//  rusqlite
+//******************************************************************************
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite_ext {
+    use crate::*;
+    use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+    /// Binds a `FixedStr` as a TEXT parameter holding the effective string.
+    ///
+    /// Fails with `ToSqlConversionFailure` if the content is not valid UTF‑8.
+    impl<const N: usize> ToSql for FixedStr<N> {
+        fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+            self.try_as_str()
+                .map(ToSqlOutput::from)
+                .map_err(|e| rusqlite::Error::ToSqlConversionFailure(alloc::boxed::Box::new(e)))
+        }
+    }
+
+    /// Reads a `FixedStr` from a TEXT column without truncation.
+    ///
+    /// Returns `FromSqlError::InvalidType` for other column types and `FromSqlError::Other`
+    /// if the text is not valid UTF‑8, exceeds the capacity, or contains a null byte (which
+    /// would end the string early).
+    impl<const N: usize> FromSql for FixedStr<N> {
+        fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+            let text = value.as_str()?;
+            if let Some(position) = text.bytes().position(|b| b == 0) {
+                let e = FixedStrError::InteriorNul { position };
+                return Err(FromSqlError::Other(alloc::boxed::Box::new(e)));
+            }
+            copy_into_buffer(text.as_bytes(), BufferCopyMode::Exact)
+                .map(|data| FixedStr { data })
+                .map_err(|e| FromSqlError::Other(alloc::boxed::Box::new(e)))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rusqlite"))]
+mod rusqlite_tests {
+    use crate::*;
+    use rusqlite::Connection;
+
+    #[test]
+    fn test_rusqlite_round_trip() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE t (name TEXT)", []).unwrap();
+        conn.execute("INSERT INTO t VALUES (?1)", [FixedStr::<8>::new("héllo")])
+            .unwrap();
+        let name: FixedStr<8> = conn
+            .query_row("SELECT name FROM t", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(name, "héllo");
+        let text: String = conn
+            .query_row("SELECT name FROM t", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(text, "héllo");
+    }
+
+    #[test]
+    fn test_rusqlite_rejects() {
+        let conn = Connection::open_in_memory().unwrap();
+        let overflow = conn.query_row("SELECT 'too long'", [], |row| row.get::<_, FixedStr<4>>(0));
+        assert!(matches!(
+            overflow,
+            Err(rusqlite::Error::FromSqlConversionFailure(..))
+        ));
+        let invalid = conn.query_row("SELECT CAST(x'ff' AS TEXT)", [], |row| {
+            row.get::<_, FixedStr<4>>(0)
+        });
+        assert!(invalid.is_err());
+        let wrong_type = conn.query_row("SELECT 42", [], |row| row.get::<_, FixedStr<4>>(0));
+        assert!(matches!(
+            wrong_type,
+            Err(rusqlite::Error::InvalidColumnType(..))
+        ));
+
+        let interior_nul = conn.query_row("SELECT CAST(x'610062' AS TEXT)", [], |row| {
+            row.get::<_, FixedStr<4>>(0)
+        });
+        match interior_nul {
+            Err(rusqlite::Error::FromSqlConversionFailure(_, _, e)) => assert_eq!(
+                e.downcast_ref::<FixedStrError>(),
+                Some(&FixedStrError::InteriorNul { position: 1 })
+            ),
+            other => panic!("expected a conversion failure, got {:?}", other),
+        }
+
+        let raw = FixedStr::<2>::from_bytes_unsafe([0xFF, 0]);
+        assert!(conn.query_row("SELECT ?1", [raw], |_| Ok(())).is_err());
+    }
+}
+
+//******************************************************************************
+//  postgres-types
+//******************************************************************************
+
+#[cfg(feature = "postgres")]
+mod postgres_ext {
+    use crate::*;
+    use alloc::boxed::Box;
+    use bytes::BytesMut;
+    use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+    use std::error::Error;
+
+    /// Binds a `FixedStr` as a text value (TEXT, VARCHAR, BPCHAR, ...) holding the effective string.
+    ///
+    /// Fails if the content is not valid UTF‑8.
+    impl<const N: usize> ToSql for FixedStr<N> {
+        fn to_sql(
+            &self,
+            ty: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            <&str as ToSql>::to_sql(&self.try_as_str()?, ty, out)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <&str as ToSql>::accepts(ty)
+        }
+
+        to_sql_checked!();
+    }
+
+    /// Reads a `FixedStr` from a text column without truncation.
+    ///
+    /// The trailing spaces that pad `CHAR(N)` (BPCHAR) values are removed, so a `CHAR(8)`
+    /// column fits a `FixedStr<8>` regardless of its padding. Fails if the text exceeds the
+    /// capacity.
+    impl<'a, const N: usize> FromSql<'a> for FixedStr<N> {
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            let mut text = <&str as FromSql>::from_sql(ty, raw)?;
+            if *ty == Type::BPCHAR {
+                text = text.trim_end_matches(' ');
+            }
+            let data = copy_into_buffer(text.effective_bytes(), BufferCopyMode::Exact)?;
+            Ok(FixedStr { data })
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <&str as FromSql>::accepts(ty)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod postgres_tests {
+    use crate::*;
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    #[test]
+    fn test_postgres_round_trip() {
+        let mut out = BytesMut::new();
+        let value = FixedStr::<8>::new("héllo");
+        value.to_sql_checked(&Type::VARCHAR, &mut out).unwrap();
+        assert_eq!(&out[..], "héllo".as_bytes());
+        assert_eq!(FixedStr::<8>::from_sql(&Type::TEXT, &out).unwrap(), value);
+        assert!(value.to_sql_checked(&Type::INT4, &mut out).is_err());
+        assert!(!<FixedStr<8> as FromSql>::accepts(&Type::BYTEA));
+    }
+
+    #[test]
+    fn test_postgres_bpchar_padding() {
+        // A CHAR(8) value is padded with spaces to its full width.
+        let padded = FixedStr::<4>::from_sql(&Type::BPCHAR, b"ab      ").unwrap();
+        assert_eq!(padded, "ab");
+        // Trailing spaces are significant in TEXT.
+        assert!(FixedStr::<4>::from_sql(&Type::TEXT, b"ab      ").is_err());
+        assert!(FixedStr::<4>::from_sql(&Type::TEXT, &[0xFF]).is_err());
+    }
+}
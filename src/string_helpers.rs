@@ -1,7 +1,9 @@
 // fixed_str/src/string_helpers.rs
 
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicPtr, Ordering};
 #[cfg(feature = "memchr")]
-use memchr::memchr;
+use memchr::{memchr, memrchr};
 
 /// Specifies how bytes should be copied from a source slice into a fixed‑size buffer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,9 +24,68 @@ pub const fn panic_on_zero(n: usize) {
     assert!(n > 0, "FixedStr capacity N must be greater than zero");
 }
 
+/// Fails to evaluate for `N == 0`, turning the capacity check into a compile‑time error.
+#[cfg(feature = "no_panic")]
+struct NonZeroCapacity<const N: usize>;
+
+#[cfg(feature = "no_panic")]
+impl<const N: usize> NonZeroCapacity<N> {
+    const CHECK: () = assert!(N > 0, "FixedStr capacity N must be greater than zero");
+}
+
+/// Rejects `N == 0`: at compile time with the `no_panic` feature, otherwise by panicking.
+#[inline(always)]
+pub(crate) const fn require_capacity<const N: usize>() {
+    #[cfg(feature = "no_panic")]
+    #[allow(clippy::let_unit_value)]
+    let () = NonZeroCapacity::<N>::CHECK;
+    #[cfg(not(feature = "no_panic"))]
+    panic_on_zero(N);
+}
+
+/// Returns `&bytes[..len]`. With the `no_panic` feature, an out‑of‑range `len` yields
+/// the whole slice instead of panicking.
+#[inline(always)]
+pub(crate) fn head(bytes: &[u8], len: usize) -> &[u8] {
+    #[cfg(feature = "no_panic")]
+    {
+        bytes.get(..len).unwrap_or(bytes)
+    }
+    #[cfg(not(feature = "no_panic"))]
+    {
+        &bytes[..len]
+    }
+}
+
+/// Copies `src` into `dst` starting at `offset`. With the `no_panic` feature, bytes that
+/// would land out of range are dropped instead of panicking.
+#[inline(always)]
+pub(crate) fn write_at(dst: &mut [u8], offset: usize, src: &[u8]) {
+    #[cfg(feature = "no_panic")]
+    if let Some(dst) = dst.get_mut(offset..) {
+        let n = dst.len().min(src.len());
+        dst[..n].copy_from_slice(&src[..n]);
+    }
+    #[cfg(not(feature = "no_panic"))]
+    dst[offset..offset + src.len()].copy_from_slice(src);
+}
+
+/// Zeroes `dst` from `offset` to the end. With the `no_panic` feature, an out‑of‑range
+/// `offset` is ignored instead of panicking.
+#[inline(always)]
+pub(crate) fn zero_from(dst: &mut [u8], offset: usize) {
+    #[cfg(feature = "no_panic")]
+    if let Some(tail) = dst.get_mut(offset..) {
+        tail.fill(0);
+    }
+    #[cfg(not(feature = "no_panic"))]
+    dst[offset..].fill(0);
+}
+
 /// Finds the index of the first null byte (`\0`) in the given slice.
 ///
 /// Returns the index of the first null byte, or the full length of the slice if no null is found.
+/// See [`find_first_null_const`] for const contexts.
 pub fn find_first_null(bytes: &[u8]) -> usize {
     #[cfg(not(feature = "memchr"))]
     {
@@ -36,6 +97,66 @@ pub fn find_first_null(bytes: &[u8]) -> usize {
     }
 }
 
+/// Finds the index of the first null byte (`\0`) in a const context.
+///
+/// Returns the same result as [`find_first_null`], using a simple loop instead of `memchr`.
+///
+/// # Examples
+/// ```
+/// use fixed_str::find_first_null_const;
+///
+/// const END: usize = find_first_null_const(b"abc\0def");
+/// assert_eq!(END, 3);
+/// ```
+pub const fn find_first_null_const(bytes: &[u8]) -> usize {
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == 0 {
+            return i;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Finds the index of the last occurrence of `needle` in the given slice.
+///
+/// Uses `memrchr` with the `memchr` feature.
+///
+/// # Examples
+/// ```
+/// use fixed_str::rfind_byte;
+///
+/// assert_eq!(rfind_byte(b"usr/local/bin", b'/'), Some(9));
+/// assert_eq!(rfind_byte(b"bin", b'/'), None);
+/// ```
+pub fn rfind_byte(bytes: &[u8], needle: u8) -> Option<usize> {
+    #[cfg(not(feature = "memchr"))]
+    {
+        bytes.iter().rposition(|&b| b == needle)
+    }
+    #[cfg(feature = "memchr")]
+    {
+        memrchr(needle, bytes)
+    }
+}
+
+/// Finds the index of the last byte that is not `pad`, i.e. the end of the content in a
+/// buffer padded with trailing `pad` bytes.
+///
+/// Returns `None` if the slice contains only padding.
+///
+/// # Examples
+/// ```
+/// use fixed_str::last_non_pad;
+///
+/// assert_eq!(last_non_pad(b"FITS    ", b' '), Some(3));
+/// assert_eq!(last_non_pad(b"    ", b' '), None);
+/// ```
+pub fn last_non_pad(bytes: &[u8], pad: u8) -> Option<usize> {
+    bytes.iter().rposition(|&b| b != pad)
+}
+
 /// Finds the largest index (up to `max_len` and not exceeding the first null) such that
 /// the slice `bytes[..index]` is valid UTF‑8.
 ///
@@ -52,7 +173,7 @@ pub fn find_valid_utf8_len(bytes: &[u8], max_len: usize) -> usize {
     let effective = find_first_null(bytes);
     let upper = max_len.min(effective);
     // If the entire prefix is valid UTF‑8, return it.
-    if core::str::from_utf8(&bytes[..upper]).is_ok() {
+    if core::str::from_utf8(head(bytes, upper)).is_ok() {
         return upper;
     }
     // Otherwise, perform a binary search on the interval [0, upper] to find the largest valid prefix.
@@ -61,7 +182,7 @@ pub fn find_valid_utf8_len(bytes: &[u8], max_len: usize) -> usize {
     while low < high {
         // Bias the midpoint upward to converge on the maximum valid index.
         let mid = (low + high + 1) / 2;
-        if core::str::from_utf8(&bytes[..mid]).is_ok() {
+        if core::str::from_utf8(head(bytes, mid)).is_ok() {
             low = mid;
         } else {
             high = mid - 1;
@@ -77,7 +198,7 @@ pub fn find_valid_utf8_len(bytes: &[u8], max_len: usize) -> usize {
 pub fn truncate_utf8_lossy(bytes: &[u8], max_len: usize) -> &str {
     let valid_len = find_valid_utf8_len(bytes, max_len);
     // SAFETY: The computed `valid_len` guarantees that `bytes[..valid_len]` is valid UTF‑8.
-    unsafe { core::str::from_utf8_unchecked(&bytes[..valid_len]) }
+    unsafe { core::str::from_utf8_unchecked(head(bytes, valid_len)) }
 }
 
 /// Finds the largest valid UTF‑8 boundary in the given byte slice within a constant context.
@@ -141,12 +262,23 @@ pub const fn find_valid_boundary(bytes: &[u8], max_len: usize) -> usize {
 /// - `Truncate`: Copies as many valid UTF‑8 bytes as possible (up to `N`), truncating the source safely.
 ///
 /// # Panics
-/// Panics if `N == 0` (zero‑length strings are not supported).
+/// Panics if `N == 0` (zero‑length strings are not supported). With the `no_panic` feature,
+/// `N == 0` is rejected at compile time instead.
+#[cfg_attr(feature = "debug_lossy", track_caller)]
 pub fn copy_into_buffer<const N: usize>(
     src: &[u8],
     mode: BufferCopyMode,
 ) -> Result<[u8; N], crate::FixedStrError> {
-    panic_on_zero(N);
+    require_capacity::<N>();
+    copy_into_array(src, mode)
+}
+
+/// [`copy_into_buffer`] without the capacity check, for the `checked_*` constructors.
+#[cfg_attr(feature = "debug_lossy", track_caller)]
+pub(crate) fn copy_into_array<const N: usize>(
+    src: &[u8],
+    mode: BufferCopyMode,
+) -> Result<[u8; N], crate::FixedStrError> {
     let len = match mode {
         BufferCopyMode::Exact => {
             if src.len() > N {
@@ -158,13 +290,75 @@ pub fn copy_into_buffer<const N: usize>(
             src.len()
         }
         BufferCopyMode::Slice => src.len().min(N),
-        BufferCopyMode::Truncate => find_valid_utf8_len(src, N),
+        BufferCopyMode::Truncate => {
+            let len = find_valid_utf8_len(src, N);
+            #[cfg(feature = "debug_lossy")]
+            crate::lossy_hook::report_if_lossy(N, find_first_null(src), len);
+            len
+        }
     };
     let mut buf = [0u8; N];
-    buf[..len].copy_from_slice(&src[..len]);
+    write_at(&mut buf, 0, head(src, len));
     Ok(buf)
 }
 
+/// Rewrites the trailing padding of every `FixedStr` in a table from `from` to `to`
+/// in one pass, as [`FixedStr::convert_padding`] does for a single value.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{convert_padding_table, FixedStr};
+///
+/// let mut table = [FixedStr::<4>::new("ab"), FixedStr::<4>::new("abcd")];
+/// convert_padding_table(&mut table, 0, 0xFF);
+/// assert_eq!(table[0].as_bytes(), b"ab\xFF\xFF");
+/// assert_eq!(table[1].as_bytes(), b"abcd");
+/// ```
+pub fn convert_padding_table<const N: usize>(table: &mut [crate::FixedStr<N>], from: u8, to: u8) {
+    for fixed in table {
+        *fixed = fixed.convert_padding(from, to);
+    }
+}
+
+/// Folds all ASCII uppercase letters in a table of `FixedStr` values to lowercase in one pass.
+///
+/// Since `FixedStr` is `#[repr(transparent)]` over `[u8; N]`, the table is processed as a
+/// single contiguous byte slice, eight bytes at a time. Non‑ASCII bytes are left unchanged,
+/// so multi‑byte UTF‑8 sequences remain intact.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{ascii_case_fold_table, FixedStr};
+///
+/// let mut table = [FixedStr::<8>::new("Hello"), FixedStr::<8>::new("WÖRLD")];
+/// ascii_case_fold_table(&mut table);
+/// assert_eq!(table[0].as_str(), "hello");
+/// assert_eq!(table[1].as_str(), "wÖrld");
+/// ```
+pub fn ascii_case_fold_table<const N: usize>(table: &mut [crate::FixedStr<N>]) {
+    // SAFETY: `FixedStr<N>` is `#[repr(transparent)]` over `[u8; N]`, so a slice of them
+    // is a contiguous run of `table.len() * N` initialized bytes.
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(table.as_mut_ptr().cast::<u8>(), table.len() * N)
+    };
+
+    const ONES: u64 = 0x0101_0101_0101_0101;
+    let mut chunks = bytes.chunks_exact_mut(8);
+    for chunk in &mut chunks {
+        let word = u64::from_ne_bytes([
+            chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7],
+        ]);
+        // Per byte, the high bit of each mask is set if the low seven bits are `>= 'A'`
+        // or `> 'Z'` respectively; no carries cross byte boundaries.
+        let low7 = word & (0x7F * ONES);
+        let ge_a = low7 + (0x80 - b'A' as u64) * ONES;
+        let gt_z = low7 + (0x7F - b'Z' as u64) * ONES;
+        let upper = (ge_a ^ gt_z) & !word & (0x80 * ONES);
+        chunk.copy_from_slice(&(word | (upper >> 2)).to_ne_bytes());
+    }
+    chunks.into_remainder().make_ascii_lowercase();
+}
+
 /// A constant lookup table that maps each `u8` value to its two-character uppercase hexadecimal representation.
 const HEX_TABLE: [[u8; 2]; 256] = [
     *b"00", *b"01", *b"02", *b"03", *b"04", *b"05", *b"06", *b"07", *b"08", *b"09", *b"0A", *b"0B",
@@ -191,11 +385,214 @@ const HEX_TABLE: [[u8; 2]; 256] = [
     *b"FC", *b"FD", *b"FE", *b"FF",
 ];
 
-/// Formats the given byte slice as an uppercase hexadecimal string,
-/// grouping bytes as specified and inserting spaces and newlines accordingly,
-/// then returns a `FixedStr` containing the formatted output.
+/// Layout options for the hex helpers (`fast_format_hex_styled`, `dump_as_hex_styled`),
+/// also used by the `Debug` fallbacks for invalid UTF‑8.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{fast_format_hex_styled, HexStyle};
+///
+/// const STYLE: HexStyle = HexStyle::DEFAULT.group(4).uppercase(false).ascii_gutter(true);
+/// let hex = fast_format_hex_styled::<64>(b"Hi!\x7f", &STYLE);
+/// assert_eq!(hex.as_str(), "48 69 21 7f  |Hi!.|");
+///
+/// let hex = fast_format_hex_styled::<16>(&[0xDE, 0xAD, 0xBE, 0xEF], &HexStyle::COMPACT);
+/// assert_eq!(hex.as_str(), "deadbeef");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexStyle {
+    /// The number of bytes per line.
+    pub group: usize,
+    /// An optional limit to the number of output lines.
+    pub max_lines: Option<usize>,
+    /// Whether hex digits are printed in uppercase.
+    pub uppercase: bool,
+    /// Whether each line ends with a `|...|` gutter of the printable ASCII bytes.
+    pub ascii_gutter: bool,
+    /// The separator written between the bytes of a line.
+    pub byte_separator: &'static str,
+    /// The separator written between lines.
+    pub line_separator: &'static str,
+}
+
+impl HexStyle {
+    /// The crate default: 16 bytes per line, at most 8 lines, uppercase, no ASCII gutter,
+    /// bytes separated by spaces and lines by newlines.
+    pub const DEFAULT: Self = Self {
+        group: 16,
+        max_lines: Some(8),
+        uppercase: true,
+        ascii_gutter: false,
+        byte_separator: " ",
+        line_separator: "\n",
+    };
+
+    /// Compact lowercase output without any separators or line limit, such as `deadbeef`.
+    pub const COMPACT: Self = Self::DEFAULT
+        .max_lines(None)
+        .uppercase(false)
+        .separators("", "");
+
+    /// Returns the style with the given number of bytes per line.
+    ///
+    /// # Panics
+    /// Panics if `group == 0`.
+    pub const fn group(mut self, group: usize) -> Self {
+        assert!(group > 0, "Group number needs to be greater than zero");
+        self.group = group;
+        self
+    }
+
+    /// Returns the style with the given line limit (`None` prints all lines).
+    pub const fn max_lines(mut self, max_lines: Option<usize>) -> Self {
+        self.max_lines = max_lines;
+        self
+    }
+
+    /// Returns the style with uppercase or lowercase hex digits.
+    pub const fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Returns the style with or without the ASCII gutter.
+    pub const fn ascii_gutter(mut self, ascii_gutter: bool) -> Self {
+        self.ascii_gutter = ascii_gutter;
+        self
+    }
+
+    /// Returns the style with the given separator between bytes.
+    pub const fn byte_separator(mut self, separator: &'static str) -> Self {
+        self.byte_separator = separator;
+        self
+    }
+
+    /// Returns the style with the given separator between lines.
+    pub const fn line_separator(mut self, separator: &'static str) -> Self {
+        self.line_separator = separator;
+        self
+    }
+
+    /// Returns the style with the given byte and line separators; pass `""` for both
+    /// to write all digits back to back.
+    pub const fn separators(self, byte: &'static str, line: &'static str) -> Self {
+        self.byte_separator(byte).line_separator(line)
+    }
+}
+
+impl Default for HexStyle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The style installed with [`set_default_hex_style`]; null selects `HexStyle::DEFAULT`.
+#[cfg(target_has_atomic = "ptr")]
+static DEFAULT_HEX_STYLE: AtomicPtr<HexStyle> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Installs the crate‑wide hex style used by the `Debug` fallbacks for invalid UTF‑8 and
+/// by [`fast_format_hex`] and [`dump_as_hex`].
+///
+/// Call it once at startup so that every dump in a program follows the same format.
+/// Passing `&HexStyle::DEFAULT` restores the built‑in style. Only available on targets
+/// with pointer‑sized atomics.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{default_hex_style, set_default_hex_style, HexStyle};
+///
+/// static STYLE: HexStyle = HexStyle::DEFAULT.uppercase(false).ascii_gutter(true);
+/// set_default_hex_style(&STYLE);
+/// assert_eq!(default_hex_style(), STYLE);
+/// set_default_hex_style(&HexStyle::DEFAULT);
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+pub fn set_default_hex_style(style: &'static HexStyle) {
+    DEFAULT_HEX_STYLE.store(style as *const HexStyle as *mut HexStyle, Ordering::Release);
+}
+
+/// Returns the crate‑wide hex style installed with `set_default_hex_style`, or
+/// `HexStyle::DEFAULT` if none was installed.
+#[cfg(target_has_atomic = "ptr")]
+pub fn default_hex_style() -> HexStyle {
+    let ptr = DEFAULT_HEX_STYLE.load(Ordering::Acquire);
+    if ptr.is_null() {
+        return HexStyle::DEFAULT;
+    }
+    // SAFETY: Every non-null value stored in `DEFAULT_HEX_STYLE` came from a `&'static HexStyle`.
+    unsafe { *ptr }
+}
+
+/// Returns `HexStyle::DEFAULT`, as the style cannot be changed without pointer‑sized atomics.
+#[cfg(not(target_has_atomic = "ptr"))]
+pub const fn default_hex_style() -> HexStyle {
+    HexStyle::DEFAULT
+}
+
+/// Walks `bytes` in the layout of `style`, passing each output token to `emit`.
+/// Stops early as soon as `emit` returns `false`.
+fn write_hex_lines(bytes: &[u8], style: &HexStyle, mut emit: impl FnMut(&[u8]) -> bool) {
+    assert!(
+        style.group > 0,
+        "Group number needs to be greater than zero"
+    );
+    for (line, chunk) in bytes.chunks(style.group).enumerate() {
+        if let Some(max) = style.max_lines {
+            // The first line is always written.
+            if line > 0 && line >= max {
+                return;
+            }
+        }
+        if line > 0 && !emit(style.line_separator.as_bytes()) {
+            return;
+        }
+        for (i, &b) in chunk.iter().enumerate() {
+            if i > 0 && !emit(style.byte_separator.as_bytes()) {
+                return;
+            }
+            let mut pair = HEX_TABLE[b as usize];
+            if !style.uppercase {
+                pair = [pair[0].to_ascii_lowercase(), pair[1].to_ascii_lowercase()];
+            }
+            if !emit(&pair) {
+                return;
+            }
+        }
+        if style.ascii_gutter {
+            for _ in chunk.len()..style.group {
+                if !emit(style.byte_separator.as_bytes()) || !emit(b"  ") {
+                    return;
+                }
+            }
+            if !emit(b"  |") {
+                return;
+            }
+            for &b in chunk {
+                let c = if b.is_ascii_graphic() || b == b' ' {
+                    b
+                } else {
+                    b'.'
+                };
+                if !emit(&[c]) {
+                    return;
+                }
+            }
+            if !emit(b"|") {
+                return;
+            }
+        }
+    }
+}
+
+/// Formats the given byte slice as a hexadecimal string in the crate‑wide default style
+/// (uppercase unless changed with `set_default_hex_style`), grouping bytes as specified and
+/// inserting spaces and newlines accordingly, then returns a `FixedStr` containing the
+/// formatted output.
 /// Any unused space in the output buffer is zero‑padded.
 ///
+/// This is a shorthand for [`fast_format_hex_styled`] with the crate‑wide
+/// [default style](default_hex_style).
+///
 /// # Parameters
 /// - `bytes`: The input byte slice to format.
 /// - `group`: The number of bytes per group. A newline is inserted when a group is complete.
@@ -211,110 +608,294 @@ pub fn fast_format_hex<const N: usize>(
     group: usize,
     max_lines: Option<usize>,
 ) -> crate::FixedStr<N> {
-    assert!(group > 0, "Group number needs to be greater than zero");
+    fast_format_hex_styled(
+        bytes,
+        &HexStyle {
+            group,
+            max_lines,
+            ..default_hex_style()
+        },
+    )
+}
+
+/// Formats the given byte slice as hexadecimal in the layout of `style`
+/// and returns a `FixedStr` containing the formatted output.
+/// If the output does not fit, it ends in `...`. Any unused space is zero‑padded.
+///
+/// # Panics
+/// Panics if `style.group == 0`.
+pub fn fast_format_hex_styled<const N: usize>(
+    bytes: &[u8],
+    style: &HexStyle,
+) -> crate::FixedStr<N> {
+    collect_tokens(|emit| write_hex_lines(bytes, style, emit))
+}
+
+/// Collects the tokens produced by `walk` into a `FixedStr`, ending in `...` if they do not fit.
+fn collect_tokens<const N: usize>(
+    walk: impl FnOnce(&mut dyn FnMut(&[u8]) -> bool),
+) -> crate::FixedStr<N> {
     let mut buffer = [0u8; N];
     let mut pos = 0;
-    let mut count_in_line = 0;
     let mut truncated = false;
 
-    // Start with the first line.
-    let mut line_count = 1;
-
-    for (i, &b) in bytes.iter().enumerate() {
-        if i > 0 {
-            if count_in_line == group {
-                // If a line limit is set and reached, break out.
-                if let Some(max) = max_lines {
-                    if line_count >= max {
-                        break;
-                    }
-                }
-                if pos < N {
-                    buffer[pos] = b'\n';
-                    pos += 1;
-                } else {
-                    truncated = true;
-                    break;
-                }
-                count_in_line = 0;
-                line_count += 1;
-            } else if pos < N {
-                buffer[pos] = b' ';
-                pos += 1;
-            } else {
-                truncated = true;
-                break;
-            }
-        }
-
-        // Write two hex digits for the current byte using the lookup table.
-        if pos + 1 < N {
-            let pair = HEX_TABLE[b as usize];
-            buffer[pos] = pair[0];
-            buffer[pos + 1] = pair[1];
-            pos += 2;
+    walk(&mut |token| {
+        if pos + token.len() <= N {
+            buffer[pos..pos + token.len()].copy_from_slice(token);
+            pos += token.len();
+            true
         } else {
             truncated = true;
-            break;
+            false
         }
-        count_in_line += 1;
-    }
+    });
 
     if truncated && pos >= 3 {
-        pos = pos.saturating_sub(3);
-        if pos + 3 <= N {
-            buffer[pos] = b'.';
-            buffer[pos + 1] = b'.';
-            buffer[pos + 2] = b'.';
-            pos += 3;
-        }
+        pos -= 3;
+        buffer[pos..pos + 3].copy_from_slice(b"...");
+        pos += 3;
     }
 
     buffer[pos..N].fill(0);
 
     // Safe due to controlled construction.
-    crate::FixedStrBuf { buffer, len: pos }.finalize()
+    crate::FixedStrBuf::from_raw_parts(buffer, pos).finalize()
 }
 
 /// Outputs the full hexadecimal representation of `bytes` by invoking the provided callback
 /// for each output byte.
 ///
+/// This is a shorthand for [`dump_as_hex_styled`] with the crate‑wide
+/// [default style](default_hex_style).
+///
 /// # Parameters
 /// - `bytes`: The input byte slice to format.
 /// - `group`: The number of bytes per group. A newline is inserted after each complete group.
 /// - `max_lines`: An optional limit to the number of output lines. If `None`, all lines are output.
 /// - `write`: A callback function that receives each output byte (for example, to write to a console).
-pub fn dump_as_hex(
-    bytes: &[u8],
-    group: usize,
-    max_lines: Option<usize>,
-    mut write: impl FnMut(u8),
-) {
-    assert!(group > 0, "Group number needs to be greater than zero");
-    let mut count_in_line = 0;
-    let mut line_count = 1;
-    for (i, &b) in bytes.iter().enumerate() {
-        if i > 0 {
-            if count_in_line == group {
-                if let Some(max) = max_lines {
-                    if line_count >= max {
-                        break;
-                    }
+pub fn dump_as_hex(bytes: &[u8], group: usize, max_lines: Option<usize>, write: impl FnMut(u8)) {
+    dump_as_hex_styled(
+        bytes,
+        &HexStyle {
+            group,
+            max_lines,
+            ..default_hex_style()
+        },
+        write,
+    )
+}
+
+/// Outputs the hexadecimal representation of `bytes` in the layout of `style`
+/// by invoking the provided callback for each output byte.
+///
+/// # Panics
+/// Panics if `style.group == 0`.
+pub fn dump_as_hex_styled(bytes: &[u8], style: &HexStyle, mut write: impl FnMut(u8)) {
+    write_hex_lines(bytes, style, |token| {
+        token.iter().for_each(|&b| write(b));
+        true
+    });
+}
+
+/// The number of bytes on each line of [`hexdump`] output.
+const HEXDUMP_WIDTH: usize = 16;
+
+/// Walks `bytes` in `xxd` layout, passing each output token to `emit`.
+/// Stops early as soon as `emit` returns `false`.
+fn write_hexdump_lines(bytes: &[u8], emit: &mut dyn FnMut(&[u8]) -> bool) {
+    for (line, chunk) in bytes.chunks(HEXDUMP_WIDTH).enumerate() {
+        if line > 0 && !emit(b"\n") {
+            return;
+        }
+        let offset = line * HEXDUMP_WIDTH;
+        // At least eight digits, more for offsets past 4 GiB.
+        let digits = usize::max(8, (usize::BITS - offset.leading_zeros() + 3) as usize / 4);
+        for shift in (0..digits).rev() {
+            let digit = HEX_TABLE[(offset >> (shift * 4)) & 0xF][1].to_ascii_lowercase();
+            if !emit(&[digit]) {
+                return;
+            }
+        }
+        if !emit(b": ") {
+            return;
+        }
+        for i in 0..HEXDUMP_WIDTH {
+            // Bytes are shown in pairs, like `xxd`.
+            if i > 0 && i % 2 == 0 && !emit(b" ") {
+                return;
+            }
+            let pair = match chunk.get(i) {
+                Some(&b) => {
+                    let pair = HEX_TABLE[b as usize];
+                    [pair[0].to_ascii_lowercase(), pair[1].to_ascii_lowercase()]
                 }
-                write(b'\n');
-                count_in_line = 0;
-                line_count += 1;
+                None => *b"  ",
+            };
+            if !emit(&pair) {
+                return;
+            }
+        }
+        if !emit(b"  ") {
+            return;
+        }
+        for &b in chunk {
+            let c = if b.is_ascii_graphic() || b == b' ' {
+                b
             } else {
-                write(b' ');
+                b'.'
+            };
+            if !emit(&[c]) {
+                return;
             }
         }
-        let pair = HEX_TABLE[b as usize];
-        write(pair[0]);
-        write(pair[1]);
-        count_in_line += 1;
     }
 }
 
+/// Formats `bytes` as a classic `xxd`‑style hexdump and returns it as a `FixedStr`.
+///
+/// Each line shows the offset, 16 bytes in lowercase hex pairs, and the bytes as printable
+/// ASCII (`.` for the others). Lines are separated by newlines, without a trailing one.
+/// If the output does not fit, it ends in `...`. Any unused space is zero‑padded.
+///
+/// # Examples
+/// ```
+/// use fixed_str::hexdump;
+///
+/// let dump = hexdump::<128>(b"Hello, world!\n\xFF");
+/// assert_eq!(
+///     dump.as_str(),
+///     "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a ff    Hello, world!.."
+/// );
+/// ```
+pub fn hexdump<const N: usize>(bytes: &[u8]) -> crate::FixedStr<N> {
+    collect_tokens(|emit| write_hexdump_lines(bytes, emit))
+}
+
+/// Outputs the `xxd`‑style hexdump of `bytes` (see [`hexdump`]) by invoking the provided
+/// callback for each output byte, without any length limit.
+pub fn hexdump_to(bytes: &[u8], mut write: impl FnMut(u8)) {
+    write_hexdump_lines(bytes, &mut |token| {
+        token.iter().for_each(|&b| write(b));
+        true
+    });
+}
+
+/// Writes `bytes` to a formatter as back‑to‑back hex digits, prefixed with `0x` for `{:#x}`.
+/// Used by the `LowerHex`/`UpperHex` implementations.
+pub(crate) fn fmt_hex_bytes(
+    bytes: &[u8],
+    f: &mut core::fmt::Formatter<'_>,
+    uppercase: bool,
+) -> core::fmt::Result {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+    for &b in bytes {
+        let mut pair = HEX_TABLE[b as usize];
+        if !uppercase {
+            pair = [pair[0].to_ascii_lowercase(), pair[1].to_ascii_lowercase()];
+        }
+        // Both digits are ASCII.
+        f.write_str(core::str::from_utf8(&pair).unwrap_or("??"))?;
+    }
+    Ok(())
+}
+
+/// The number of padding bytes previewed by the alternate `Debug` output.
+const PADDING_PREVIEW: usize = 16;
+
+/// Writes the alternate (`{:#?}`) `Debug` output shared by `FixedStr` and `FixedStrBuf`:
+/// the capacity, the effective length, the string, and a hex preview of the bytes after it.
+/// The preview always uses [`HexStyle::DEFAULT`], whatever the crate‑wide default style.
+pub(crate) fn debug_layout(
+    f: &mut core::fmt::Formatter<'_>,
+    name: &str,
+    buffer: &[u8],
+    len: usize,
+) -> core::fmt::Result {
+    let tail = &buffer[len..];
+    let shown = tail.len().min(PADDING_PREVIEW);
+    let preview = fast_format_hex_styled::<{ PADDING_PREVIEW * 3 }>(
+        &tail[..shown],
+        &HexStyle::DEFAULT.group(PADDING_PREVIEW),
+    );
+    let more = if tail.len() > shown { " ..." } else { "" };
+    let mut debug = f.debug_struct(name);
+    debug.field("capacity", &buffer.len()).field("len", &len);
+    match core::str::from_utf8(&buffer[..len]) {
+        Ok(s) => debug.field("value", &s),
+        Err(_) => debug.field("value", &format_args!("<invalid UTF-8>")),
+    };
+    debug
+        .field("padding", &format_args!("[{}{}]", preview, more))
+        .finish()
+}
+
+/// Returns the value of an ASCII hex digit.
+fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes pairs of hex digits from `hex` into `out`; `hex` must be twice as long as `out`.
+pub(crate) fn decode_hex_into(hex: &[u8], out: &mut [u8]) -> Result<(), crate::FixedStrError> {
+    debug_assert_eq!(hex.len(), out.len() * 2);
+    for (i, (slot, pair)) in out.iter_mut().zip(hex.chunks_exact(2)).enumerate() {
+        let high =
+            hex_value(pair[0]).ok_or(crate::FixedStrError::InvalidHex { position: 2 * i })?;
+        let low = hex_value(pair[1]).ok_or(crate::FixedStrError::InvalidHex {
+            position: 2 * i + 1,
+        })?;
+        *slot = high << 4 | low;
+    }
+    Ok(())
+}
+
+/// Parses a string of exactly `2 * M` hex digits (in either case) into `M` bytes.
+///
+/// This is the reverse of the hex formatting helpers for compact, separator‑free input.
+///
+/// # Errors
+/// - Returns `FixedStrError::Overflow` if the input has more than `2 * M` digits.
+/// - Returns `FixedStrError::InvalidHex` at the first character that is not a hex digit,
+///   or at the end of the input if it is too short.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{parse_hex, FixedStrError};
+///
+/// assert_eq!(parse_hex::<4>("DEADbeef"), Ok([0xDE, 0xAD, 0xBE, 0xEF]));
+/// assert_eq!(
+///     parse_hex::<2>("12x4"),
+///     Err(FixedStrError::InvalidHex { position: 2 })
+/// );
+/// ```
+pub fn parse_hex<const M: usize>(input: &str) -> Result<[u8; M], crate::FixedStrError> {
+    let hex = input.as_bytes();
+    if hex.len() > 2 * M {
+        return Err(crate::FixedStrError::Overflow {
+            available: 2 * M,
+            found: hex.len(),
+        });
+    }
+    let mut out = [0u8; M];
+    // Report a bad digit before the missing ones.
+    let complete = hex.len() / 2;
+    decode_hex_into(&hex[..complete * 2], &mut out[..complete])?;
+    if hex.len() < 2 * M {
+        let position = match hex.last() {
+            Some(&digit) if hex.len() % 2 == 1 && hex_value(digit).is_none() => hex.len() - 1,
+            _ => hex.len(),
+        };
+        return Err(crate::FixedStrError::InvalidHex { position });
+    }
+    Ok(out)
+}
+
 //******************************************************************************
 //  Tests
 //******************************************************************************
@@ -365,7 +946,7 @@ mod helper_tests {
         let bytes = [0x12, 0xAB, 0x00, 0xFF];
         let hex = fast_format_hex::<32>(&bytes, 2, None);
         // Expected output: "12 AB\n00 FF"
-        assert_eq!(hex, "12 AB\n00 FF");
+        assert_eq!(hex.as_str(), "12 AB\n00 FF");
     }
 
     #[test]
@@ -377,9 +958,166 @@ mod helper_tests {
         // Line 1: "FF FF FF"
         // Line 2: "FF FF FF"
         // The formatter stops before processing further groups.
-        assert_eq!(hex, "FF FF FF\nFF FF FF");
+        assert_eq!(hex.as_str(), "FF FF FF\nFF FF FF");
+    }
+
+    #[test]
+    fn test_fast_format_hex_styled() {
+        let style = HexStyle::DEFAULT
+            .group(4)
+            .uppercase(false)
+            .ascii_gutter(true);
+        let hex = fast_format_hex_styled::<64>(b"ab\x00\xFFxyz", &style);
+        assert_eq!(hex.as_str(), "61 62 00 ff  |ab..|\n78 79 7a     |xyz|");
+
+        let hex = fast_format_hex_styled::<64>(&[0xAB; 6], &style.max_lines(Some(1)));
+        assert_eq!(hex.as_str(), "ab ab ab ab  |....|");
+
+        // Truncated output ends in an ellipsis.
+        let hex = fast_format_hex_styled::<10>(&[0xAB; 8], &HexStyle::DEFAULT.max_lines(None));
+        assert_eq!(hex.as_str(), "AB AB ...");
+        assert_eq!(HexStyle::default(), HexStyle::DEFAULT);
+    }
+
+    #[test]
+    fn test_fast_format_hex_separators() {
+        let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+        let compact = fast_format_hex_styled::<16>(&[0xAB; 40], &HexStyle::COMPACT.group(4));
+        assert_eq!(compact.as_str(), "ababababababa...");
+
+        let style = HexStyle::DEFAULT.group(2).separators(":", " | ");
+        assert_eq!(
+            fast_format_hex_styled::<32>(&bytes, &style).as_str(),
+            "DE:AD | BE:EF"
+        );
+
+        let style = style.ascii_gutter(true);
+        assert_eq!(
+            fast_format_hex_styled::<64>(&bytes[..3], &style).as_str(),
+            "DE:AD  |..| | BE:    |.|"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_dump_as_hex_styled() {
+        let mut output = Vec::new();
+        let style = HexStyle::DEFAULT.group(2).ascii_gutter(true);
+        dump_as_hex_styled(b"A\x01B", &style, |b| output.push(b));
+        assert_eq!(output, b"41 01  |A.|\n42     |B|");
+    }
+
+    #[test]
+    fn test_hexdump() {
+        let bytes: [u8; 20] = core::array::from_fn(|i| i as u8 + 0x3C);
+        let dump = hexdump::<256>(&bytes);
+        assert_eq!(
+            dump.as_str(),
+            "00000000: 3c3d 3e3f 4041 4243 4445 4647 4849 4a4b  <=>?@ABCDEFGHIJK\n\
+             00000010: 4c4d 4e4f                                LMNO"
+        );
+        assert_eq!(hexdump::<16>(&bytes).as_str(), "00000000: 3c...");
+        assert_eq!(hexdump::<16>(&[]).as_str(), "");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hexdump_to() {
+        let mut output = Vec::new();
+        hexdump_to(&[0u8; 33], |b| output.push(b));
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text.lines().count(), 3);
+        assert!(text.ends_with("00000020: 00                                       ."));
+    }
+
+    #[test]
+    fn test_find_first_null_const() {
+        for bytes in [&b""[..], b"\0", b"abc", b"ab\0c\0"] {
+            assert_eq!(find_first_null_const(bytes), find_first_null(bytes));
+        }
+    }
+
+    #[test]
+    fn test_reverse_search() {
+        assert_eq!(rfind_byte(b"a,b,c", b','), Some(3));
+        assert_eq!(rfind_byte(b"", b','), None);
+        assert_eq!(last_non_pad(b"ab\xFF\xFF", 0xFF), Some(1));
+        assert_eq!(last_non_pad(b"", b' '), None);
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex::<3>("00aFff"), Ok([0x00, 0xAF, 0xFF]));
+        assert_eq!(
+            parse_hex::<1>("abc"),
+            Err(crate::FixedStrError::Overflow {
+                available: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            parse_hex::<2>("ab"),
+            Err(crate::FixedStrError::InvalidHex { position: 2 })
+        );
+        assert_eq!(
+            parse_hex::<2>("abg"),
+            Err(crate::FixedStrError::InvalidHex { position: 2 })
+        );
+        assert_eq!(
+            parse_hex::<2>("a "),
+            Err(crate::FixedStrError::InvalidHex { position: 1 })
+        );
+    }
+
+    #[test]
+    fn test_ascii_case_fold_table() {
+        let mut table = [
+            crate::FixedStr::<5>::new("ABCDE"),
+            crate::FixedStr::<5>::new("@[`{Z"),
+            crate::FixedStr::<5>::new("ÄbC"),
+        ];
+        ascii_case_fold_table(&mut table);
+        assert_eq!(table[0].as_str(), "abcde");
+        // Bytes next to the letter ranges are not touched.
+        assert_eq!(table[1].as_str(), "@[`{z");
+        assert_eq!(table[2].as_str(), "Äbc");
+
+        // Every byte value folds exactly like `u8::to_ascii_lowercase`.
+        let mut all = [crate::FixedStr::<8>::default(); 32];
+        for (i, fixed) in all.iter_mut().enumerate() {
+            for (j, b) in fixed.as_mut_bytes().iter_mut().enumerate() {
+                *b = (i * 8 + j) as u8;
+            }
+        }
+        ascii_case_fold_table(&mut all);
+        for (i, fixed) in all.iter().enumerate() {
+            for (j, &b) in fixed.as_bytes().iter().enumerate() {
+                assert_eq!(b, ((i * 8 + j) as u8).to_ascii_lowercase());
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_padding_table() {
+        let mut table = [
+            crate::FixedStr::<5>::from_bytes_unsafe(*b"ab   "),
+            crate::FixedStr::<5>::from_bytes_unsafe(*b" a b "),
+            crate::FixedStr::<5>::from_bytes_unsafe(*b"     "),
+        ];
+        convert_padding_table(&mut table, b' ', 0);
+        assert_eq!(table[0].as_bytes(), b"ab\0\0\0");
+        // Inner and leading spaces are kept.
+        assert_eq!(table[1].as_bytes(), b" a b\0");
+        assert_eq!(table[2].as_bytes(), b"\0\0\0\0\0");
+        assert!(table[2].is_empty());
+
+        convert_padding_table(&mut table, 0, 0xFF);
+        assert_eq!(table[1].as_bytes(), b" a b\xFF");
+        assert!(table[2].is_sentinel());
     }
 
+    // With `no_panic`, `N == 0` does not compile instead.
+    #[cfg(not(feature = "no_panic"))]
     #[test]
     #[should_panic]
     fn test_panic_on_zero() {
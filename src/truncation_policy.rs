@@ -0,0 +1,337 @@
+// fixed_str/src/truncation_policy.rs
+
+use super::*;
+
+/// Decides what happens when a string does not fit into a `FixedStr`.
+///
+/// Pass a policy to [`FixedStr::new_with_policy`], or, with the `truncation_policy` feature,
+/// install it crate‑wide with [`set_truncation_policy`] so that the lossy constructors
+/// ([`FixedStr::new`], [`FixedStr::set_lossy`] and the `From` conversions) follow it.
+#[derive(Debug, Clone, Copy)]
+pub enum TruncationPolicy {
+    /// Rejects the input. Lossy constructors panic when this policy is installed globally.
+    Error,
+    /// Truncates at the last valid UTF‑8 boundary (the default).
+    Silent,
+    /// Truncates and ends the string with `…` to mark the loss.
+    Ellipsis,
+    /// Calls the function with the input and the capacity, then truncates silently.
+    Callback(fn(&str, usize)),
+}
+
+impl Default for TruncationPolicy {
+    fn default() -> Self {
+        Self::Silent
+    }
+}
+
+/// A [`TruncationPolicy`] chosen through a type parameter, for [`FixedStr::new_with_policy_param`].
+///
+/// Built‑in parameters are [`ErrorPolicy`], [`SilentPolicy`] and [`EllipsisPolicy`]. A
+/// callback policy is a unit struct whose `POLICY` is a `TruncationPolicy::Callback`.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, TruncationPolicy, TruncationPolicyParam};
+///
+/// struct Logged;
+///
+/// impl TruncationPolicyParam for Logged {
+///     const POLICY: TruncationPolicy =
+///         TruncationPolicy::Callback(|input, capacity| eprintln!("{input:?} exceeds {capacity}"));
+/// }
+///
+/// let fixed = FixedStr::<4>::new_with_policy_param::<Logged>("truncated").unwrap();
+/// assert_eq!(fixed.as_str(), "trun");
+/// ```
+pub trait TruncationPolicyParam {
+    /// The policy applied to overlong input.
+    const POLICY: TruncationPolicy;
+}
+
+/// Implements a built‑in type‑level truncation policy.
+macro_rules! policy_param {
+    ($(#[$doc:meta])* $name:ident, $policy:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name;
+
+        impl TruncationPolicyParam for $name {
+            const POLICY: TruncationPolicy = $policy;
+        }
+    };
+}
+
+policy_param!(
+    /// Rejects overlong input, as [`TruncationPolicy::Error`].
+    ErrorPolicy,
+    TruncationPolicy::Error
+);
+
+policy_param!(
+    /// Truncates at the last valid UTF‑8 boundary, as [`TruncationPolicy::Silent`].
+    SilentPolicy,
+    TruncationPolicy::Silent
+);
+
+policy_param!(
+    /// Truncates and ends the string with `…`, as [`TruncationPolicy::Ellipsis`].
+    EllipsisPolicy,
+    TruncationPolicy::Ellipsis
+);
+
+impl<const N: usize> FixedStr<N> {
+    /// Creates a new `FixedStr`, handling overlong input according to `policy`.
+    ///
+    /// Input that fits is stored as with [`FixedStr::new`]. The globally installed policy
+    /// is not consulted.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the input does not fit and `policy` is
+    /// [`TruncationPolicy::Error`].
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, TruncationPolicy};
+    ///
+    /// let fixed = FixedStr::<8>::new_with_policy("truncated", TruncationPolicy::Ellipsis);
+    /// assert_eq!(fixed.unwrap().as_str(), "trunc…");
+    /// assert!(FixedStr::<8>::new_with_policy("truncated", TruncationPolicy::Error).is_err());
+    /// ```
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn new_with_policy(input: &str, policy: TruncationPolicy) -> Result<Self, FixedStrError> {
+        require_capacity::<N>();
+        Self::store_with_policy(input, policy)
+    }
+
+    /// Creates a new `FixedStr`, handling overlong input according to the policy parameter `P`.
+    ///
+    /// This fixes the policy in the type system, e.g. in a type alias or a generic wrapper,
+    /// without consulting the globally installed policy.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the input does not fit and `P` is [`ErrorPolicy`].
+    ///
+    /// # Panics
+    /// Panics if `N == 0`. Zero‑length strings are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{EllipsisPolicy, ErrorPolicy, FixedStr};
+    ///
+    /// let fixed = FixedStr::<8>::new_with_policy_param::<EllipsisPolicy>("truncated");
+    /// assert_eq!(fixed.unwrap().as_str(), "trunc…");
+    /// assert!(FixedStr::<8>::new_with_policy_param::<ErrorPolicy>("truncated").is_err());
+    /// ```
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub fn new_with_policy_param<P: TruncationPolicyParam>(
+        input: &str,
+    ) -> Result<Self, FixedStrError> {
+        Self::new_with_policy(input, P::POLICY)
+    }
+
+    /// [`FixedStr::new_with_policy`] without the capacity check, for the `checked_*` constructors.
+    #[cfg_attr(feature = "debug_lossy", track_caller)]
+    pub(crate) fn store_with_policy(
+        input: &str,
+        policy: TruncationPolicy,
+    ) -> Result<Self, FixedStrError> {
+        const ELLIPSIS: &str = "…";
+        let bytes = input.effective_bytes();
+        if bytes.len() <= N {
+            let data = copy_into_array(bytes, BufferCopyMode::Exact)?;
+            return Ok(Self { data });
+        }
+        match policy {
+            TruncationPolicy::Error => Err(FixedStrError::Overflow {
+                available: N,
+                found: bytes.len(),
+            }),
+            TruncationPolicy::Ellipsis if N >= ELLIPSIS.len() => {
+                let keep = find_valid_utf8_len(bytes, N - ELLIPSIS.len());
+                let mut data = [0u8; N];
+                data[..keep].copy_from_slice(&bytes[..keep]);
+                data[keep..keep + ELLIPSIS.len()].copy_from_slice(ELLIPSIS.as_bytes());
+                Ok(Self { data })
+            }
+            TruncationPolicy::Callback(callback) => {
+                callback(input, N);
+                let data = copy_into_array(bytes, BufferCopyMode::Truncate)?;
+                Ok(Self { data })
+            }
+            TruncationPolicy::Silent | TruncationPolicy::Ellipsis => {
+                let data = copy_into_array(bytes, BufferCopyMode::Truncate)?;
+                Ok(Self { data })
+            }
+        }
+    }
+}
+
+//******************************************************************************
+//  Global Policy
+//******************************************************************************
+
+#[cfg(all(
+    feature = "truncation_policy",
+    target_has_atomic = "8",
+    target_has_atomic = "ptr"
+))]
+pub use atomic_policy::{set_truncation_policy, truncation_policy};
+
+/// The installed policy, stored in atomics.
+#[cfg(all(
+    feature = "truncation_policy",
+    target_has_atomic = "8",
+    target_has_atomic = "ptr"
+))]
+mod atomic_policy {
+    use super::*;
+    use core::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+
+    const KIND_SILENT: u8 = 0;
+    const KIND_ERROR: u8 = 1;
+    const KIND_ELLIPSIS: u8 = 2;
+    const KIND_CALLBACK: u8 = 3;
+
+    /// The kind of the installed policy.
+    static KIND: AtomicU8 = AtomicU8::new(KIND_SILENT);
+
+    /// The callback of an installed `Callback` policy, stored as a type‑erased `fn(&str, usize)`.
+    static CALLBACK: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+    /// Installs the policy followed by the lossy constructors crate‑wide.
+    ///
+    /// Replaces any previously installed policy. Requires the `truncation_policy` feature and a
+    /// target with byte‑ and pointer‑sized atomics.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{set_truncation_policy, FixedStr, TruncationPolicy};
+    ///
+    /// set_truncation_policy(TruncationPolicy::Ellipsis);
+    /// assert_eq!(FixedStr::<8>::new("truncated").as_str(), "trunc…");
+    /// set_truncation_policy(TruncationPolicy::Silent);
+    /// ```
+    pub fn set_truncation_policy(policy: TruncationPolicy) {
+        let kind = match policy {
+            TruncationPolicy::Silent => KIND_SILENT,
+            TruncationPolicy::Error => KIND_ERROR,
+            TruncationPolicy::Ellipsis => KIND_ELLIPSIS,
+            TruncationPolicy::Callback(callback) => {
+                CALLBACK.store(callback as *mut (), Ordering::Release);
+                KIND_CALLBACK
+            }
+        };
+        KIND.store(kind, Ordering::Release);
+    }
+
+    /// Returns the installed policy ([`TruncationPolicy::Silent`] unless changed).
+    ///
+    /// Requires the `truncation_policy` feature.
+    pub fn truncation_policy() -> TruncationPolicy {
+        match KIND.load(Ordering::Acquire) {
+            KIND_ERROR => TruncationPolicy::Error,
+            KIND_ELLIPSIS => TruncationPolicy::Ellipsis,
+            KIND_CALLBACK => {
+                let ptr = CALLBACK.load(Ordering::Acquire);
+                // SAFETY: `CALLBACK` is set to a `fn(&str, usize)` before `KIND_CALLBACK` is stored.
+                TruncationPolicy::Callback(unsafe {
+                    core::mem::transmute::<*mut (), fn(&str, usize)>(ptr)
+                })
+            }
+            _ => TruncationPolicy::Silent,
+        }
+    }
+}
+
+/// Returns [`TruncationPolicy::Silent`], as no policy can be installed without byte‑ and
+/// pointer‑sized atomics.
+///
+/// Requires the `truncation_policy` feature.
+#[cfg(all(
+    feature = "truncation_policy",
+    not(all(target_has_atomic = "8", target_has_atomic = "ptr"))
+))]
+pub const fn truncation_policy() -> TruncationPolicy {
+    TruncationPolicy::Silent
+}
+
+/// Stores `input` following the installed policy, for the lossy constructors.
+///
+/// # Panics
+/// Panics if the input does not fit and the installed policy is [`TruncationPolicy::Error`].
+#[cfg(feature = "truncation_policy")]
+#[cfg_attr(feature = "debug_lossy", track_caller)]
+pub(crate) fn apply_global<const N: usize>(input: &str) -> FixedStr<N> {
+    match FixedStr::new_with_policy(input, truncation_policy()) {
+        Ok(fixed) => fixed,
+        Err(_) => panic!("Input exceeds the FixedStr capacity and the truncation policy is Error"),
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod policy_tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_policy() {
+        let fits = FixedStr::<4>::new_with_policy("abcd", TruncationPolicy::Error);
+        assert_eq!(fits, Ok(FixedStr::new("abcd")));
+        assert_eq!(
+            FixedStr::<4>::new_with_policy("abcde", TruncationPolicy::Error),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 5
+            })
+        );
+        assert_eq!(
+            FixedStr::<4>::new_with_policy("abcde", TruncationPolicy::Silent),
+            Ok(FixedStr::new("abcd"))
+        );
+        // The ellipsis never splits a character.
+        assert_eq!(
+            FixedStr::<5>::new_with_policy("aéééé", TruncationPolicy::Ellipsis),
+            Ok(FixedStr::new("a…"))
+        );
+        // Too small for an ellipsis.
+        assert_eq!(
+            FixedStr::<2>::new_with_policy("abc", TruncationPolicy::Ellipsis),
+            Ok(FixedStr::new("ab"))
+        );
+    }
+
+    #[test]
+    fn test_new_with_policy_callback() {
+        fn check(input: &str, capacity: usize) {
+            assert_eq!((input, capacity), ("too long", 3));
+        }
+        let fixed = FixedStr::<3>::new_with_policy("too long", TruncationPolicy::Callback(check));
+        assert_eq!(fixed, Ok(FixedStr::new("too")));
+    }
+
+    #[test]
+    fn test_new_with_policy_param() {
+        assert_eq!(
+            FixedStr::<4>::new_with_policy_param::<ErrorPolicy>("abcde"),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 5
+            })
+        );
+        assert_eq!(
+            FixedStr::<4>::new_with_policy_param::<SilentPolicy>("abcde"),
+            Ok(FixedStr::new("abcd"))
+        );
+        assert_eq!(
+            FixedStr::<5>::new_with_policy_param::<EllipsisPolicy>("abcdef"),
+            Ok(FixedStr::new("ab…"))
+        );
+    }
+}
@@ -0,0 +1,299 @@
+// fixed_str/src/unicode_ext.rs
+
+//******************************************************************************
+//  unicode-normalization
+//******************************************************************************
+
+#[cfg(feature = "unicode_normalization")]
+mod normalization_ext {
+    use crate::*;
+    use unicode_normalization::UnicodeNormalization;
+
+    /// Encodes `chars` into a new `FixedStr<M>`, failing if they do not fit.
+    fn collect_into<const M: usize>(
+        chars: impl Iterator<Item = char>,
+    ) -> Result<FixedStr<M>, FixedStrError> {
+        require_capacity::<M>();
+        let mut data = [0u8; M];
+        let mut len = 0;
+        let mut fits = true;
+        for c in chars {
+            let end = len + c.len_utf8();
+            if fits && end <= M {
+                c.encode_utf8(&mut data[len..end]);
+            } else {
+                fits = false;
+            }
+            len = end;
+        }
+        if !fits {
+            return Err(FixedStrError::Overflow {
+                available: M,
+                found: len,
+            });
+        }
+        Ok(FixedStr { data })
+    }
+
+    impl<const N: usize> FixedStr<N> {
+        /// Returns a copy of the effective string in Normalization Form C (canonical
+        /// composition), stored in a `FixedStr<M>` (requires the `unicode_normalization`
+        /// feature).
+        ///
+        /// Normalizing both sides makes byte‑wise comparison agree with canonical
+        /// equivalence, so `"é"` precomposed and `"e\u{301}"` compare equal.
+        ///
+        /// # Errors
+        /// Returns `FixedStrError::Overflow` if the normalized string exceeds `M` bytes.
+        ///
+        /// # Panics
+        /// Panics if `M == 0`. Zero‑length strings are not supported.
+        ///
+        /// # Examples
+        /// ```
+        /// use fixed_str::FixedStr;
+        ///
+        /// let decomposed = FixedStr::<8>::new("e\u{301}");
+        /// let composed = decomposed.to_nfc::<8>().unwrap();
+        /// assert_eq!(composed.as_str(), "\u{e9}");
+        /// assert_eq!(composed.len(), 2);
+        /// ```
+        pub fn to_nfc<const M: usize>(&self) -> Result<FixedStr<M>, FixedStrError> {
+            collect_into(self.as_str().nfc())
+        }
+
+        /// Returns a copy of the effective string in Normalization Form D (canonical
+        /// decomposition), stored in a `FixedStr<M>` (requires the `unicode_normalization`
+        /// feature).
+        ///
+        /// Decomposition can grow the string, so `M` may need to exceed `N`.
+        ///
+        /// # Errors
+        /// Returns `FixedStrError::Overflow` if the normalized string exceeds `M` bytes.
+        ///
+        /// # Panics
+        /// Panics if `M == 0`. Zero‑length strings are not supported.
+        ///
+        /// # Examples
+        /// ```
+        /// use fixed_str::FixedStr;
+        ///
+        /// let composed = FixedStr::<2>::new("\u{e9}");
+        /// assert_eq!(composed.to_nfd::<3>().unwrap().as_str(), "e\u{301}");
+        /// assert!(composed.to_nfd::<2>().is_err());
+        /// ```
+        pub fn to_nfd<const M: usize>(&self) -> Result<FixedStr<M>, FixedStrError> {
+            collect_into(self.as_str().nfd())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "unicode_normalization"))]
+mod normalization_tests {
+    use crate::*;
+
+    #[test]
+    fn test_to_nfc() {
+        let a = FixedStr::<16>::new("Cafe\u{301}");
+        let b = FixedStr::<16>::new("Caf\u{e9}");
+        assert_ne!(a, b);
+        assert_eq!(a.to_nfc::<16>(), b.to_nfc::<16>());
+        assert_eq!(a.to_nfc::<5>().unwrap().as_str(), "Caf\u{e9}");
+        // Hangul syllables compose algorithmically.
+        let jamo = FixedStr::<16>::new("\u{1100}\u{1161}");
+        assert_eq!(jamo.to_nfc::<3>().unwrap().as_str(), "\u{ac00}");
+    }
+
+    #[test]
+    fn test_to_nfd() {
+        let s = FixedStr::<8>::new("\u{e9}t\u{e9}");
+        let nfd = s.to_nfd::<8>().unwrap();
+        assert_eq!(nfd.as_str(), "e\u{301}te\u{301}");
+        assert_eq!(nfd.to_nfc::<8>().unwrap().as_str(), s.as_str());
+        assert_eq!(
+            s.to_nfd::<6>(),
+            Err(FixedStrError::Overflow {
+                available: 6,
+                found: 7
+            })
+        );
+    }
+}
+
+//******************************************************************************
+//  unicode-segmentation
+//******************************************************************************
+
+#[cfg(feature = "unicode_segmentation")]
+mod segmentation_ext {
+    use crate::*;
+    use unicode_segmentation::UnicodeSegmentation;
+
+    /// Returns the length of the longest prefix of `s` that ends at an extended grapheme
+    /// cluster boundary and is at most `max_len` bytes.
+    fn grapheme_boundary(s: &str, max_len: usize) -> usize {
+        let mut len = 0;
+        for (start, grapheme) in s.grapheme_indices(true) {
+            let end = start + grapheme.len();
+            if end > max_len {
+                break;
+            }
+            len = end;
+        }
+        len
+    }
+
+    impl<const N: usize> FixedStr<N> {
+        /// Creates a new `FixedStr`, truncating at the last extended grapheme cluster
+        /// boundary that fits (requires the `unicode_segmentation` feature).
+        ///
+        /// Unlike [`FixedStr::new`], this never splits an emoji ZWJ sequence or separates
+        /// a base character from its combining marks; the result may be shorter as a
+        /// consequence. As with `new`, the content ends at the first null byte.
+        ///
+        /// # Panics
+        /// Panics if `N == 0`. Zero‑length strings are not supported.
+        ///
+        /// # Examples
+        /// ```
+        /// use fixed_str::FixedStr;
+        ///
+        /// // "e" followed by a combining acute accent (U+0301).
+        /// let fs = FixedStr::<4>::new_lossy_graphemes("ab\u{65}\u{301}");
+        /// assert_eq!(fs.as_str(), "ab");
+        /// ```
+        #[cfg_attr(feature = "debug_lossy", track_caller)]
+        pub fn new_lossy_graphemes(input: &str) -> Self {
+            require_capacity::<N>();
+            let content = &input[..input.effective_bytes().len()];
+            let len = grapheme_boundary(content, N);
+            #[cfg(feature = "debug_lossy")]
+            crate::lossy_hook::report_if_lossy(N, content.len(), len);
+            let mut data = [0u8; N];
+            write_at(&mut data, 0, head(content.as_bytes(), len));
+            Self { data }
+        }
+
+        /// Shortens the effective string to at most `max_bytes`, cutting at an extended
+        /// grapheme cluster boundary (requires the `unicode_segmentation` feature).
+        ///
+        /// If the string already fits, this method does nothing.
+        pub fn truncate_graphemes(&mut self, max_bytes: usize) {
+            let len = grapheme_boundary(self.as_str(), max_bytes);
+            self.truncate(len);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "unicode_segmentation"))]
+mod segmentation_tests {
+    use crate::*;
+
+    #[test]
+    fn test_new_lossy_graphemes() {
+        // A family emoji: four people joined by zero-width joiners (25 bytes).
+        let family = "👨\u{200D}👩\u{200D}👧\u{200D}👦";
+        assert_eq!(FixedStr::<30>::new_lossy_graphemes(family).as_str(), family);
+        assert_eq!(FixedStr::<24>::new_lossy_graphemes(family).as_str(), "");
+        let input = "ok👨\u{200D}👩\0ignored";
+        assert_eq!(
+            FixedStr::<16>::new_lossy_graphemes(input).as_str(),
+            "ok👨\u{200D}👩"
+        );
+    }
+
+    #[test]
+    fn test_truncate_graphemes() {
+        let mut fs = FixedStr::<16>::new("ne\u{301}e\u{301}");
+        fs.truncate_graphemes(5);
+        assert_eq!(fs.as_str(), "ne\u{301}");
+        assert_eq!(fs.as_bytes()[4..], [0u8; 12]);
+        fs.truncate_graphemes(16);
+        assert_eq!(fs.as_str(), "ne\u{301}");
+    }
+}
+
+//******************************************************************************
+//  unicode-width
+//******************************************************************************
+
+#[cfg(feature = "unicode_width")]
+mod width_ext {
+    use crate::*;
+    use unicode_width::UnicodeWidthStr;
+
+    impl<const N: usize> FixedStr<N> {
+        /// Returns the number of terminal columns the effective string occupies
+        /// (requires the `unicode_width` feature).
+        ///
+        /// Wide characters, such as most CJK ideographs, count as two columns; control and
+        /// zero‑width characters count as none.
+        ///
+        /// # Examples
+        /// ```
+        /// use fixed_str::FixedStr;
+        ///
+        /// assert_eq!(FixedStr::<16>::new("東京").len(), 6);
+        /// assert_eq!(FixedStr::<16>::new("東京").display_width(), 4);
+        /// ```
+        pub fn display_width(&self) -> usize {
+            self.as_str().width()
+        }
+
+        /// Shortens the effective string so that it occupies at most `cols` terminal columns
+        /// (requires the `unicode_width` feature).
+        ///
+        /// A wide character that would straddle the limit is removed entirely, so the result
+        /// may be one column narrower than `cols`. If the string already fits, this method
+        /// does nothing.
+        ///
+        /// # Examples
+        /// ```
+        /// use fixed_str::FixedStr;
+        ///
+        /// let mut fs = FixedStr::<16>::new("東京タワー");
+        /// fs.truncate_to_width(5);
+        /// assert_eq!(fs.as_str(), "東京");
+        /// ```
+        pub fn truncate_to_width(&mut self, cols: usize) {
+            let s = self.as_str();
+            let mut len = 0;
+            for (start, c) in s.char_indices() {
+                let end = start + c.len_utf8();
+                if s[..end].width() > cols {
+                    break;
+                }
+                len = end;
+            }
+            self.truncate(len);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "unicode_width"))]
+mod width_tests {
+    use crate::*;
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(FixedStr::<8>::new("abc").display_width(), 3);
+        assert_eq!(FixedStr::<8>::new("e\u{301}").display_width(), 1);
+        assert_eq!(FixedStr::<8>::new("日本").display_width(), 4);
+    }
+
+    #[test]
+    fn test_truncate_to_width() {
+        let mut fs = FixedStr::<16>::new("ab日本");
+        fs.truncate_to_width(4);
+        assert_eq!(fs.as_str(), "ab日");
+        assert_eq!(fs.as_bytes()[5..], [0u8; 11]);
+        fs.truncate_to_width(3);
+        assert_eq!(fs.as_str(), "ab");
+        fs.truncate_to_width(10);
+        assert_eq!(fs.as_str(), "ab");
+        // Zero-width combining marks stay with their base character.
+        let mut fs = FixedStr::<16>::new("ae\u{301}b");
+        fs.truncate_to_width(2);
+        assert_eq!(fs.as_str(), "ae\u{301}");
+    }
+}
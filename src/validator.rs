@@ -0,0 +1,163 @@
+// fixed_str/src/validator.rs
+
+use super::*;
+
+/// A domain rule that is checked whenever a validated `FixedStr` is constructed.
+///
+/// Validators receive the effective string exactly as it will be stored and either
+/// accept it or reject it with a typed error. Any closure of the form
+/// `Fn(&str) -> Result<(), E>` is also a validator.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, ValidationError};
+///
+/// let no_spaces = |s: &str| if s.contains(' ') { Err("contains a space") } else { Ok(()) };
+///
+/// let ok = FixedStr::<8>::new_validated("abc", &no_spaces).unwrap();
+/// assert_eq!(ok.as_str(), "abc");
+///
+/// let err = FixedStr::<8>::new_validated("a b", &no_spaces).unwrap_err();
+/// assert_eq!(err, ValidationError::Rule("contains a space"));
+/// ```
+pub trait Validator {
+    /// The error produced when the content violates the rule.
+    type Error;
+
+    /// Checks the effective string, returning an error if it is not acceptable.
+    fn validate(&self, s: &str) -> Result<(), Self::Error>;
+}
+
+impl<F, E> Validator for F
+where
+    F: Fn(&str) -> Result<(), E>,
+{
+    type Error = E;
+
+    fn validate(&self, s: &str) -> Result<(), Self::Error> {
+        self(s)
+    }
+}
+
+/// Implements a built‑in validator that rejects the first character failing `$accept`.
+macro_rules! char_validator {
+    ($(#[$doc:meta])* $name:ident, $accept:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name;
+
+        impl Validator for $name {
+            type Error = RejectedChar;
+
+            fn validate(&self, s: &str) -> Result<(), Self::Error> {
+                let accept: fn(char) -> bool = $accept;
+                match s.char_indices().find(|&(_, ch)| !accept(ch)) {
+                    Some((position, ch)) => Err(RejectedChar { position, ch }),
+                    None => Ok(()),
+                }
+            }
+        }
+    };
+}
+
+char_validator!(
+    /// Accepts only ASCII content.
+    AsciiOnly,
+    |ch| ch.is_ascii()
+);
+
+char_validator!(
+    /// Accepts only ASCII letters and digits.
+    AsciiAlphanumeric,
+    |ch| ch.is_ascii_alphanumeric()
+);
+
+char_validator!(
+    /// Accepts only printable ASCII: letters, digits, punctuation and the space.
+    PrintableAscii,
+    |ch| ch == ' ' || ch.is_ascii_graphic()
+);
+
+char_validator!(
+    /// Rejects control characters, such as newlines, tabs and escape sequences.
+    NoControl,
+    |ch| !ch.is_control()
+);
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod validator_tests {
+    use super::*;
+
+    /// A validator restricting content to an inclusive byte-length range.
+    struct LengthRange(usize, usize);
+
+    impl Validator for LengthRange {
+        type Error = usize;
+
+        fn validate(&self, s: &str) -> Result<(), Self::Error> {
+            if (self.0..=self.1).contains(&s.len()) {
+                Ok(())
+            } else {
+                Err(s.len())
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_validated_accepts() {
+        let fixed = FixedStr::<10>::new_validated("Hello", &LengthRange(2, 6)).unwrap();
+        assert_eq!(fixed.as_str(), "Hello");
+    }
+
+    #[test]
+    fn test_new_validated_rule_error() {
+        let result = FixedStr::<10>::new_validated("H", &LengthRange(2, 6));
+        assert_eq!(result, Err(ValidationError::Rule(1)));
+    }
+
+    #[test]
+    fn test_new_validated_overflow() {
+        let result = FixedStr::<4>::new_validated("Hello", &LengthRange(0, 10));
+        assert_eq!(
+            result,
+            Err(ValidationError::Invalid(FixedStrError::Overflow {
+                available: 4,
+                found: 5
+            }))
+        );
+    }
+
+    #[test]
+    fn test_builtin_validators() {
+        assert_eq!(AsciiOnly.validate("abc"), Ok(()));
+        assert_eq!(
+            AsciiOnly.validate("aé"),
+            Err(RejectedChar {
+                position: 1,
+                ch: 'é'
+            })
+        );
+        assert!(AsciiAlphanumeric.validate("a1B2").is_ok());
+        assert!(AsciiAlphanumeric.validate("a-1").is_err());
+        assert!(PrintableAscii.validate("Label 1!").is_ok());
+        assert!(PrintableAscii.validate("a\tb").is_err());
+        assert!(NoControl.validate("naïve").is_ok());
+        assert_eq!(
+            NoControl.validate("a\nb"),
+            Err(RejectedChar {
+                position: 1,
+                ch: '\n'
+            })
+        );
+    }
+
+    #[test]
+    fn test_new_validated_sees_effective_string() {
+        let fixed = FixedStr::<10>::new_validated("Hi\0there", &LengthRange(2, 2)).unwrap();
+        assert_eq!(fixed.as_str(), "Hi");
+    }
+}
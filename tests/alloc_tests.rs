@@ -0,0 +1,104 @@
+// fixed_str/tests/alloc_tests.rs
+
+//! Verifies that the `NoAllocDisplay` implementations never touch the allocator.
+//! Lives in its own test binary so the counting allocator does not affect other tests.
+
+#[cfg(feature = "std")]
+mod alloc_tests {
+    use core::fmt::Write;
+    use fixed_str::*;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// Counts the allocations made on the current thread.
+    struct CountingAlloc;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+
+    /// Collects formatted output in a fixed buffer.
+    struct Sink(FixedStrBuf<64>);
+
+    impl Write for Sink {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0.try_push_str(s).map_err(|_| core::fmt::Error)
+        }
+    }
+
+    /// Formats `value` into a fixed buffer, asserting that no allocation happens.
+    fn format_no_alloc<T: NoAllocDisplay>(value: &T) -> FixedStr<64> {
+        let mut sink = Sink(FixedStrBuf::new());
+        let before = ALLOCATIONS.with(Cell::get);
+        write!(sink, "{}", value).unwrap();
+        let after = ALLOCATIONS.with(Cell::get);
+        assert_eq!(after - before, 0, "formatting allocated");
+        sink.0.finalize()
+    }
+
+    /// Displays a value from within its own `Display` implementation.
+    struct Nested<'a>(&'a FixedStr<8>);
+
+    impl core::fmt::Display for Nested<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "[{}|{}]", self.0, self.0.display_uppercase())
+        }
+    }
+
+    impl NoAllocDisplay for Nested<'_> {}
+
+    #[test]
+    fn test_counting_alloc_detects_allocations() {
+        let before = ALLOCATIONS.with(Cell::get);
+        let heap = format!("{}", FixedStr::<8>::new("heap"));
+        assert!(ALLOCATIONS.with(Cell::get) > before);
+        assert_eq!(heap, "heap");
+    }
+
+    #[test]
+    fn test_display_does_not_allocate() {
+        let fixed = FixedStr::<8>::new("Straße");
+        assert_eq!(format_no_alloc(&fixed).as_str(), "Straße");
+
+        assert_eq!(
+            format_no_alloc(&fixed.display_uppercase()).as_str(),
+            "STRASSE"
+        );
+
+        assert_eq!(
+            format_no_alloc(&fixed.display_lowercase()).as_str(),
+            "straße"
+        );
+
+        let buf = FixedStrBuf::<8>::try_from(b"buffer".as_slice()).unwrap();
+        assert_eq!(format_no_alloc(&buf).as_str(), "buffer");
+
+        let secret = SecretFixedStr::<8>::new("hunter2");
+        assert_eq!(format_no_alloc(&secret).as_str(), "[REDACTED]");
+
+        let err = FixedStrError::Overflow {
+            available: 2,
+            found: 5,
+        };
+        let _ = format_no_alloc(&err);
+
+        assert_eq!(
+            format_no_alloc(&Nested(&fixed)).as_str(),
+            "[Straße|STRASSE]"
+        );
+    }
+}
@@ -0,0 +1,68 @@
+// fixed_str/tests/derive_tests.rs
+
+//! Verifies `#[derive(FixedRecord)]` from outside the crate, as users see it.
+
+#[cfg(feature = "derive")]
+mod derive_tests {
+    use fixed_str::*;
+
+    #[derive(Debug, PartialEq, FixedRecord)]
+    struct Transfer {
+        account: SpacePadded<8>,
+        amount: u32,
+        r#type: FixedStr<2>,
+    }
+
+    #[derive(FixedRecord)]
+    struct Empty {}
+
+    // Verifies the generated offsets and layout.
+    #[test]
+    fn test_layout() {
+        assert_eq!(Transfer::ACCOUNT_OFFSET, 0);
+        assert_eq!(Transfer::AMOUNT_OFFSET, 8);
+        assert_eq!(Transfer::TYPE_OFFSET, 12);
+        assert_eq!(<Transfer as FixedRecord>::WIDTH, 14);
+        assert_eq!(
+            Transfer::FIELDS[1],
+            FieldSpec {
+                offset: 8,
+                width: 4,
+                pad: 0
+            }
+        );
+        assert_eq!(<Empty as FixedRecord>::WIDTH, 0);
+    }
+
+    // Verifies that a derived record round-trips through its bytes.
+    #[test]
+    fn test_round_trip() {
+        let bytes = *b"DE12    \x00\x00\x01\x00CR";
+        let transfer = Transfer::from_bytes(&bytes).unwrap();
+        assert_eq!(transfer.account.as_str(), "DE12");
+        assert_eq!(transfer.amount, 256);
+        assert_eq!(transfer.r#type.as_str(), "CR");
+        assert_eq!(transfer.to_bytes(), bytes);
+        assert_eq!(parse_record::<Transfer, 14>(&bytes), Ok(transfer));
+    }
+
+    // Verifies that field errors report the failing field.
+    #[test]
+    fn test_field_error() {
+        assert_eq!(
+            Transfer::from_bytes(b"DE12    \x00\x00\x01\x00\xFFR"),
+            Err(RecordError::Field {
+                index: 2,
+                offset: 12,
+                error: FixedStrError::InvalidUtf8 { valid_up_to: 0 },
+            })
+        );
+        assert_eq!(
+            Transfer::parse_slice(b"DE12"),
+            Err(RecordError::WrongLength {
+                expected: 14,
+                found: 4
+            })
+        );
+    }
+}
@@ -130,6 +130,20 @@ mod fs_tests {
         assert_eq!(debug_str, "\"Hello\"");
     }
 
+    // Checks that alternate Debug formatting shows the layout, including stray padding bytes.
+    #[test]
+    fn test_debug_format_alternate() {
+        let fixed = FixedStr::<4>::from_bytes_unsafe(*b"ab\0\x7f");
+        assert_eq!(
+            format!("{:#?}", fixed),
+            "FixedStr {\n    capacity: 4,\n    len: 2,\n    value: \"ab\",\n    padding: [00 7F],\n}"
+        );
+
+        let buf = FixedStrBuf::<20>::try_from(&b"x"[..]).unwrap();
+        assert!(format!("{:#?}", buf)
+            .contains("    padding: [00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ...],"));
+    }
+
     // Checks that Display formatting returns the effective string.
     #[test]
     fn test_display() {
@@ -139,6 +153,30 @@ mod fs_tests {
         assert_eq!(display_str, "Hello");
     }
 
+    // Checks that Display honours width, fill, alignment and precision.
+    #[test]
+    fn test_display_flags() {
+        let fixed = FixedStr::<8>::new("héllo");
+        assert_eq!(format!("[{:>8}]", fixed), "[   héllo]");
+        assert_eq!(format!("[{:*^9}]", fixed), "[**héllo**]");
+        assert_eq!(format!("[{:<7.2}]", fixed), "[hé     ]");
+
+        let buf = FixedStrBuf::<8>::try_from(&b"abc"[..]).unwrap();
+        assert_eq!(format!("[{:>5}]", buf), "[  abc]");
+    }
+
+    // Checks that the hex formatting traits print the whole raw buffer.
+    #[test]
+    fn test_hex_format() {
+        let fixed = FixedStr::<4>::new("Hi\u{7f}");
+        assert_eq!(format!("{:x}", fixed), "48697f00");
+        assert_eq!(format!("{:#X}", fixed), "0x48697F00");
+
+        let mut buf = FixedStrBuf::<3>::new();
+        buf.try_push_str("\u{fe}").unwrap();
+        assert_eq!(format!("{:X}", buf), "C3BE00");
+    }
+
     // Tests that the IntoIterator implementation iterates over the effective bytes.
     #[test]
     fn test_into_iter() {
@@ -149,6 +187,7 @@ mod fs_tests {
     }
 
     // Verifies equality comparisons between FixedStr and &str.
+    #[cfg(feature = "cmp_foreign")]
     #[test]
     fn test_equality() {
         const N: usize = 5;
@@ -262,6 +301,261 @@ mod fs_tests {
         assert_eq!(s.as_str(), "Hi");
     }
 
+    // Checks that truncate_middle keeps both ends and respects multi-byte boundaries.
+    #[test]
+    fn test_truncate_middle() {
+        let mut s = FixedStr::<16>::new("abcdefghij");
+        s.truncate_middle(7);
+        assert_eq!(s.as_str(), "ab…ij");
+
+        // Multi-byte characters on either side are never split.
+        let mut s = FixedStr::<16>::new("ééééé");
+        s.truncate_middle(8);
+        assert_eq!(s.as_str(), "é…é");
+        assert!(s.len() <= 8);
+
+        // Fits already: unchanged.
+        let mut s = FixedStr::<16>::new("short");
+        s.truncate_middle(10);
+        assert_eq!(s.as_str(), "short");
+
+        // Too small for the ellipsis: plain truncation.
+        let mut s = FixedStr::<16>::new("abcdef");
+        s.truncate_middle(2);
+        assert_eq!(s.as_str(), "ab");
+    }
+
+    // Checks that eq_bytes_const compares effective bytes and is usable in const contexts.
+    #[test]
+    fn test_eq_bytes_const() {
+        const FIXED: FixedStr<6> = FixedStr::new_const("Hi");
+        const _: () = assert!(FIXED.eq_bytes_const(b"Hi\0xyz"));
+        assert!(!FIXED.eq_bytes_const(b"Hi!\0\0\0"));
+        assert!(!FIXED.eq_bytes_const(b"H\0\0\0\0\0"));
+    }
+
+    // Verifies that const_eq and const_cmp agree with the runtime comparisons.
+    #[test]
+    fn test_const_eq_cmp() {
+        const A: FixedStr<6> = FixedStr::new_const("ab\0xyz");
+        const B: FixedStr<6> = FixedStr::new_const("ab");
+        const C: FixedStr<6> = FixedStr::new_const("abc");
+        const _: () = assert!(A.const_eq(&B) && !B.const_eq(&C));
+        assert_ne!(A, B);
+        for (x, y) in [(A, B), (B, C), (C, A), (C, C)] {
+            assert_eq!(x.const_cmp(&y), x.cmp(&y));
+        }
+    }
+
+    // Verifies that concat_const and fixed_concat! join values at compile time.
+    #[test]
+    fn test_concat_const() {
+        const A: FixedStr<4> = FixedStr::new_const("ab\0x");
+        const B: FixedStr<3> = FixedStr::new_const("cde");
+        const AB: FixedStr<7> = A.concat_const(&B);
+        assert_eq!(AB.as_str(), "abcde");
+        assert_eq!(AB.as_bytes(), b"abcde\0\0");
+        const JOINED: FixedStr<6> = fixed_concat!(6; "ab", "", "cd");
+        assert_eq!(JOINED.as_str(), "abcd");
+        assert_eq!(fixed_concat!(2; "ab").as_str(), "ab");
+    }
+
+    // Verifies that fixed_concat! panics at runtime when the parts do not fit.
+    #[test]
+    #[should_panic(expected = "fixed_concat parts exceed the FixedStr capacity")]
+    fn test_fixed_concat_overflow() {
+        let part = "abc";
+        let _ = fixed_concat!(2; part);
+    }
+
+    // Verifies that sub_const copies compile-time ranges of the raw buffer.
+    #[test]
+    fn test_sub_const() {
+        const HEADER: FixedStr<10> = FixedStr::new_const("MAGIC\0v1");
+        const MAGIC: FixedStr<5> = HEADER.sub_const::<0, 5>();
+        assert_eq!(MAGIC.as_str(), "MAGIC");
+        // Bytes after the first null are still part of the raw buffer.
+        assert_eq!(HEADER.sub_const::<6, 2>().as_str(), "v1");
+        assert_eq!(HEADER.sub_const::<8, 2>().as_bytes(), b"\0\0");
+        assert_eq!(HEADER.sub_const::<0, 10>(), HEADER);
+    }
+
+    // Verifies that the reporting constructor accounts for dropped input.
+    #[test]
+    fn test_new_lossy_report() {
+        let (fixed, info) = FixedStr::<8>::new_lossy_report("short");
+        assert_eq!(fixed.as_str(), "short");
+        assert_eq!(info.cut_at, 5);
+        assert!(!info.is_truncated());
+
+        let (fixed, info) = FixedStr::<5>::new_lossy_report("naïve café\0ignored");
+        assert_eq!(fixed.as_str(), "naïv");
+        assert!(info.is_truncated());
+        assert_eq!(
+            info,
+            TruncationInfo {
+                cut_at: 5,
+                bytes_dropped: 7,
+                chars_dropped: 6
+            }
+        );
+    }
+
+    // Verifies that the reporting setter replaces the content and accounts for dropped input.
+    #[test]
+    fn test_set_lossy_report() {
+        let mut fixed = FixedStr::<4>::new("long");
+        let info = fixed.set_lossy_report("jalapeño");
+        assert_eq!(fixed.as_str(), "jala");
+        assert_eq!(
+            (info.cut_at, info.bytes_dropped, info.chars_dropped),
+            (4, 5, 4)
+        );
+
+        // The cut falls back to the last character boundary and the rest is zeroed.
+        let info = fixed.set_lossy_report("abcñ");
+        assert_eq!(fixed.as_bytes(), b"abc\0");
+        assert_eq!(info.cut_at, 3);
+
+        let info = fixed.set_lossy_report("ok\0dropped");
+        assert_eq!(fixed.as_str(), "ok");
+        assert!(!info.is_truncated());
+    }
+
+    // Verifies hex-encoded content is decoded and validated.
+    #[test]
+    fn test_from_hex_bytes() {
+        let fixed = FixedStr::<4>::from_hex_bytes(b"c3a9").unwrap();
+        assert_eq!(fixed.as_str(), "é");
+        assert_eq!(
+            FixedStr::<4>::from_hex_bytes(b"0102030405"),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 5
+            })
+        );
+        assert_eq!(
+            FixedStr::<4>::from_hex_bytes(b"abc"),
+            Err(FixedStrError::InvalidHex { position: 3 })
+        );
+        assert_eq!(
+            FixedStr::<4>::from_hex_bytes(b"4z"),
+            Err(FixedStrError::InvalidHex { position: 1 })
+        );
+        assert_eq!(
+            FixedStr::<4>::from_hex_bytes(b"ff"),
+            Err(FixedStrError::InvalidUtf8 { valid_up_to: 0 })
+        );
+    }
+
+    // Verifies right-aligned numeric fields are padded to exactly N bytes.
+    #[test]
+    fn test_from_uint_right_aligned() {
+        let field = FixedStr::<6>::from_uint_right_aligned(42, ' ').unwrap();
+        assert_eq!(field.as_str(), "    42");
+        assert_eq!(field.len(), 6);
+
+        let zero = FixedStr::<3>::from_uint_right_aligned(0, '0').unwrap();
+        assert_eq!(zero.as_str(), "000");
+
+        let exact = FixedStr::<3>::from_uint_right_aligned(999, '0').unwrap();
+        assert_eq!(exact.as_str(), "999");
+
+        assert_eq!(
+            FixedStr::<3>::from_uint_right_aligned(1000, '0'),
+            Err(FixedStrError::Overflow {
+                available: 3,
+                found: 4
+            })
+        );
+    }
+
+    // Verifies invalid padding characters are reported as errors instead of panicking.
+    #[test]
+    fn test_from_uint_right_aligned_invalid_pad() {
+        assert_eq!(
+            FixedStr::<4>::from_uint_right_aligned(7, 'é'),
+            Err(FixedStrError::NonAscii { position: 0 })
+        );
+        assert_eq!(
+            FixedStr::<4>::from_uint_right_aligned(7, '\0'),
+            Err(FixedStrError::InteriorNul { position: 0 })
+        );
+    }
+
+    // Verifies bulk conversion reports the index of the first input that does not fit.
+    #[test]
+    fn test_try_from_all() {
+        let table = FixedStr::<5>::try_from_all(&["alpha", "beta", ""]).unwrap();
+        assert_eq!(table[0].as_str(), "alpha");
+        assert_eq!(table[1].as_str(), "beta");
+        assert!(table[2].is_empty());
+
+        assert_eq!(
+            FixedStr::<5>::try_from_all(&["alpha", "beta", "gamma!", "delta!!"]),
+            Err((
+                2,
+                FixedStrError::Overflow {
+                    available: 5,
+                    found: 6
+                }
+            ))
+        );
+        assert_eq!(FixedStr::<5>::try_from_all(&[]), Ok([]));
+    }
+
+    // Verifies single-character construction and the overflow reported for small capacities.
+    #[test]
+    fn test_from_char() {
+        assert_eq!(FixedStr::<4>::from_char('🦀').as_str(), "🦀");
+        assert_eq!(FixedStr::<8>::from_char('x').as_bytes(), b"x\0\0\0\0\0\0\0");
+        assert_eq!(FixedStr::<1>::try_from('a').unwrap().as_str(), "a");
+        assert_eq!(FixedStr::<2>::try_from('é').unwrap().as_str(), "é");
+        assert_eq!(
+            FixedStr::<2>::try_from('€'),
+            Err(FixedStrError::Overflow {
+                available: 2,
+                found: 3
+            })
+        );
+        assert!(FixedStr::<2>::try_from('\0').unwrap().is_empty());
+    }
+
+    // Verifies conversions between FixedStr and byte arrays of the same capacity.
+    #[test]
+    fn test_array_conversions() {
+        let fixed = FixedStr::<4>::try_from(b"ab\0x").unwrap();
+        assert_eq!(fixed.as_str(), "ab");
+        assert_eq!(<[u8; 4]>::from(fixed), *b"ab\0\0");
+        assert_eq!(
+            FixedStr::<2>::try_from(&[b'a', 0xFF]),
+            Err(FixedStrError::InvalidUtf8 { valid_up_to: 1 })
+        );
+        // The raw buffer is returned unchanged.
+        let raw: [u8; 4] = FixedStr::from_bytes_unsafe(*b"a\0bc").into();
+        assert_eq!(raw, *b"a\0bc");
+        #[cfg(feature = "convert_lossy")]
+        assert_eq!(FixedStr::from(*b"ok\xFF").as_str(), "ok");
+    }
+
+    // Verifies a chunked copy matches from_slice and yields between chunks only.
+    #[test]
+    fn test_copy_from_slice_chunked() {
+        let input = "é".repeat(3000);
+        let mut fixed = FixedStr::<4096>::new("old content");
+        let mut yields = 0;
+        fixed.copy_from_slice_chunked(input.as_bytes(), 1024, || yields += 1);
+        assert_eq!(fixed, FixedStr::<4096>::from_slice(input.as_bytes()));
+        assert_eq!(fixed.len(), 4096);
+        assert_eq!(yields, 3);
+
+        let mut fixed = FixedStr::<6>::new("abcdef");
+        let mut yields = 0;
+        fixed.copy_from_slice_chunked(b"xy\0z", 6, || yields += 1);
+        assert_eq!(fixed.as_bytes(), b"xy\0\0\0\0");
+        assert_eq!(yields, 0);
+    }
+
     // Tests conversion of FixedStr into an owned String.
     #[cfg(feature = "std")]
     #[test]
@@ -293,6 +587,22 @@ mod fs_tests {
         assert_eq!(also_valid.try_into_string().unwrap(), "Still");
     }
 
+    // Verifies that maps keyed by FixedStr can be queried with a plain &str.
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_lookup_by_str() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(FixedStr::<8>::new("alpha"), 1);
+        assert_eq!(map.get("alpha"), Some(&1));
+        assert_eq!(map.get("alph"), None);
+
+        let mut index = indexmap::IndexMap::new();
+        index.insert(FixedStr::<8>::new("beta"), 2);
+        index.insert(FixedStr::<8>::new("gamma"), 3);
+        assert_eq!(index.get_index_of("gamma"), Some(1));
+        assert_eq!(index.swap_remove("beta"), Some(2));
+    }
+
     // Tests that a FixedStr can be safely created from a raw byte array via transmute.
     #[cfg(feature = "std")]
     #[test]
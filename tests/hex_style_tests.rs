@@ -0,0 +1,40 @@
+// fixed_str/tests/hex_style_tests.rs
+
+//! Exercises the crate‑wide default `HexStyle`.
+//! Lives in its own test binary so changing the global style does not affect other tests.
+#![cfg(target_has_atomic = "ptr")]
+
+mod hex_style_tests {
+    use fixed_str::*;
+
+    static LOWER: HexStyle = HexStyle::COMPACT.uppercase(false);
+
+    #[test]
+    fn test_global_hex_style() {
+        // A single test, as the style is shared by every thread.
+        let invalid = FixedStr::<4>::from_bytes_unsafe([0xAB, 0xCD, 0xFF, 0x00]);
+        assert_eq!(default_hex_style(), HexStyle::DEFAULT);
+        assert_eq!(format!("{:?}", invalid), "<invalid UTF-8>\n\"AB CD FF 00\"");
+        assert_eq!(
+            fast_format_hex::<16>(&[0xAB, 0xCD], 16, None).as_str(),
+            "AB CD"
+        );
+
+        set_default_hex_style(&LOWER);
+        assert_eq!(default_hex_style(), LOWER);
+        assert_eq!(format!("{:?}", invalid), "<invalid UTF-8>\n\"abcdff00\"");
+        assert_eq!(
+            fast_format_hex::<16>(&[0xAB, 0xCD], 16, None).as_str(),
+            "abcd"
+        );
+        let mut dumped = Vec::new();
+        dump_as_hex(&[0xAB, 0xCD], 16, None, |b| dumped.push(b));
+        assert_eq!(dumped, b"abcd");
+        // The padding preview of the alternate `Debug` output keeps its own style.
+        let padded = FixedStr::<4>::new("ab");
+        assert!(format!("{:#?}", padded).contains("padding: [00 00]"));
+
+        set_default_hex_style(&HexStyle::DEFAULT);
+        assert_eq!(format!("{:?}", invalid), "<invalid UTF-8>\n\"AB CD FF 00\"");
+    }
+}
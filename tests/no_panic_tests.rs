@@ -0,0 +1,96 @@
+// fixed_str/tests/no_panic_tests.rs
+
+//! Proves that the core constructors and accessors contain no panic paths.
+//!
+//! `#[no_panic]` is checked at link time and needs cross‑crate inlining, so these tests only
+//! run in optimized builds, with LTO enabled by the `no-panic` profile in `Cargo.toml`:
+//! `cargo test --profile no-panic --features no_panic --test no_panic_tests`.
+//! The `debug_lossy` hook and the `truncation_policy` callback run user code, which may panic.
+#![cfg(all(
+    feature = "no_panic",
+    not(debug_assertions),
+    not(feature = "debug_lossy"),
+    not(feature = "truncation_policy")
+))]
+
+use fixed_str::*;
+use no_panic::no_panic;
+
+#[no_panic]
+fn new(input: &str) -> FixedStr<16> {
+    FixedStr::new(input)
+}
+
+#[no_panic]
+fn from_slice(input: &[u8]) -> FixedStr<16> {
+    FixedStr::from_slice(input)
+}
+
+#[no_panic]
+fn from_bytes(input: [u8; 16]) -> FixedStr<16> {
+    FixedStr::from_bytes(input)
+}
+
+#[no_panic]
+fn set(fixed: &mut FixedStr<16>, input: &str) -> Result<(), FixedStrError> {
+    fixed.set(input)
+}
+
+#[no_panic]
+fn set_lossy(fixed: &mut FixedStr<16>, input: &str) {
+    fixed.set_lossy(input)
+}
+
+#[no_panic]
+fn checked_new(input: &str) -> Result<FixedStr<16>, FixedStrError> {
+    FixedStr::checked_new(input)
+}
+
+#[no_panic]
+fn len(fixed: &FixedStr<16>) -> usize {
+    fixed.len()
+}
+
+#[no_panic]
+fn try_as_str(fixed: &FixedStr<16>) -> Result<&str, FixedStrError> {
+    fixed.try_as_str()
+}
+
+#[no_panic]
+fn as_str(fixed: &FixedStr<16>) -> &str {
+    fixed.as_str()
+}
+
+#[no_panic]
+fn try_push_str(buf: &mut FixedStrBuf<16>, input: &str) -> Result<(), FixedStrError> {
+    buf.try_push_str(input)
+}
+
+#[no_panic]
+fn push_str_lossy(buf: &mut FixedStrBuf<16>, input: &str) -> PushOutcome {
+    buf.push_str_lossy(input)
+}
+
+#[no_panic]
+fn finalize(buf: FixedStrBuf<16>) -> FixedStr<16> {
+    buf.finalize()
+}
+
+// Linking this test proves the wrapped functions contain no panic paths.
+#[test]
+fn test_no_panic_paths() {
+    let mut fixed = new("hello, wide world");
+    assert_eq!(as_str(&fixed), "hello, wide worl");
+    assert_eq!(from_slice(b"abc\0def"), "abc");
+    assert_eq!(from_bytes(*b"0123456789abcdef"), "0123456789abcdef");
+    assert!(set(&mut fixed, "much too long for this").is_err());
+    set_lossy(&mut fixed, "é");
+    assert_eq!(len(&fixed), 2);
+    assert_eq!(try_as_str(&fixed), Ok("é"));
+    assert_eq!(checked_new("ok").unwrap(), "ok");
+
+    let mut buf = FixedStrBuf::new();
+    assert!(try_push_str(&mut buf, "abc").is_ok());
+    assert!(push_str_lossy(&mut buf, "defghijklmnopqrstuvwxyz").is_truncated());
+    assert_eq!(finalize(buf), "abcdefghijklmnop");
+}
@@ -0,0 +1,48 @@
+// fixed_str/tests/policy_tests.rs
+
+//! Exercises the crate‑wide `TruncationPolicy`.
+//! Lives in its own test binary so changing the global policy does not affect other tests.
+
+#[cfg(all(
+    feature = "truncation_policy",
+    target_has_atomic = "8",
+    target_has_atomic = "ptr"
+))]
+mod policy_tests {
+    use fixed_str::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static REPORTED: AtomicUsize = AtomicUsize::new(0);
+
+    fn report(_: &str, capacity: usize) {
+        REPORTED.fetch_add(capacity, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn test_global_policy() {
+        // A single test, as the policy is shared by every thread.
+        assert!(matches!(truncation_policy(), TruncationPolicy::Silent));
+        assert_eq!(FixedStr::<4>::new("truncated"), "trun");
+
+        set_truncation_policy(TruncationPolicy::Ellipsis);
+        assert_eq!(FixedStr::<8>::new("truncated"), "trunc…");
+        let mut fixed = FixedStr::<8>::new("");
+        fixed.set_lossy("overlong");
+        assert_eq!(fixed, "overlong");
+        fixed.set_lossy("overlong!");
+        assert_eq!(fixed, "overl…");
+
+        set_truncation_policy(TruncationPolicy::Callback(report));
+        assert!(matches!(truncation_policy(), TruncationPolicy::Callback(_)));
+        assert_eq!(FixedStr::<4>::new("truncated"), "trun");
+        assert_eq!(FixedStr::<4>::new("fits"), "fits");
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 4);
+
+        set_truncation_policy(TruncationPolicy::Error);
+        assert_eq!(FixedStr::<4>::new("fits"), "fits");
+        assert!(std::panic::catch_unwind(|| FixedStr::<4>::new("truncated")).is_err());
+
+        set_truncation_policy(TruncationPolicy::Silent);
+        assert_eq!(FixedStr::<4>::new("truncated"), "trun");
+    }
+}